@@ -0,0 +1,219 @@
+//! Generates the const tables of the `gen-tables` feature, see `src/tables.rs`.
+//!
+//! The Chinese calendar and the solar terms are computed with the integer
+//! series of `astronomy::fixed`, the same as the Chinese calendar in
+//! `Mode::Deterministic`, so that the tables are the same on every host.
+
+use std::fmt::Write as _;
+use std::path::Path;
+
+#[allow(dead_code)]
+#[path = "src/astronomy/fixed/series.rs"]
+mod series;
+
+const FIRST_YEAR: i32 = 1900;
+const LAST_YEAR: i32 = 2100;
+/// The timezone of Beijing, in seconds.
+const BEIJING_TZ: i64 = 8 * 3600;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/astronomy/fixed/series.rs");
+    if std::env::var_os("CARGO_FEATURE_GEN_TABLES").is_none() {
+        return;
+    }
+    let out_dir = std::env::var_os("OUT_DIR").unwrap();
+    let mut out = String::new();
+    write_gregorian_table(&mut out);
+    let days = Days::new(jdn(FIRST_YEAR - 1, 11, 1), jdn(LAST_YEAR + 2, 1, 1));
+    write_chinese_table(&mut out, &days);
+    write_solar_term_table(&mut out, &days);
+    std::fs::write(Path::new(&out_dir).join("tables.rs"), out).unwrap();
+}
+
+fn is_leap(year: i32) -> bool {
+    year % 400 == 0 || (year % 4 == 0 && year % 100 != 0)
+}
+
+fn num_days_of_month(year: i32, month: u8) -> u16 {
+    match month {
+        2 if is_leap(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// The Julian day number of a date of the proleptic Gregorian calendar.
+fn jdn(year: i32, month: u8, day: u8) -> i64 {
+    let a = (14 - month as i64) / 12;
+    let y = year as i64 + 4800 - a;
+    let m = month as i64 + 12 * a - 3;
+    day as i64 + (153 * m + 2) / 5 + 365 * y + y / 4 - y / 100 + y / 400 - 32045
+}
+
+fn write_gregorian_table(out: &mut String) {
+    out.push_str("/// The number of days before each month, and in the whole year, of common\n");
+    out.push_str("/// and leap years.\n");
+    out.push_str("pub const GREGORIAN_DAYS_BEFORE_MONTH: [[u16; 13]; 2] = [\n");
+    for year in [2001, 2004] {
+        let mut days = 0;
+        out.push_str("    [0");
+        for month in 1..=12 {
+            days += num_days_of_month(year, month);
+            write!(out, ", {}", days).unwrap();
+        }
+        out.push_str("],\n");
+    }
+    out.push_str("];\n\n");
+}
+
+/// The solar terms and new moons of each day in Beijing time.
+struct Days {
+    first: i64,
+    /// The solar term beginning on the day, as in `SolarTerm::from_repr`.
+    solar_terms: Vec<Option<u8>>,
+    new_moons: Vec<bool>,
+}
+
+impl Days {
+    fn new(first: i64, last: i64) -> Self {
+        let midnights: Vec<_> = (first..=last + 1)
+            .map(|jdn| (jdn - 2451545) * 86400 - 43200 - BEIJING_TZ)
+            .collect();
+        let sun: Vec<_> = midnights.iter().map(|&t| series::sun_ecl_long(t)).collect();
+        let moon: Vec<_> = midnights
+            .iter()
+            .zip(&sun)
+            .map(|(&t, &sun)| series::moon_ecl_long(t).wrapping_sub(sun))
+            .collect();
+        Self {
+            first,
+            solar_terms: sun
+                .windows(2)
+                .map(|w| {
+                    let (begin, end) = series::sectors(w[0], w[1], 24);
+                    (begin < end).then(|| (begin - 270 / 15).rem_euclid(24) as u8)
+                })
+                .collect(),
+            new_moons: moon
+                .windows(2)
+                .map(|w| {
+                    let (begin, end) = series::sectors(w[0], w[1], 4);
+                    begin < end && begin.rem_euclid(4) == 0
+                })
+                .collect(),
+        }
+    }
+
+    fn solar_term(&self, jdn: i64) -> Option<u8> {
+        self.solar_terms[(jdn - self.first) as usize]
+    }
+
+    fn is_new_moon(&self, jdn: i64) -> bool {
+        self.new_moons[(jdn - self.first) as usize]
+    }
+
+    fn winter_solstice(&self, year: i32) -> i64 {
+        let mut d = jdn(year, 12, 18);
+        while self.solar_term(d) != Some(0) {
+            d += 1;
+        }
+        d
+    }
+
+    fn prev_new_moon(&self, jdn: i64) -> i64 {
+        let mut d = jdn;
+        while !self.is_new_moon(d) {
+            d -= 1;
+        }
+        d
+    }
+
+    /// The first day, the number of days of each month and the leap month of
+    /// the suì ending at the winter solstice of the year, as
+    /// `lunisolar::calc_sui_data` computes them.
+    fn sui(&self, year: i32) -> (i64, Vec<u8>, Option<usize>) {
+        let first_day = self.prev_new_moon(self.winter_solstice(year - 1));
+        let mut months = Vec::new();
+        let mut last_new_moon = first_day;
+        let mut has_mid_term = false;
+        for d in first_day..=self.winter_solstice(year) {
+            if d > first_day && self.is_new_moon(d) {
+                months.push(((d - last_new_moon) as u8, has_mid_term));
+                last_new_moon = d;
+                has_mid_term = false;
+            }
+            if self.solar_term(d).is_some_and(|st| st % 2 == 0) {
+                has_mid_term = true;
+            }
+        }
+        let leap_month = if months.len() > 12 {
+            months.iter().position(|&(_, has_mid_term)| !has_mid_term)
+        } else {
+            None
+        };
+        let months = months.into_iter().map(|(num_days, _)| num_days).collect();
+        (first_day, months, leap_month)
+    }
+
+    /// The same as `lunisolar::calc_year_data`.
+    fn chinese_year(&self, year: i32) -> (i64, Vec<u8>, Option<usize>) {
+        let (first_day1, months1, leap_month1) = self.sui(year);
+        let (_, months2, leap_month2) = self.sui(year + 1);
+        let (off1, lm1) = match leap_month1 {
+            Some(lm) if lm <= 2 => (3, None),
+            Some(lm) => (2, Some(lm - 2)),
+            None => (2, None),
+        };
+        let (off2, lm2) = match leap_month2 {
+            Some(lm) if lm <= 2 => (3, Some(lm + 10)),
+            _ => (2, None),
+        };
+        let first_day = first_day1 + months1[..off1].iter().map(|&n| n as i64).sum::<i64>();
+        let months = [&months1[off1..], &months2[..off2]].concat();
+        (first_day, months, lm1.or(lm2))
+    }
+}
+
+fn write_chinese_table(out: &mut String, days: &Days) {
+    out.push_str("/// The Chinese years beginning in each Gregorian year from `FIRST_YEAR`.\n");
+    out.push_str("pub const CHINESE_YEARS: [ChineseYear; NUM_YEARS] = [\n");
+    for year in FIRST_YEAR..=LAST_YEAR {
+        let (first_day, months, leap_month) = days.chinese_year(year);
+        assert_eq!(months.len(), 12 + leap_month.is_some() as usize);
+        let long_months = months
+            .iter()
+            .enumerate()
+            .fold(0u16, |bits, (i, &n)| bits | (((n == 30) as u16) << i));
+        writeln!(
+            out,
+            "    ChineseYear {{ first_day: Date::from_jdn({}), long_months: {:#06x}, leap_month: {} }},",
+            first_day,
+            long_months,
+            leap_month.unwrap_or(13)
+        )
+        .unwrap();
+    }
+    out.push_str("];\n\n");
+}
+
+fn write_solar_term_table(out: &mut String, days: &Days) {
+    out.push_str("/// The days of the solar terms of each Gregorian year from `FIRST_YEAR`, in\n");
+    out.push_str("/// the order of [`solar_terms`].\n");
+    out.push_str("pub static SOLAR_TERMS: [[Date; 24]; NUM_YEARS] = [\n");
+    for year in FIRST_YEAR..=LAST_YEAR {
+        let dates: Vec<_> = (jdn(year, 1, 1)..jdn(year + 1, 1, 1))
+            .filter(|&d| days.solar_term(d).is_some())
+            .collect();
+        assert_eq!(dates.len(), 24);
+        assert_eq!(days.solar_term(dates[0]), Some(1));
+        out.push_str("    [");
+        for (i, d) in dates.iter().enumerate() {
+            let sep = if i == 0 { "" } else { ", " };
+            write!(out, "{}Date::from_jdn({})", sep, d).unwrap();
+        }
+        out.push_str("],\n");
+    }
+    out.push_str("];\n");
+}
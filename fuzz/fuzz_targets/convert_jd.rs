@@ -0,0 +1,24 @@
+//! Conversions of Julian days to the days of the calendars and back.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use omnical::chinese::Mode;
+use omnical::{ChineseDay, Date, GregorianDay};
+
+fuzz_target!(|input: (f64, f64, bool)| {
+    let (jd, tz, deterministic) = input;
+    let date = Date::from_jd_with_tz(jd, tz);
+    if let Some(day) = GregorianDay::checked_from_date_with_tz(date, 0.0) {
+        assert_eq!(Date::from(day), date);
+    }
+    let _ = GregorianDay::checked_from_date_with_tz(date, tz);
+    let mode = if deterministic {
+        Mode::Deterministic
+    } else {
+        Mode::default()
+    };
+    if let Some(day) = ChineseDay::checked_from_date_with_mode(date, mode) {
+        assert_eq!(Date::from(day), date);
+    }
+});
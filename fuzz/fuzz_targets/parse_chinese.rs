@@ -0,0 +1,26 @@
+//! Chinese dates and era years written in Chinese, e.g. "甲辰年八月十五".
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use omnical::chinese::era::EraYear;
+use omnical::{ChineseDay, Day as _, Year as _};
+
+fuzz_target!(|input: (&str, i32)| {
+    let (s, pivot) = input;
+    if let Ok(day) = ChineseDay::parse_with_pivot(s, pivot) {
+        let pivot = day.the_year().ord();
+        assert_eq!(
+            ChineseDay::parse_with_pivot(&day.to_string(), pivot),
+            Ok(day)
+        );
+        assert_eq!(
+            ChineseDay::parse_with_pivot(&format!("{:#}", day), pivot),
+            Ok(day)
+        );
+    }
+    let _ = s.parse::<ChineseDay>();
+    if let Ok(era_year) = s.parse::<EraYear>() {
+        assert_eq!(era_year.to_string().parse(), Ok(era_year));
+    }
+});
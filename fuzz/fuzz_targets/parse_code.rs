@@ -0,0 +1,23 @@
+//! Codes of days, ISO 8601 dates and regnal era years.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use omnical::{code, regnal, ChineseDay, Date, GregorianDay};
+
+fuzz_target!(|s: &str| {
+    if let Ok(date) = code::parse(s) {
+        let code = if s.starts_with("G:") {
+            GregorianDay::from(date).to_code()
+        } else {
+            ChineseDay::try_from(date).unwrap().to_code()
+        };
+        assert_eq!(code, s);
+    }
+    if let Ok(day) = GregorianDay::from_iso8601(s) {
+        assert_eq!(GregorianDay::from_iso8601(&day.to_iso8601()), Ok(day));
+    }
+    if let Ok(era_year) = s.parse::<regnal::EraYear>() {
+        assert_eq!(era_year.to_string().parse(), Ok(era_year));
+    }
+});
@@ -1,6 +1,15 @@
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering::Relaxed};
+use std::sync::Mutex;
+
 use strum::{Display, EnumCount, EnumProperty, EnumString, FromRepr, VariantArray};
+
+use crate::cache::Cache;
+use crate::calendar::Calendar as _;
+use crate::{Date, GregorianCalendar};
 // use std::cmp::Ordering::*;
 
+pub mod fixed;
+
 #[derive(
     Debug,
     Clone,
@@ -15,53 +24,53 @@ use strum::{Display, EnumCount, EnumProperty, EnumString, FromRepr, VariantArray
     EnumProperty,
 )]
 pub enum SolarTerm {
-    #[strum(props(zh = "冬至"))]
+    #[strum(props(zh = "冬至", pinyin = "Dōngzhì", english = "Winter Solstice"))]
     WinterSolstice,
-    #[strum(props(zh = "小寒"))]
+    #[strum(props(zh = "小寒", pinyin = "Xiǎohán", english = "Minor Cold"))]
     MinorCold,
-    #[strum(props(zh = "大寒"))]
+    #[strum(props(zh = "大寒", pinyin = "Dàhán", english = "Major Cold"))]
     MajorCold,
-    #[strum(props(zh = "立春"))]
+    #[strum(props(zh = "立春", pinyin = "Lìchūn", english = "Beginning of Spring"))]
     BeginningOfSpring,
-    #[strum(props(zh = "雨水"))]
+    #[strum(props(zh = "雨水", pinyin = "Yǔshuǐ", english = "Rain Water"))]
     RainWater,
-    #[strum(props(zh = "惊蛰"))]
+    #[strum(props(zh = "惊蛰", pinyin = "Jīngzhé", english = "Awakening of Insects"))]
     AwakeningOfInsects,
-    #[strum(props(zh = "春分"))]
+    #[strum(props(zh = "春分", pinyin = "Chūnfēn", english = "Spring Equinox"))]
     SpringEquinox,
-    #[strum(props(zh = "清明"))]
+    #[strum(props(zh = "清明", pinyin = "Qīngmíng", english = "Pure Brightness"))]
     PureBrightness,
-    #[strum(props(zh = "谷雨"))]
+    #[strum(props(zh = "谷雨", pinyin = "Gǔyǔ", english = "Grain Rain"))]
     GrainRain,
-    #[strum(props(zh = "立夏"))]
+    #[strum(props(zh = "立夏", pinyin = "Lìxià", english = "Beginning of Summer"))]
     BeginningOfSummer,
-    #[strum(props(zh = "小满"))]
+    #[strum(props(zh = "小满", pinyin = "Xiǎomǎn", english = "Grain Buds"))]
     GrainBuds,
-    #[strum(props(zh = "芒种"))]
+    #[strum(props(zh = "芒种", pinyin = "Mángzhòng", english = "Grain in Ear"))]
     GrainInEar,
-    #[strum(props(zh = "夏至"))]
+    #[strum(props(zh = "夏至", pinyin = "Xiàzhì", english = "Summer Solstice"))]
     SummerSolstice,
-    #[strum(props(zh = "小暑"))]
+    #[strum(props(zh = "小暑", pinyin = "Xiǎoshǔ", english = "Minor Heat"))]
     MinorHeat,
-    #[strum(props(zh = "大暑"))]
+    #[strum(props(zh = "大暑", pinyin = "Dàshǔ", english = "Major Heat"))]
     MajorHeat,
-    #[strum(props(zh = "立秋"))]
+    #[strum(props(zh = "立秋", pinyin = "Lìqiū", english = "Beginning of Autumn"))]
     BeginningOfAutumn,
-    #[strum(props(zh = "处暑"))]
+    #[strum(props(zh = "处暑", pinyin = "Chǔshǔ", english = "End of Heat"))]
     EndOfHeat,
-    #[strum(props(zh = "白露"))]
+    #[strum(props(zh = "白露", pinyin = "Báilù", english = "White Dew"))]
     WhiteDew,
-    #[strum(props(zh = "秋分"))]
+    #[strum(props(zh = "秋分", pinyin = "Qiūfēn", english = "Autumn Equinox"))]
     AutumnEquinox,
-    #[strum(props(zh = "寒露"))]
+    #[strum(props(zh = "寒露", pinyin = "Hánlù", english = "Cold Dew"))]
     ColdDew,
-    #[strum(props(zh = "霜降"))]
+    #[strum(props(zh = "霜降", pinyin = "Shuāngjiàng", english = "Frost's Descent"))]
     FrostsDescent,
-    #[strum(props(zh = "立冬"))]
+    #[strum(props(zh = "立冬", pinyin = "Lìdōng", english = "Beginning of Winter"))]
     BeginningOfWinter,
-    #[strum(props(zh = "小雪"))]
+    #[strum(props(zh = "小雪", pinyin = "Xiǎoxuě", english = "Minor Snow"))]
     MinorSnow,
-    #[strum(props(zh = "大雪"))]
+    #[strum(props(zh = "大雪", pinyin = "Dàxuě", english = "Major Snow"))]
     MajorSnow,
 }
 
@@ -105,6 +114,14 @@ impl SolarTerm {
     pub fn chinese(&self) -> &str {
         self.get_str("zh").unwrap()
     }
+
+    pub fn pinyin(&self) -> &str {
+        self.get_str("pinyin").unwrap()
+    }
+
+    pub fn english(&self) -> &str {
+        self.get_str("english").unwrap()
+    }
 }
 
 #[test]
@@ -118,6 +135,8 @@ fn test_solar_term() {
     assert_eq!(MinorCold.pred(), WinterSolstice);
 
     assert_eq!(WinterSolstice.degrees(), 270.0);
+    assert_eq!(AwakeningOfInsects.pinyin(), "Jīngzhé");
+    assert_eq!(AwakeningOfInsects.english(), "Awakening of Insects");
     assert_eq!(SpringEquinox.degrees(), 0.0);
     assert_eq!(PureBrightness.degrees(), 15.0);
 
@@ -191,6 +210,12 @@ impl LunarPhase {
         }
     }
 
+    /// Whether the phase is a new moon, a first quarter, a full moon or a last
+    /// quarter, which are instants rather than spans of days.
+    pub fn is_principal(&self) -> bool {
+        matches!(self, NewMoon | FirstQuarter | FullMoon | LastQuarter)
+    }
+
     pub fn chinese(&self) -> &str {
         self.get_str("zh").unwrap()
     }
@@ -211,6 +236,9 @@ fn test_lunar_phase() {
     assert_eq!(FullMoon.degrees(), 180.0);
     assert_eq!(WaningGibbous.degrees(), 225.0);
 
+    assert!(NewMoon.is_principal() && LastQuarter.is_principal());
+    assert!(!WaxingCrescent.is_principal() && !WaningGibbous.is_principal());
+
     assert_eq!(LunarPhase::from_degree_range(-1.0, 1.0), NewMoon);
     assert_eq!(LunarPhase::from_degree_range(1.0, 89.0), WaxingCrescent);
     assert_eq!(LunarPhase::from_degree_range(1.0, 179.0), FirstQuarter);
@@ -221,20 +249,930 @@ fn test_lunar_phase() {
     assert_eq!(LunarPhase::from_degree_range(271.0, 359.0), WaningCrescent);
 }
 
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    EnumCount,
+    VariantArray,
+    Display,
+    EnumString,
+    FromRepr,
+    EnumProperty,
+)]
+pub enum ZodiacSign {
+    #[strum(props(zh = "白羊座", symbol = "♈"))]
+    Aries,
+    #[strum(props(zh = "金牛座", symbol = "♉"))]
+    Taurus,
+    #[strum(props(zh = "双子座", symbol = "♊"))]
+    Gemini,
+    #[strum(props(zh = "巨蟹座", symbol = "♋"))]
+    Cancer,
+    #[strum(props(zh = "狮子座", symbol = "♌"))]
+    Leo,
+    #[strum(props(zh = "处女座", symbol = "♍"))]
+    Virgo,
+    #[strum(props(zh = "天秤座", symbol = "♎"))]
+    Libra,
+    #[strum(props(zh = "天蝎座", symbol = "♏"))]
+    Scorpio,
+    #[strum(props(zh = "射手座", symbol = "♐"))]
+    Sagittarius,
+    #[strum(props(zh = "摩羯座", symbol = "♑"))]
+    Capricorn,
+    #[strum(props(zh = "水瓶座", symbol = "♒"))]
+    Aquarius,
+    #[strum(props(zh = "双鱼座", symbol = "♓"))]
+    Pisces,
+}
+
+pub use ZodiacSign::*;
+
+/// The method used to determine the zodiac sign of a date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ZodiacMethod {
+    /// By the ecliptic longitude of the sun.
+    #[default]
+    Astronomical,
+    /// By the fixed date ranges commonly used in horoscopes.
+    DateRange,
+}
+
+/// The (month, day) on which each sign begins when using [`ZodiacMethod::DateRange`].
+const ZODIAC_SIGN_FIRST_DAYS: [(u8, u8); 12] = [
+    (3, 21),
+    (4, 20),
+    (5, 21),
+    (6, 22),
+    (7, 23),
+    (8, 23),
+    (9, 23),
+    (10, 24),
+    (11, 23),
+    (12, 22),
+    (1, 20),
+    (2, 19),
+];
+
+impl ZodiacSign {
+    pub fn ord(&self) -> u8 {
+        *self as u8 + 1
+    }
+
+    pub fn from_ord(ord: u8) -> Option<Self> {
+        Self::from_repr((ord as i8 - 1) as usize)
+    }
+
+    pub fn succ(&self) -> Self {
+        Self::from_repr((*self as i8 + 1).rem_euclid(Self::COUNT as i8) as usize).unwrap()
+    }
+
+    pub fn pred(&self) -> Self {
+        Self::from_repr((*self as i8 - 1).rem_euclid(Self::COUNT as i8) as usize).unwrap()
+    }
+
+    /// The ecliptic longitude of the sun when it enters the sign.
+    pub fn degrees(&self) -> f64 {
+        (*self as i8 as f64) * 30.0
+    }
+
+    pub fn from_degrees(deg: f64) -> Self {
+        Self::from_repr(deg.div_euclid(30.0).rem_euclid(Self::COUNT as f64) as usize).unwrap()
+    }
+
+    /// The sign of a day in the given Gregorian month, by fixed date ranges,
+    /// or `None` if no year has the day, e.g. (2, 30).
+    pub fn from_month_day(month: u8, day: u8) -> Option<Self> {
+        // 2000 is a leap year, so that February 29 is accepted.
+        GregorianCalendar::from_ymd(2000, month, day)?;
+        let (_, first_day) = ZODIAC_SIGN_FIRST_DAYS[(month as usize + 9) % 12];
+        let sign = Self::from_repr((month as usize + 9) % 12).unwrap();
+        if day < first_day {
+            Some(sign.pred())
+        } else {
+            Some(sign)
+        }
+    }
+
+    /// The (month, day) on which the sign begins, by fixed date ranges.
+    pub fn first_month_day(&self) -> (u8, u8) {
+        ZODIAC_SIGN_FIRST_DAYS[*self as usize]
+    }
+
+    pub fn chinese(&self) -> &str {
+        self.get_str("zh").unwrap()
+    }
+
+    pub fn symbol(&self) -> &str {
+        self.get_str("symbol").unwrap()
+    }
+}
+
+#[test]
+fn test_zodiac_sign() {
+    assert_eq!(ZodiacSign::COUNT, 12);
+    assert_eq!(Aries.pred(), Pisces);
+    assert_eq!(Pisces.succ(), Aries);
+
+    assert_eq!(Aries.degrees(), 0.0);
+    assert_eq!(Capricorn.degrees(), 270.0);
+
+    assert_eq!(ZodiacSign::from_degrees(0.0), Aries);
+    assert_eq!(ZodiacSign::from_degrees(359.9), Pisces);
+    assert_eq!(ZodiacSign::from_degrees(-0.1), Pisces);
+    assert_eq!(ZodiacSign::from_degrees(275.0), Capricorn);
+
+    assert_eq!(ZodiacSign::from_month_day(3, 20), Some(Pisces));
+    assert_eq!(ZodiacSign::from_month_day(3, 21), Some(Aries));
+    assert_eq!(ZodiacSign::from_month_day(1, 1), Some(Capricorn));
+    assert_eq!(ZodiacSign::from_month_day(12, 31), Some(Capricorn));
+    assert_eq!(ZodiacSign::from_month_day(9, 15), Some(Virgo));
+    assert_eq!(ZodiacSign::from_month_day(13, 1), None);
+    assert_eq!(ZodiacSign::from_month_day(2, 29), Some(Pisces));
+    assert_eq!(ZodiacSign::from_month_day(2, 30), None);
+    assert_eq!(ZodiacSign::from_month_day(4, 31), None);
+    assert_eq!(ZodiacSign::from_month_day(1, 0), None);
+    assert_eq!(Libra.first_month_day(), (9, 23));
+
+    assert_eq!(Aries.chinese(), "白羊座");
+    assert_eq!(Aries.symbol(), "♈");
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    EnumCount,
+    VariantArray,
+    Display,
+    EnumString,
+    FromRepr,
+    EnumProperty,
+)]
+pub enum Season {
+    #[strum(props(zh = "春"))]
+    Spring,
+    #[strum(props(zh = "夏"))]
+    Summer,
+    #[strum(props(zh = "秋"))]
+    Autumn,
+    #[strum(props(zh = "冬"))]
+    Winter,
+}
+
+pub use Season::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Hemisphere {
+    #[default]
+    Northern,
+    Southern,
+}
+
+/// The definition used to divide a year into seasons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SeasonConvention {
+    /// Seasons begin at the equinoxes and solstices.
+    #[default]
+    Astronomical,
+    /// Seasons are groups of whole Gregorian months, with spring beginning in
+    /// March in the northern hemisphere.
+    Meteorological,
+    /// Seasons begin at the four "beginning" solar terms (立春, 立夏, 立秋, 立冬).
+    Chinese,
+}
+
+impl Season {
+    pub fn ord(&self) -> u8 {
+        *self as u8 + 1
+    }
+
+    pub fn from_ord(ord: u8) -> Option<Self> {
+        Self::from_repr((ord as i8 - 1) as usize)
+    }
+
+    pub fn succ(&self) -> Self {
+        Self::from_repr((*self as i8 + 1).rem_euclid(Self::COUNT as i8) as usize).unwrap()
+    }
+
+    pub fn pred(&self) -> Self {
+        Self::from_repr((*self as i8 - 1).rem_euclid(Self::COUNT as i8) as usize).unwrap()
+    }
+
+    /// The season at the same time of year in the other hemisphere.
+    pub fn opposite(&self) -> Self {
+        self.succ().succ()
+    }
+
+    /// The astronomical season in the northern hemisphere when the sun is at
+    /// the given ecliptic longitude.
+    pub fn from_degrees(deg: f64) -> Self {
+        Self::from_repr(deg.div_euclid(90.0).rem_euclid(Self::COUNT as f64) as usize).unwrap()
+    }
+
+    /// The meteorological season in the northern hemisphere of the given
+    /// Gregorian month.
+    pub fn from_month(month: u8) -> Option<Self> {
+        if !(1..=12).contains(&month) {
+            return None;
+        }
+        Self::from_repr((month as usize + 9) % 12 / 3)
+    }
+
+    pub fn chinese(&self) -> &str {
+        self.get_str("zh").unwrap()
+    }
+}
+
+#[test]
+fn test_season() {
+    assert_eq!(Spring.pred(), Winter);
+    assert_eq!(Winter.succ(), Spring);
+    assert_eq!(Spring.opposite(), Autumn);
+    assert_eq!(Winter.opposite(), Summer);
+
+    assert_eq!(Season::from_degrees(0.0), Spring);
+    assert_eq!(Season::from_degrees(135.0), Summer);
+    assert_eq!(Season::from_degrees(269.9), Autumn);
+    assert_eq!(Season::from_degrees(-1.0), Winter);
+
+    assert_eq!(Season::from_month(1), Some(Winter));
+    assert_eq!(Season::from_month(3), Some(Spring));
+    assert_eq!(Season::from_month(8), Some(Summer));
+    assert_eq!(Season::from_month(11), Some(Autumn));
+    assert_eq!(Season::from_month(12), Some(Winter));
+    assert_eq!(Season::from_month(0), None);
+}
+
+const DEFAULT_EPHEMERIS_CACHE_CAPACITY: usize = 4096;
+
+static EPHEMERIS_CACHE_CAPACITY: AtomicUsize = AtomicUsize::new(DEFAULT_EPHEMERIS_CACHE_CAPACITY);
+static SUN_ECL_LONG_CACHE: Mutex<EphemerisCache> = Mutex::new(EphemerisCache::new());
+static MOON_ECL_LONG_TO_SUN_CACHE: Mutex<EphemerisCache> = Mutex::new(EphemerisCache::new());
+
+static SUN_EVALUATIONS: AtomicU64 = AtomicU64::new(0);
+static MOON_EVALUATIONS: AtomicU64 = AtomicU64::new(0);
+static EPHEMERIS_CACHE_HITS: AtomicU64 = AtomicU64::new(0);
+
+/// The numbers of times the ephemeris was evaluated or found in the cache
+/// since the process started, to see where the time of computing calendars
+/// goes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct EphemerisCounters {
+    /// The evaluations of the longitude of the sun, including those of
+    /// [`fixed`].
+    pub sun: u64,
+    /// The evaluations of the longitude of the moon, including those of
+    /// [`fixed`].
+    pub moon: u64,
+    /// The values found in the caches instead of evaluated.
+    pub cache_hits: u64,
+}
+
+/// Returns the counters of the ephemeris, which only increase.
+pub fn ephemeris_counters() -> EphemerisCounters {
+    EphemerisCounters {
+        sun: SUN_EVALUATIONS.load(Relaxed),
+        moon: MOON_EVALUATIONS.load(Relaxed),
+        cache_hits: EPHEMERIS_CACHE_HITS.load(Relaxed),
+    }
+}
+
+/// A memoization cache of ephemeris values keyed by the bits of Julian days.
+type EphemerisCache = Cache<u64, f64>;
+
+fn cached(cache: &Mutex<EphemerisCache>, jd: f64, f: impl FnOnce() -> f64) -> f64 {
+    let capacity = EPHEMERIS_CACHE_CAPACITY.load(Relaxed);
+    if capacity == 0 {
+        return f();
+    }
+    let lock = || cache.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(value) = lock().get(&jd.to_bits()) {
+        EPHEMERIS_CACHE_HITS.fetch_add(1, Relaxed);
+        return value;
+    }
+    // Computed without holding the lock, so that threads computing other
+    // values do not wait for this one.
+    let value = f();
+    lock().insert(jd.to_bits(), value, capacity);
+    value
+}
+
+/// Sets the maximum number of Julian days whose ephemeris values are cached.
+///
+/// Each cached function keeps its own cache of this size; a capacity of 0
+/// disables caching.
+pub fn set_ephemeris_cache_capacity(capacity: usize) {
+    EPHEMERIS_CACHE_CAPACITY.store(capacity, Relaxed);
+    for cache in [&SUN_ECL_LONG_CACHE, &MOON_ECL_LONG_TO_SUN_CACHE] {
+        cache
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .shrink_to(capacity);
+    }
+}
+
+/// Removes all cached ephemeris values.
+pub fn clear_ephemeris_cache() {
+    for cache in [&SUN_ECL_LONG_CACHE, &MOON_ECL_LONG_TO_SUN_CACHE] {
+        cache.lock().unwrap_or_else(|e| e.into_inner()).shrink_to(0);
+    }
+}
+
+#[test]
+fn test_ephemeris_cache() {
+    let jd = 2460301.0;
+    assert_eq!(get_sun_ecl_long(jd), get_sun_ecl_long(jd));
+    assert_eq!(get_moon_ecl_long_to_sun(jd), get_moon_ecl_long_to_sun(jd));
+
+    // Other tests evaluate the ephemeris at the same time.
+    let before = ephemeris_counters();
+    get_moon_ecl_long(jd);
+    fixed::get_sun_ecl_long(0);
+    let cache = Mutex::new(EphemerisCache::new());
+    assert_eq!(cached(&cache, jd, || 1.0), 1.0);
+    assert_eq!(cached(&cache, jd, || 2.0), 1.0);
+    let after = ephemeris_counters();
+    assert!(after.moon > before.moon);
+    assert!(after.sun > before.sun);
+    assert!(after.cache_hits > before.cache_hits);
+}
+
+/// Returns the geocentric ecliptic longitude of the sun in degrees.
+///
+/// Results are cached, see [`set_ephemeris_cache_capacity`].
 pub fn get_sun_ecl_long(jd: f64) -> f64 {
-    let (ecl_pnt, _) = astro::sun::geocent_ecl_pos(jd);
-    ecl_pnt.long.to_degrees()
+    cached(&SUN_ECL_LONG_CACHE, jd, || {
+        SUN_EVALUATIONS.fetch_add(1, Relaxed);
+        let (ecl_pnt, _) = astro::sun::geocent_ecl_pos(jd);
+        ecl_pnt.long.to_degrees()
+    })
+}
+
+/// Returns the apparent ecliptic longitude of the sun in degrees, i.e.
+/// [`get_sun_ecl_long`] corrected for nutation and aberration.
+pub fn get_sun_apparent_ecl_long(jd: f64) -> f64 {
+    let (nut_in_long, _) = get_nutation(jd);
+    (get_sun_ecl_long(jd) + nut_in_long - 20.4898 / 3600.0).rem_euclid(360.0)
 }
 
 pub fn get_moon_ecl_long(jd: f64) -> f64 {
+    MOON_EVALUATIONS.fetch_add(1, Relaxed);
     let (ecl_pnt, _) = astro::lunar::geocent_ecl_pos(jd);
     ecl_pnt.long.to_degrees()
 }
 
+/// Returns the ecliptic longitude of the moon relative to the sun in degrees.
+///
+/// Results are cached, see [`set_ephemeris_cache_capacity`].
 pub fn get_moon_ecl_long_to_sun(jd: f64) -> f64 {
-    let moon_ecl_long = get_moon_ecl_long(jd);
-    let sun_ecl_long = get_sun_ecl_long(jd);
-    (moon_ecl_long - sun_ecl_long).rem_euclid(360.0)
+    cached(&MOON_ECL_LONG_TO_SUN_CACHE, jd, || {
+        let moon_ecl_long = get_moon_ecl_long(jd);
+        let sun_ecl_long = get_sun_ecl_long(jd);
+        (moon_ecl_long - sun_ecl_long).rem_euclid(360.0)
+    })
+}
+
+/// Evaluates [`get_sun_ecl_long`] at each of the given Julian days.
+pub fn get_sun_ecl_long_many(jds: &[f64]) -> Vec<f64> {
+    jds.iter().map(|&jd| get_sun_ecl_long(jd)).collect()
+}
+
+/// Evaluates [`get_moon_ecl_long_to_sun`] at each of the given Julian days.
+pub fn get_moon_ecl_long_to_sun_many(jds: &[f64]) -> Vec<f64> {
+    jds.iter().map(|&jd| get_moon_ecl_long_to_sun(jd)).collect()
+}
+
+/// The solar term that begins between two consecutive sun ecliptic longitudes.
+pub(crate) fn solar_term_between(
+    curr_sun_ecl_long: f64,
+    next_sun_ecl_long: f64,
+) -> Option<SolarTerm> {
+    let curr_sun_ecl_long = if next_sun_ecl_long < curr_sun_ecl_long {
+        curr_sun_ecl_long - 360.0
+    } else {
+        curr_sun_ecl_long
+    };
+    SolarTerm::from_degree_range(curr_sun_ecl_long, next_sun_ecl_long)
+}
+
+/// The lunar phase between two consecutive moon ecliptic longitudes relative
+/// to the sun.
+pub(crate) fn lunar_phase_between(
+    curr_moon_ecl_long_to_sun: f64,
+    next_moon_ecl_long_to_sun: f64,
+) -> LunarPhase {
+    let curr_moon_ecl_long_to_sun = if next_moon_ecl_long_to_sun < curr_moon_ecl_long_to_sun {
+        curr_moon_ecl_long_to_sun - 360.0
+    } else {
+        curr_moon_ecl_long_to_sun
+    };
+    LunarPhase::from_degree_range(curr_moon_ecl_long_to_sun, next_moon_ecl_long_to_sun)
+}
+
+fn midnight_jds(begin: Date, end: Date, tz: f64) -> Vec<f64> {
+    (0..=(end - begin).max(0))
+        .map(|i| (begin + i).midnight_jd(tz))
+        .collect()
+}
+
+/// Returns the solar term of each day from `begin` (inclusive) to `end`
+/// (exclusive), as [`Date::solar_term`] would.
+///
+/// Each midnight is evaluated only once, instead of twice when calling
+/// [`Date::solar_term`] day by day.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug"))]
+pub fn get_solar_terms(begin: Date, end: Date, tz: f64) -> Vec<Option<SolarTerm>> {
+    get_sun_ecl_long_many(&midnight_jds(begin, end, tz))
+        .windows(2)
+        .map(|w| solar_term_between(w[0], w[1]))
+        .collect()
+}
+
+/// Returns the lunar phase of each day from `begin` (inclusive) to `end`
+/// (exclusive), as [`Date::lunar_phase`] would.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug"))]
+pub fn get_lunar_phases(begin: Date, end: Date, tz: f64) -> Vec<LunarPhase> {
+    get_moon_ecl_long_to_sun_many(&midnight_jds(begin, end, tz))
+        .windows(2)
+        .map(|w| lunar_phase_between(w[0], w[1]))
+        .collect()
+}
+
+/// Returns the mean ecliptic longitude of the sun in degrees.
+pub fn get_mean_sun_ecl_long(jd: f64) -> f64 {
+    let t = (jd - 2451545.0) / 36525.0;
+    (280.46646 + 36000.76983 * t + 0.0003032 * t * t).rem_euclid(360.0)
+}
+
+/// Returns the mean elongation of the moon from the sun in degrees.
+pub fn get_mean_moon_ecl_long_to_sun(jd: f64) -> f64 {
+    let t = (jd - 2451545.0) / 36525.0;
+    (297.8501921 + 445267.1114034 * t - 0.0018819 * t * t).rem_euclid(360.0)
+}
+
+/// Same as [`get_solar_terms`], but with the mean sun instead of the true sun
+/// (平气).
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug"))]
+pub fn get_mean_solar_terms(begin: Date, end: Date, tz: f64) -> Vec<Option<SolarTerm>> {
+    midnight_jds(begin, end, tz)
+        .into_iter()
+        .map(get_mean_sun_ecl_long)
+        .collect::<Vec<_>>()
+        .windows(2)
+        .map(|w| solar_term_between(w[0], w[1]))
+        .collect()
+}
+
+/// Same as [`get_lunar_phases`], but with the mean moon instead of the true
+/// moon (平朔).
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug"))]
+pub fn get_mean_lunar_phases(begin: Date, end: Date, tz: f64) -> Vec<LunarPhase> {
+    midnight_jds(begin, end, tz)
+        .into_iter()
+        .map(get_mean_moon_ecl_long_to_sun)
+        .collect::<Vec<_>>()
+        .windows(2)
+        .map(|w| lunar_phase_between(w[0], w[1]))
+        .collect()
+}
+
+/// Returns the days with a solar term from `begin` (inclusive) to `end`
+/// (exclusive).
+pub fn find_solar_terms(begin: Date, end: Date, tz: f64) -> Vec<(Date, SolarTerm)> {
+    get_solar_terms(begin, end, tz)
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, st)| st.map(|st| (begin + i as i32, st)))
+        .collect()
+}
+
+/// Returns the days of new moon from `begin` (inclusive) to `end` (exclusive).
+pub fn find_new_moons(begin: Date, end: Date, tz: f64) -> Vec<Date> {
+    get_lunar_phases(begin, end, tz)
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, lp)| (lp == NewMoon).then_some(begin + i as i32))
+        .collect()
+}
+
+/// The Julian date within `lo..hi` at which the longitude given by `long`
+/// increases through `degrees`.
+fn find_crossing(lo: f64, hi: f64, degrees: f64, long: impl Fn(f64) -> f64) -> f64 {
+    let offset = |jd| (long(jd) - degrees + 180.0).rem_euclid(360.0) - 180.0;
+    let (mut lo, mut hi) = (lo, hi);
+    while hi - lo > 1e-6 {
+        let mid = (lo + hi) / 2.0;
+        if offset(mid) < 0.0 {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    (lo + hi) / 2.0
+}
+
+/// Returns the instants, as Julian dates in TT, at which the apparent
+/// longitude of the sun reaches each solar term, for the solar terms from
+/// `begin` (inclusive) to `end` (exclusive).
+///
+/// The days of the solar terms follow the geometric longitude as
+/// [`get_solar_terms`] does, so an instant may fall minutes into the adjacent
+/// day.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug"))]
+pub fn find_solar_term_instants(begin: Date, end: Date, tz: f64) -> Vec<(f64, SolarTerm)> {
+    // The apparent longitude lags the geometric one by well under an hour.
+    const MARGIN: f64 = 1.0 / 24.0;
+    let jds = midnight_jds(begin, end, tz);
+    get_sun_ecl_long_many(&jds)
+        .windows(2)
+        .zip(jds.windows(2))
+        .filter_map(|(w, jd)| {
+            let term = solar_term_between(w[0], w[1])?;
+            let jd = find_crossing(
+                jd[0] - MARGIN,
+                jd[1] + MARGIN,
+                term.degrees(),
+                get_sun_apparent_ecl_long,
+            );
+            Some((jd, term))
+        })
+        .collect()
+}
+
+/// Returns the instants, as Julian dates in TT, of the new moons, first
+/// quarters, full moons and last quarters from `begin` (inclusive) to `end`
+/// (exclusive).
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug"))]
+pub fn find_lunar_phase_instants(begin: Date, end: Date, tz: f64) -> Vec<(f64, LunarPhase)> {
+    let jds = midnight_jds(begin, end, tz);
+    get_moon_ecl_long_to_sun_many(&jds)
+        .windows(2)
+        .zip(jds.windows(2))
+        .filter_map(|(w, jd)| {
+            let phase = lunar_phase_between(w[0], w[1]);
+            if !phase.is_principal() {
+                return None;
+            }
+            let jd = find_crossing(jd[0], jd[1], phase.degrees(), get_moon_ecl_long_to_sun);
+            Some((jd, phase))
+        })
+        .collect()
+}
+
+/// Returns the approximate illuminated fraction of the moon's disk, from 0 to
+/// 1, ignoring the latitude of the moon.
+pub fn get_moon_illumination(jd: f64) -> f64 {
+    (1.0 - get_moon_ecl_long_to_sun(jd).to_radians().cos()) / 2.0
+}
+
+#[test]
+fn test_batch_evaluation() {
+    let begin = Date::from_jdn(2460290);
+    let end = Date::from_jdn(2460310);
+    let solar_terms = get_solar_terms(begin, end, 8.0);
+    let lunar_phases = get_lunar_phases(begin, end, 8.0);
+    assert_eq!(solar_terms.len(), 20);
+    assert_eq!(lunar_phases.len(), 20);
+    for i in 0..20 {
+        let date = begin + i;
+        assert_eq!(solar_terms[i as usize], date.solar_term(8.0));
+        assert_eq!(lunar_phases[i as usize], date.lunar_phase(8.0));
+    }
+    assert_eq!(
+        find_solar_terms(begin, end, 8.0),
+        vec![(Date::from_jdn(2460301), WinterSolstice)]
+    );
+    assert_eq!(
+        find_new_moons(begin, end, 8.0),
+        vec![Date::from_jdn(2460292)]
+    );
+    assert!(get_solar_terms(end, begin, 8.0).is_empty());
+
+    let instants = find_lunar_phase_instants(begin, end, 8.0);
+    let expected = [(2, NewMoon), (9, FirstQuarter), (16, FullMoon)];
+    assert_eq!(instants.len(), expected.len());
+    for ((jd, lp), (days, expected_lp)) in instants.into_iter().zip(expected) {
+        assert_eq!(lp, expected_lp);
+        assert_eq!(Date::from_jd_with_tz(jd, 8.0), begin + days);
+        let offset =
+            (get_moon_ecl_long_to_sun(jd) - lp.degrees() + 180.0).rem_euclid(360.0) - 180.0;
+        assert!(offset.abs() < 1e-3);
+    }
+    let instants = find_solar_term_instants(begin, end, 8.0);
+    assert_eq!(instants.len(), 1);
+    let (jd, term) = instants[0];
+    assert_eq!(term, WinterSolstice);
+    assert_eq!(Date::from_jd_with_tz(jd, 8.0), Date::from_jdn(2460301));
+    assert!((get_sun_apparent_ecl_long(jd) - 270.0).abs() < 1e-3);
+
+    let new_moon = Date::from_jdn(2460292).noon_jd(8.0);
+    assert!(get_moon_illumination(new_moon) < 0.01);
+    assert!(get_moon_illumination(new_moon + 14.8) > 0.99);
+
+    let mean_solar_terms = get_mean_solar_terms(begin, end, 8.0);
+    let mean_lunar_phases = get_mean_lunar_phases(begin, end, 8.0);
+    assert_eq!(mean_solar_terms.len(), 20);
+    assert_eq!(mean_lunar_phases.len(), 20);
+    assert_eq!(mean_solar_terms.iter().filter(|st| st.is_some()).count(), 1);
+    assert_eq!(
+        mean_lunar_phases
+            .iter()
+            .filter(|lp| **lp == NewMoon)
+            .count(),
+        1
+    );
+}
+
+/// The Julian day of the first new moon in 2000 (Meeus lunation 0).
+const LUNATION_EPOCH_JD: f64 = 2451550.09766;
+const MEAN_SYNODIC_MONTH: f64 = 29.530588861;
+/// The Brown lunation number of Meeus lunation 0.
+const BROWN_LUNATION_OFFSET: i32 = 953;
+
+/// A synodic month, from the day of a new moon to the day before the next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lunation {
+    number: i32,
+    first_day: Date,
+    next_first_day: Date,
+}
+
+impl Lunation {
+    /// The lunation with the given Brown lunation number.
+    pub fn from_number(number: i32, tz: f64) -> Self {
+        let jd = LUNATION_EPOCH_JD + (number - BROWN_LUNATION_OFFSET) as f64 * MEAN_SYNODIC_MONTH;
+        let mut first_day = Date::from_jd_with_tz(jd, tz) + 3;
+        while first_day.lunar_phase(tz) != NewMoon {
+            first_day = first_day.pred();
+        }
+        Self::from_first_day(first_day, tz)
+    }
+
+    /// The lunation containing the given date.
+    pub fn from_date(date: Date, tz: f64) -> Self {
+        let mut first_day = date;
+        while first_day.lunar_phase(tz) != NewMoon {
+            first_day = first_day.pred();
+        }
+        Self::from_first_day(first_day, tz)
+    }
+
+    fn from_first_day(first_day: Date, tz: f64) -> Self {
+        let mut next_first_day = first_day + 29;
+        while next_first_day.lunar_phase(tz) != NewMoon {
+            next_first_day = next_first_day.succ();
+        }
+        let k =
+            ((first_day.midnight_jd(tz) + 0.5 - LUNATION_EPOCH_JD) / MEAN_SYNODIC_MONTH).round();
+        Self {
+            number: k as i32 + BROWN_LUNATION_OFFSET,
+            first_day,
+            next_first_day,
+        }
+    }
+
+    /// The Brown lunation number, counting from the new moon of 1923-01-17.
+    pub fn number(&self) -> i32 {
+        self.number
+    }
+
+    /// The lunation number used by Meeus, counting from the new moon of
+    /// 2000-01-06.
+    pub fn meeus_number(&self) -> i32 {
+        self.number - BROWN_LUNATION_OFFSET
+    }
+
+    /// The day of the new moon beginning the lunation.
+    pub fn first_day(&self) -> Date {
+        self.first_day
+    }
+
+    /// The day before the new moon ending the lunation.
+    pub fn last_day(&self) -> Date {
+        self.next_first_day.pred()
+    }
+
+    pub fn num_days(&self) -> usize {
+        (self.next_first_day - self.first_day) as usize
+    }
+
+    pub fn contains(&self, date: Date) -> bool {
+        (self.first_day..self.next_first_day).contains(&date)
+    }
+}
+
+#[test]
+fn test_lunation() {
+    let lunation = Lunation::from_number(1, 0.0);
+    assert_eq!(lunation.first_day(), Date::from_jdn(2423437));
+    assert_eq!(lunation.meeus_number(), -952);
+
+    let lunation = Lunation::from_number(953, 0.0);
+    assert_eq!(lunation.first_day(), Date::from_jdn(2451550));
+    assert_eq!(lunation.meeus_number(), 0);
+
+    let lunation = Lunation::from_date(Date::from_jdn(2460300), 8.0);
+    assert_eq!(lunation.number(), 1249);
+    assert_eq!(lunation.first_day(), Date::from_jdn(2460292));
+    assert_eq!(lunation.last_day(), Date::from_jdn(2460320));
+    assert_eq!(lunation.num_days(), 29);
+    assert!(lunation.contains(Date::from_jdn(2460300)));
+    assert!(!lunation.contains(Date::from_jdn(2460321)));
+    assert_eq!(Lunation::from_number(1249, 8.0), lunation);
+    assert_eq!(
+        Lunation::from_number(1250, 8.0).first_day(),
+        Date::from_jdn(2460321)
+    );
+}
+
+/// Returns the nutation in ecliptic longitude and in obliquity, in degrees.
+pub fn get_nutation(jd: f64) -> (f64, f64) {
+    let (nut_in_long, nut_in_oblq) = astro::nutation::nutation(jd);
+    (nut_in_long.to_degrees(), nut_in_oblq.to_degrees())
+}
+
+/// Returns the mean obliquity of the ecliptic in degrees.
+pub fn get_mean_obliquity(jd: f64) -> f64 {
+    astro::ecliptic::mn_oblq_laskar(jd).to_degrees()
+}
+
+/// Returns the true obliquity of the ecliptic (mean obliquity corrected for
+/// nutation) in degrees.
+pub fn get_true_obliquity(jd: f64) -> f64 {
+    let (_, nut_in_oblq) = get_nutation(jd);
+    get_mean_obliquity(jd) + nut_in_oblq
+}
+
+/// Returns the mean sidereal time at Greenwich in degrees.
+pub fn get_greenwich_mean_sidereal_time(jd: f64) -> f64 {
+    astro::time::mn_sidr(jd).to_degrees()
+}
+
+/// Returns the apparent sidereal time at Greenwich in degrees.
+pub fn get_greenwich_apparent_sidereal_time(jd: f64) -> f64 {
+    let (nut_in_long, _) = get_nutation(jd);
+    let true_oblq = get_true_obliquity(jd);
+    (get_greenwich_mean_sidereal_time(jd) + nut_in_long * true_oblq.to_radians().cos())
+        .rem_euclid(360.0)
+}
+
+/// Returns the local mean sidereal time in degrees.
+///
+/// The longitude `lon` is in degrees, positive east of Greenwich.
+pub fn get_local_mean_sidereal_time(jd: f64, lon: f64) -> f64 {
+    (get_greenwich_mean_sidereal_time(jd) + lon).rem_euclid(360.0)
+}
+
+/// Returns the local apparent sidereal time in degrees.
+///
+/// The longitude `lon` is in degrees, positive east of Greenwich.
+pub fn get_local_apparent_sidereal_time(jd: f64, lon: f64) -> f64 {
+    (get_greenwich_apparent_sidereal_time(jd) + lon).rem_euclid(360.0)
+}
+
+#[test]
+fn test_sidereal_time_and_obliquity() {
+    // Example 12.a and 22.a in Astronomical Algorithms by Jean Meeus.
+    let jd = 2446895.5;
+    assert!((get_greenwich_mean_sidereal_time(jd) - 197.693195).abs() < 1e-5);
+    assert!((get_greenwich_apparent_sidereal_time(jd) - 197.692226).abs() < 1e-4);
+    assert!((get_local_mean_sidereal_time(jd, 116.4) - 314.093195).abs() < 1e-5);
+    assert!((get_local_mean_sidereal_time(jd, 180.0) - 17.693195).abs() < 1e-5);
+    assert!((get_mean_obliquity(jd) - 23.440946).abs() < 1e-5);
+    assert!((get_true_obliquity(jd) - 23.443569).abs() < 1e-4);
+}
+
+/// The altitude of the center of the sun at sunrise and sunset in degrees,
+/// allowing for refraction and the radius of the sun.
+pub const SUNRISE_ALTITUDE: f64 = -0.833;
+/// The altitude of the sun at the beginning and end of civil twilight.
+pub const CIVIL_TWILIGHT_ALTITUDE: f64 = -6.0;
+/// The altitude of the sun at the beginning and end of nautical twilight.
+pub const NAUTICAL_TWILIGHT_ALTITUDE: f64 = -12.0;
+/// The altitude of the sun at the beginning and end of astronomical twilight.
+pub const ASTRONOMICAL_TWILIGHT_ALTITUDE: f64 = -18.0;
+
+/// The rate of the hour angle of the sun in degrees per day.
+const HOUR_ANGLE_RATE: f64 = 360.9856;
+
+/// Returns the apparent right ascension and declination of the sun in degrees.
+pub fn get_sun_equatorial(jd: f64) -> (f64, f64) {
+    let long = get_sun_apparent_ecl_long(jd).to_radians();
+    let oblq = get_true_obliquity(jd).to_radians();
+    let ra = (oblq.cos() * long.sin()).atan2(long.cos()).to_degrees();
+    let dec = (oblq.sin() * long.sin()).asin().to_degrees();
+    (ra.rem_euclid(360.0), dec)
+}
+
+/// The local hour angle of the sun in degrees, from -180 to 180.
+fn sun_hour_angle(jd: f64, lon: f64) -> f64 {
+    let (ra, _) = get_sun_equatorial(jd);
+    (get_local_apparent_sidereal_time(jd, lon) - ra + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// Returns the Julian date (UT) of the solar noon, i.e. the transit of the
+/// sun, on the given day at the longitude `lon` (positive east).
+pub fn find_solar_noon(date: Date, lon: f64, tz: f64) -> f64 {
+    let mut jd = date.noon_jd(tz) + (tz * 15.0 - lon) / 360.0;
+    for _ in 0..3 {
+        jd -= sun_hour_angle(jd, lon) / HOUR_ANGLE_RATE;
+    }
+    jd
+}
+
+/// When the sun crosses an altitude on a day.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AltitudeCrossing {
+    /// The sun rises above the altitude and sets below it, at the given Julian
+    /// dates (UT).
+    RiseSet { rise: f64, set: f64 },
+    /// The sun stays above the altitude all day, e.g. in the polar summer.
+    AlwaysAbove,
+    /// The sun stays below the altitude all day, e.g. in the polar winter.
+    AlwaysBelow,
+}
+
+/// Returns when the sun crosses the given altitude (in degrees) on the given
+/// day at latitude `lat` and longitude `lon` (positive north and east).
+pub fn find_altitude_crossing(
+    date: Date,
+    lat: f64,
+    lon: f64,
+    altitude: f64,
+    tz: f64,
+) -> AltitudeCrossing {
+    let noon = find_solar_noon(date, lon, tz);
+    // The hour angle of the sun at the altitude, if it reaches the altitude.
+    let hour_angle = |jd: f64| {
+        let (_, dec) = get_sun_equatorial(jd);
+        let (lat, dec) = (lat.to_radians(), dec.to_radians());
+        (altitude.to_radians().sin() - lat.sin() * dec.sin()) / (lat.cos() * dec.cos())
+    };
+    let cos_h0 = hour_angle(noon);
+    if cos_h0 < -1.0 {
+        return AltitudeCrossing::AlwaysAbove;
+    }
+    if cos_h0 > 1.0 {
+        return AltitudeCrossing::AlwaysBelow;
+    }
+    // Refine each crossing with the declination at its own time.
+    let crossing = |sign: f64| {
+        let mut jd = noon + sign * cos_h0.acos().to_degrees() / HOUR_ANGLE_RATE;
+        for _ in 0..2 {
+            let h0 = hour_angle(jd).clamp(-1.0, 1.0).acos().to_degrees();
+            jd += (sign * h0 - sun_hour_angle(jd, lon)) / HOUR_ANGLE_RATE;
+        }
+        jd
+    };
+    AltitudeCrossing::RiseSet {
+        rise: crossing(-1.0),
+        set: crossing(1.0),
+    }
+}
+
+#[test]
+fn test_solar_events() {
+    // Beijing (39.9°N, 116.4°E) in UTC+8, compared with almanac minutes.
+    let (lat, lon) = (39.9, 116.4);
+    let local = |jd: f64| (jd + 0.5 + 8.0 / 24.0).fract() * 24.0 * 60.0;
+    // 2024-06-21 and 2024-12-21.
+    let summer = Date::from_jdn(2460483);
+    assert!((local(find_solar_noon(summer, lon, 8.0)) - (12.0 * 60.0 + 15.0)).abs() < 2.0);
+    let AltitudeCrossing::RiseSet { rise, set } =
+        find_altitude_crossing(summer, lat, lon, SUNRISE_ALTITUDE, 8.0)
+    else {
+        panic!("no sunrise");
+    };
+    assert!((local(rise) - (4.0 * 60.0 + 46.0)).abs() < 2.0);
+    assert!((local(set) - (19.0 * 60.0 + 46.0)).abs() < 2.0);
+    let winter = Date::from_jdn(2460666);
+    let AltitudeCrossing::RiseSet { rise, set } =
+        find_altitude_crossing(winter, lat, lon, SUNRISE_ALTITUDE, 8.0)
+    else {
+        panic!("no sunrise");
+    };
+    assert!((local(rise) - (7.0 * 60.0 + 33.0)).abs() < 2.0);
+    assert!((local(set) - (16.0 * 60.0 + 53.0)).abs() < 2.0);
+
+    // Tromsø (69.6°N) has the midnight sun and the polar night.
+    assert_eq!(
+        find_altitude_crossing(summer, 69.6, 19.0, SUNRISE_ALTITUDE, 2.0),
+        AltitudeCrossing::AlwaysAbove
+    );
+    assert_eq!(
+        find_altitude_crossing(winter, 69.6, 19.0, SUNRISE_ALTITUDE, 1.0),
+        AltitudeCrossing::AlwaysBelow
+    );
+    assert!(matches!(
+        find_altitude_crossing(winter, 69.6, 19.0, CIVIL_TWILIGHT_ALTITUDE, 1.0),
+        AltitudeCrossing::RiseSet { .. }
+    ));
 }
 
 // fn solve(func: fn(f64) -> f64, left: f64, right: f64, eps: f64) -> f64 {
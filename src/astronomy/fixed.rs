@@ -0,0 +1,146 @@
+//! The ecliptic longitudes of the sun and the moon in integer arithmetic, so
+//! that the days computed from them are the same on every platform.
+//!
+//! The `f64` functions of [`astronomy`](super) call `sin` and `cos` of the
+//! math library of the platform, whose last bits may differ between
+//! platforms, and so may the day of a new moon or a solar term close to
+//! midnight. The functions here evaluate the same series with integers only:
+//! VSOP87 for the sun, without the terms under 1e-7 rad within 4000 years
+//! of J2000, and chapter 47 of Meeus' *Astronomical Algorithms* for the
+//! moon. Their coefficients are converted from the published values at
+//! compile time, which gives the same bits on every platform.
+//!
+//! Angles are `u64` in 2<sup>-64</sup> turns, wrapping around at a full turn,
+//! and instants are seconds from J2000 (JD 2451545.0).
+//!
+//! The Chinese calendar uses them with [`Mode::Deterministic`], and so does
+//! the build script with the `gen-tables` feature, whose table of 1900–2100
+//! the `chinese-table` feature looks up in that mode.
+//!
+//! [`Mode::Deterministic`]: crate::chinese::Mode::Deterministic
+
+use std::sync::atomic::Ordering::Relaxed;
+
+use crate::*;
+
+mod series;
+
+pub use series::{cos, sin};
+use series::{sectors, TURN};
+
+/// The midnight at the start of the date in the given timezone.
+pub fn midnight_seconds(date: Date, tz: f64) -> i64 {
+    (date.jdn() as i64 - 2451545) * 86400 - 43200 - (tz * 3600.0).round() as i64
+}
+
+/// Converts an angle to degrees in `0.0..360.0`.
+pub fn to_degrees(angle: u64) -> f64 {
+    angle as f64 * (360.0 / TURN)
+}
+
+/// Returns the geocentric ecliptic longitude of the sun, as
+/// [`astronomy::get_sun_ecl_long`](super::get_sun_ecl_long) does.
+pub fn get_sun_ecl_long(t: i64) -> u64 {
+    super::SUN_EVALUATIONS.fetch_add(1, Relaxed);
+    series::sun_ecl_long(t)
+}
+
+/// Returns the geocentric ecliptic longitude of the moon, as
+/// [`astronomy::get_moon_ecl_long`](super::get_moon_ecl_long) does.
+pub fn get_moon_ecl_long(t: i64) -> u64 {
+    super::MOON_EVALUATIONS.fetch_add(1, Relaxed);
+    series::moon_ecl_long(t)
+}
+
+/// Returns the ecliptic longitude of the moon relative to the sun.
+pub fn get_moon_ecl_long_to_sun(t: i64) -> u64 {
+    get_moon_ecl_long(t).wrapping_sub(get_sun_ecl_long(t))
+}
+
+/// The solar term that begins between two consecutive sun ecliptic
+/// longitudes.
+pub fn solar_term_between(curr_sun_ecl_long: u64, next_sun_ecl_long: u64) -> Option<SolarTerm> {
+    let (begin, end) = sectors(curr_sun_ecl_long, next_sun_ecl_long, 24);
+    (begin < end).then(|| SolarTerm::from_repr((begin - 270 / 15).rem_euclid(24) as usize).unwrap())
+}
+
+/// The lunar phase between two consecutive moon ecliptic longitudes relative
+/// to the sun.
+pub fn lunar_phase_between(
+    curr_moon_ecl_long_to_sun: u64,
+    next_moon_ecl_long_to_sun: u64,
+) -> LunarPhase {
+    let (begin, end) = sectors(curr_moon_ecl_long_to_sun, next_moon_ecl_long_to_sun, 4);
+    let ord = if begin < end {
+        begin * 2
+    } else {
+        begin * 2 - 1
+    };
+    LunarPhase::from_repr(ord.rem_euclid(8) as usize).unwrap()
+}
+
+fn midnights(begin: Date, end: Date, tz: f64) -> Vec<i64> {
+    (0..=(end - begin).max(0))
+        .map(|i| midnight_seconds(begin + i, tz))
+        .collect()
+}
+
+/// Same as [`astronomy::get_solar_terms`](super::get_solar_terms), but
+/// computed in integer arithmetic.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug"))]
+pub fn get_solar_terms(begin: Date, end: Date, tz: f64) -> Vec<Option<SolarTerm>> {
+    midnights(begin, end, tz)
+        .into_iter()
+        .map(get_sun_ecl_long)
+        .collect::<Vec<_>>()
+        .windows(2)
+        .map(|w| solar_term_between(w[0], w[1]))
+        .collect()
+}
+
+/// Same as [`astronomy::get_lunar_phases`](super::get_lunar_phases), but
+/// computed in integer arithmetic.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug"))]
+pub fn get_lunar_phases(begin: Date, end: Date, tz: f64) -> Vec<LunarPhase> {
+    midnights(begin, end, tz)
+        .into_iter()
+        .map(get_moon_ecl_long_to_sun)
+        .collect::<Vec<_>>()
+        .windows(2)
+        .map(|w| lunar_phase_between(w[0], w[1]))
+        .collect()
+}
+
+#[test]
+fn test_fixed() {
+    for i in 0..64 {
+        let angle = (i as u64) << 58 | 0x123456789;
+        let x = angle as f64 / TURN * std::f64::consts::TAU;
+        assert!((sin(angle) as f64 / series::ONE as f64 - x.sin()).abs() < 1e-12);
+        assert!((cos(angle) as f64 / series::ONE as f64 - x.cos()).abs() < 1e-12);
+    }
+    assert_eq!(sin(0), 0);
+    assert_eq!(cos(0) as i128, series::ONE);
+    assert_eq!(to_degrees(series::HALF_TURN), 180.0);
+
+    let date: Date = GregorianCalendar::from_ymd(2024, 2, 10).unwrap().into();
+    assert_eq!(midnight_seconds(date, 8.0), 8806 * 86400 - 43200 - 28800);
+    for date in testing::Dates::new(1, -2000..=6000).take(200) {
+        let t = midnight_seconds(date, 8.0);
+        let jd = date.midnight_jd(8.0);
+        let diff = |a: u64, b: f64| ((to_degrees(a) - b + 180.0).rem_euclid(360.0) - 180.0).abs();
+        assert!(diff(get_sun_ecl_long(t), astronomy::get_sun_ecl_long(jd)) < 1e-4);
+        assert!(diff(get_moon_ecl_long(t), astronomy::get_moon_ecl_long(jd)) < 1e-5);
+    }
+
+    let begin: Date = GregorianCalendar::from_ymd(2023, 1, 1).unwrap().into();
+    let end = begin + 365;
+    assert_eq!(
+        get_solar_terms(begin, end, 8.0),
+        astronomy::get_solar_terms(begin, end, 8.0)
+    );
+    assert_eq!(
+        get_lunar_phases(begin, end, 8.0),
+        astronomy::get_lunar_phases(begin, end, 8.0)
+    );
+}
@@ -0,0 +1,528 @@
+//! The series of the ecliptic longitudes of the sun and the moon in integer
+//! arithmetic.
+//!
+//! It uses nothing else of the crate, so that the build script can include it
+//! to generate the tables of the `gen-tables` feature.
+
+// Some terms of VSOP87 have a phase of π, as published.
+#![allow(clippy::approx_constant)]
+
+const CENTURY_SECONDS: i64 = 36525 * 86400;
+const MILLENNIUM_SECONDS: i64 = 10 * CENTURY_SECONDS;
+pub const TURN: f64 = 18446744073709551616.0;
+pub const HALF_TURN: u64 = 1 << 63;
+/// 1 in the fixed-point numbers of 62 fractional bits returned by [`sin`]
+/// and [`cos`].
+pub const ONE: i128 = 1 << 62;
+
+const fn round(x: f64) -> i128 {
+    if x < 0.0 {
+        (x - 0.5) as i128
+    } else {
+        (x + 0.5) as i128
+    }
+}
+
+/// Radians to turns.
+const fn from_radians(x: f64) -> i128 {
+    round(x / std::f64::consts::TAU * TURN)
+}
+
+/// Degrees to turns.
+const fn from_degrees(x: f64) -> i128 {
+    round(x / 360.0 * TURN)
+}
+
+/// The sine of an angle within an eighth of a turn, by its Taylor series.
+fn sin_octant(x: u64) -> i128 {
+    let x = radians(x);
+    let x2 = (x * x) >> 62;
+    let mut y = ONE;
+    for d in [156, 110, 72, 42, 20, 6] {
+        y = ONE - ((x2 * y) >> 62) / d;
+    }
+    (x * y) >> 62
+}
+
+/// The cosine of an angle within an eighth of a turn, by its Taylor series.
+fn cos_octant(x: u64) -> i128 {
+    let x = radians(x);
+    let x2 = (x * x) >> 62;
+    let mut y = ONE;
+    for d in [182, 132, 90, 56, 30, 12, 2] {
+        y = ONE - ((x2 * y) >> 62) / d;
+    }
+    y
+}
+
+/// An angle under a quarter turn in radians, with 62 fractional bits.
+fn radians(x: u64) -> i128 {
+    const TAU: u128 = (std::f64::consts::TAU * (1u64 << 61) as f64) as u128;
+    ((x as u128 * TAU) >> 63) as i128
+}
+
+/// The sine of an angle, with 62 fractional bits.
+pub fn sin(angle: u64) -> i64 {
+    const QUARTER: u64 = 1 << 62;
+    let x = angle & (QUARTER - 1);
+    let y = match (x < QUARTER / 2, angle >> 62) {
+        (true, 0 | 2) => sin_octant(x),
+        (false, 0 | 2) => cos_octant(QUARTER - x),
+        (true, _) => cos_octant(x),
+        (false, _) => sin_octant(QUARTER - x),
+    };
+    (if angle >> 62 < 2 { y } else { -y }) as i64
+}
+
+/// The cosine of an angle, with 62 fractional bits.
+pub fn cos(angle: u64) -> i64 {
+    sin(angle.wrapping_add(1 << 62))
+}
+
+/// A term `a cos(b + c τ)` of VSOP87, where τ is in Julian millennia.
+struct Term {
+    amplitude: i64,
+    phase: u64,
+    /// The change of the phase per second.
+    rate: u64,
+}
+
+const fn term(a: f64, b: f64, c: f64) -> Term {
+    Term {
+        amplitude: from_radians(a) as i64,
+        phase: from_radians(b) as u64,
+        rate: from_radians(c / MILLENNIUM_SECONDS as f64) as u64,
+    }
+}
+
+fn series(terms: &[Term], t: i64) -> i128 {
+    terms
+        .iter()
+        .map(|term| {
+            let phase = term.phase.wrapping_add(term.rate.wrapping_mul(t as u64));
+            (term.amplitude as i128 * cos(phase) as i128) >> 62
+        })
+        .sum()
+}
+
+/// The first term of `L1`, the mean motion of the Earth, which is kept
+/// exact as a change per second.
+const SUN_MEAN_MOTION: u64 = from_radians(6283.31966747491 / MILLENNIUM_SECONDS as f64) as u64;
+
+/// The geocentric ecliptic longitude of the sun at `t` seconds from J2000.
+pub fn sun_ecl_long(t: i64) -> u64 {
+    // Julian millennia with 40 fractional bits.
+    let tau = ((t as i128) << 40).div_euclid(MILLENNIUM_SECONDS as i128);
+    let mut long = 0;
+    let mut power = 1 << 40;
+    for terms in [&L0[..], &L1, &L2, &L3, &L4, &L5] {
+        long += (series(terms, t) * power) >> 40;
+        power = (power * tau) >> 40;
+    }
+    (long as u64)
+        .wrapping_add(SUN_MEAN_MOTION.wrapping_mul(t as u64))
+        .wrapping_add(HALF_TURN)
+}
+
+/// A polynomial of the Julian centuries, from the coefficients in degrees.
+struct Polynomial {
+    constant: u64,
+    /// The change per second of the linear term.
+    rate: u64,
+    higher: [i128; 3],
+}
+
+const fn polynomial(c: [f64; 5]) -> Polynomial {
+    Polynomial {
+        constant: from_degrees(c[0]) as u64,
+        rate: from_degrees(c[1] / CENTURY_SECONDS as f64) as u64,
+        higher: [from_degrees(c[2]), from_degrees(c[3]), from_degrees(c[4])],
+    }
+}
+
+impl Polynomial {
+    /// The value at `t`, whose powers of Julian centuries from the second
+    /// have 32 fractional bits.
+    fn eval(&self, t: i64, powers: &[i128; 3]) -> u64 {
+        let higher: i128 = self.higher.iter().zip(powers).map(|(c, p)| c * p).sum();
+        self.constant
+            .wrapping_add(self.rate.wrapping_mul(t as u64))
+            .wrapping_add((higher >> 32) as u64)
+    }
+}
+
+/// The mean longitude of the moon.
+const MOON_MEAN_LONG: Polynomial = polynomial([
+    218.3164477,
+    481267.88123421,
+    -0.0015786,
+    1.0 / 538841.0,
+    -1.0 / 65194000.0,
+]);
+/// The mean elongation of the moon.
+const MOON_MEAN_ELONGATION: Polynomial = polynomial([
+    297.8501921,
+    445267.1114034,
+    -0.0018819,
+    1.0 / 545868.0,
+    -1.0 / 113065000.0,
+]);
+/// The mean anomaly of the sun.
+const SUN_MEAN_ANOMALY: Polynomial = polynomial([
+    357.5291092,
+    35999.0502909,
+    -0.0001536,
+    1.0 / 24490000.0,
+    0.0,
+]);
+/// The mean anomaly of the moon.
+const MOON_MEAN_ANOMALY: Polynomial = polynomial([
+    134.9633964,
+    477198.8675055,
+    0.0087414,
+    1.0 / 69699.0,
+    -1.0 / 14712000.0,
+]);
+/// The argument of latitude of the moon.
+const MOON_ARGUMENT_OF_LATITUDE: Polynomial = polynomial([
+    93.272095,
+    483202.0175233,
+    -0.0036539,
+    -1.0 / 3526000.0,
+    1.0 / 863310000.0,
+]);
+const A1: Polynomial = polynomial([119.75, 131.849, 0.0, 0.0, 0.0]);
+const A2: Polynomial = polynomial([53.09, 479264.29, 0.0, 0.0, 0.0]);
+
+/// The geocentric ecliptic longitude of the moon at `t` seconds from J2000.
+pub fn moon_ecl_long(t: i64) -> u64 {
+    // Julian centuries with 32 fractional bits.
+    let t1 = ((t as i128) << 32).div_euclid(CENTURY_SECONDS as i128);
+    let t2 = (t1 * t1) >> 32;
+    let powers = [t2, (t2 * t1) >> 32, (t2 * t2) >> 32];
+    let l1 = MOON_MEAN_LONG.eval(t, &powers);
+    let d = MOON_MEAN_ELONGATION.eval(t, &powers);
+    let m = SUN_MEAN_ANOMALY.eval(t, &powers);
+    let m1 = MOON_MEAN_ANOMALY.eval(t, &powers);
+    let f = MOON_ARGUMENT_OF_LATITUDE.eval(t, &powers);
+    // The eccentricity of the orbit of the Earth, decreasing.
+    let e = ONE
+        - ((round(0.002516 * ONE as f64) * t1) >> 32)
+        - ((round(0.0000074 * ONE as f64) * t2) >> 32);
+
+    // In millionths of a degree, with 62 fractional bits.
+    let mut sum: i128 = 0;
+    for &(nd, nm, nm1, nf, coefficient) in &MOON_TERMS {
+        let arg = [(nd, d), (nm, m), (nm1, m1), (nf, f)]
+            .iter()
+            .fold(0u64, |arg, &(n, x)| {
+                arg.wrapping_add((n as u64).wrapping_mul(x))
+            });
+        let mut y = sin(arg) as i128;
+        for _ in 0..nm.unsigned_abs() {
+            y = (y * e) >> 62;
+        }
+        sum += coefficient as i128 * y;
+    }
+    sum += 3958 * sin(A1.eval(t, &powers)) as i128
+        + 1962 * sin(l1.wrapping_sub(f)) as i128
+        + 318 * sin(A2.eval(t, &powers)) as i128;
+    l1.wrapping_add((sum * 4).div_euclid(360_000_000) as u64)
+}
+
+/// The index of the first of `n` equal sectors of a turn whose start is at
+/// or after the angle, which may be negative.
+fn sector(angle: i128, n: i128) -> i128 {
+    -((-angle * n) >> 64)
+}
+
+/// The sectors of the angles, the first one unwrapped to be before the
+/// second.
+pub fn sectors(curr: u64, next: u64, n: i128) -> (i128, i128) {
+    let curr = curr as i128 - if next < curr { 1 << 64 } else { 0 };
+    (sector(curr, n), sector(next as i128, n))
+}
+
+/// The terms of VSOP87D for the longitude of the Earth, in radians, each
+/// series multiplied by the next power of τ.
+static L0: [Term; 93] = [
+    term(1.75347045673, 0.0, 0.0),
+    term(0.03341656456, 4.66925680417, 6283.0758499914),
+    term(0.00034894275, 4.62610241759, 12566.1516999828),
+    term(3.417571e-05, 2.82886579606, 3.523118349),
+    term(3.497056e-05, 2.74411800971, 5753.3848848968),
+    term(3.135896e-05, 3.62767041758, 77713.7714681205),
+    term(2.676218e-05, 4.41808351397, 7860.4193924392),
+    term(2.342687e-05, 6.13516237631, 3930.2096962196),
+    term(1.273166e-05, 2.03709655772, 529.6909650946),
+    term(1.324292e-05, 0.74246356352, 11506.7697697936),
+    term(9.01855e-06, 2.04505443513, 26.2983197998),
+    term(1.199167e-05, 1.10962944315, 1577.3435424478),
+    term(8.57223e-06, 3.50849156957, 398.1490034082),
+    term(7.79786e-06, 1.17882652114, 5223.6939198022),
+    term(9.9025e-06, 5.23268129594, 5884.9268465832),
+    term(7.53141e-06, 2.53339053818, 5507.5532386674),
+    term(5.05264e-06, 4.58292563052, 18849.2275499742),
+    term(4.92379e-06, 4.20506639861, 775.522611324),
+    term(3.56655e-06, 2.91954116867, 0.0673103028),
+    term(2.84125e-06, 1.89869034186, 796.2980068164),
+    term(2.4281e-06, 0.34481140906, 5486.777843175),
+    term(3.17087e-06, 5.84901952218, 11790.6290886588),
+    term(2.71039e-06, 0.31488607649, 10977.078804699),
+    term(2.0616e-06, 4.80646606059, 2544.3144198834),
+    term(2.05385e-06, 1.86947813692, 5573.1428014331),
+    term(2.02261e-06, 2.45767795458, 6069.7767545534),
+    term(1.26184e-06, 1.0830263021, 20.7753954924),
+    term(1.55516e-06, 0.83306073807, 213.299095438),
+    term(1.15132e-06, 0.64544911683, 0.9803210682),
+    term(1.02851e-06, 0.63599846727, 4694.0029547076),
+    term(1.01724e-06, 4.26679821365, 7.1135470008),
+    term(9.9206e-07, 6.20992940258, 2146.1654164752),
+    term(1.32212e-06, 3.41118275555, 2942.4634232916),
+    term(9.7607e-07, 0.6810127227, 155.4203994342),
+    term(8.5128e-07, 1.29870743025, 6275.9623029906),
+    term(7.4651e-07, 1.75508916159, 5088.6288397668),
+    term(1.01895e-06, 0.97569221824, 15720.8387848784),
+    term(8.4711e-07, 3.67080093025, 71430.69561812909),
+    term(7.3547e-07, 4.67926565481, 801.8209311238),
+    term(7.3874e-07, 3.50319443167, 3154.6870848956),
+    term(7.8756e-07, 3.03698313141, 12036.4607348882),
+    term(7.9637e-07, 1.807913307, 17260.1546546904),
+    term(8.5803e-07, 5.98322631256, 161000.6857376741),
+    term(5.6963e-07, 2.78430398043, 6286.5989683404),
+    term(6.1148e-07, 1.81839811024, 7084.8967811152),
+    term(6.9627e-07, 0.83297596966, 9437.762934887),
+    term(5.6116e-07, 4.38694880779, 14143.4952424306),
+    term(6.2449e-07, 3.97763880587, 8827.3902698748),
+    term(5.1145e-07, 0.28306864501, 5856.4776591154),
+    term(5.5577e-07, 3.47006009062, 6279.5527316424),
+    term(4.1036e-07, 5.36817351402, 8429.2412664666),
+    term(5.1605e-07, 1.33282746983, 1748.016413067),
+    term(5.1992e-07, 0.18914945834, 12139.5535091068),
+    term(4.9e-07, 0.48735065033, 1194.4470102246),
+    term(3.92e-07, 6.16832995016, 10447.3878396044),
+    term(3.5566e-07, 1.77597314691, 6812.766815086),
+    term(3.677e-07, 6.04133859347, 10213.285546211),
+    term(3.6596e-07, 2.56955238628, 1059.3819301892),
+    term(3.3291e-07, 0.59309499459, 17789.845619785),
+    term(3.5954e-07, 1.70876111898, 2352.8661537718),
+    term(4.0938e-07, 2.39850881707, 19651.048481098),
+    term(3.0047e-07, 2.73975123935, 1349.8674096588),
+    term(3.0412e-07, 0.44294464135, 83996.84731811189),
+    term(2.3663e-07, 0.48473567763, 8031.0922630584),
+    term(2.3574e-07, 2.06527720049, 3340.6124266998),
+    term(2.1089e-07, 4.14825464101, 951.7184062506),
+    term(2.4738e-07, 0.21484762138, 3.5904286518),
+    term(2.5352e-07, 3.16470953405, 4690.4798363586),
+    term(2.282e-07, 5.22197888032, 4705.7323075436),
+    term(2.1419e-07, 1.42563735525, 16730.4636895958),
+    term(2.1891e-07, 5.55594302562, 553.5694028424),
+    term(1.7481e-07, 4.56052900359, 135.0650800354),
+    term(1.9925e-07, 5.22208471269, 12168.0026965746),
+    term(1.986e-07, 5.77470167653, 6309.3741697912),
+    term(2.03e-07, 0.37133792946, 283.8593188652),
+    term(1.4421e-07, 4.19315332546, 242.728603974),
+    term(1.6225e-07, 5.98837722564, 11769.8536931664),
+    term(1.5077e-07, 4.19567181073, 6256.7775301916),
+    term(1.9124e-07, 3.82219996949, 23581.2581773176),
+    term(1.8888e-07, 5.38626880969, 149854.4001348079),
+    term(1.4346e-07, 3.72355084422, 38.0276726358),
+    term(1.7898e-07, 2.21490735647, 13367.9726311066),
+    term(1.2054e-07, 2.62229588349, 955.5997416086),
+    term(1.1287e-07, 0.17739328092, 4164.311989613),
+    term(1.3971e-07, 4.40138139996, 6681.2248533996),
+    term(1.3621e-07, 1.88934471407, 7632.9432596502),
+    term(1.2503e-07, 1.13052412208, 5.5229243074),
+    term(1.0498e-07, 5.35909518669, 1592.5960136328),
+    term(1.0327e-07, 6.19982566125, 6438.4962494256),
+    term(1.2003e-07, 1.003514567, 632.7837393132),
+    term(1.0827e-07, 0.32734520222, 103.0927742186),
+    term(1.0005e-07, 6.0291496328, 5746.271337896),
+    term(1.0523e-07, 0.93871805506, 11926.2544136688),
+];
+static L1: [Term; 55] = [
+    term(0.00206058863, 2.67823455584, 6283.0758499914),
+    term(4.30343e-05, 2.63512650414, 12566.1516999828),
+    term(4.25264e-06, 1.59046980729, 3.523118349),
+    term(1.08977e-06, 2.96618001993, 1577.3435424478),
+    term(9.3478e-07, 2.59212835365, 18849.2275499742),
+    term(1.19261e-06, 5.79557487799, 26.2983197998),
+    term(7.2122e-07, 1.13846158196, 529.6909650946),
+    term(6.7768e-07, 1.87472304791, 398.1490034082),
+    term(6.7327e-07, 4.40918235168, 5507.5532386674),
+    term(5.9027e-07, 2.8879703846, 5223.6939198022),
+    term(5.5976e-07, 2.17471680261, 155.4203994342),
+    term(4.5407e-07, 0.39803079805, 796.2980068164),
+    term(3.6369e-07, 0.46624739835, 775.522611324),
+    term(2.8958e-07, 2.64707383882, 7.1135470008),
+    term(1.9097e-07, 1.84628332577, 5486.777843175),
+    term(2.0844e-07, 5.34138275149, 0.9803210682),
+    term(1.8508e-07, 4.96855124577, 213.299095438),
+    term(1.6233e-07, 0.03216483047, 2544.3144198834),
+    term(1.7293e-07, 2.99116864949, 6275.9623029906),
+    term(1.5832e-07, 1.43049285325, 2146.1654164752),
+    term(1.4615e-07, 1.20532366323, 10977.078804699),
+    term(1.1877e-07, 3.25804815607, 5088.6288397668),
+    term(1.1514e-07, 2.07502418155, 4694.0029547076),
+    term(9.721e-08, 4.23925472239, 1349.8674096588),
+    term(9.969e-08, 1.30262991097, 6286.5989683404),
+    term(9.452e-08, 2.69957062864, 242.728603974),
+    term(1.2461e-07, 2.83432285512, 1748.016413067),
+    term(1.1808e-07, 5.2737979048, 1194.4470102246),
+    term(8.577e-08, 5.64475868067, 951.7184062506),
+    term(1.0641e-07, 0.76614199202, 553.5694028424),
+    term(7.576e-08, 5.30062664886, 2352.8661537718),
+    term(5.834e-08, 1.76649917904, 1059.3819301892),
+    term(6.385e-08, 2.65033984967, 9437.762934887),
+    term(5.223e-08, 5.66135767624, 71430.69561812909),
+    term(5.305e-08, 0.90857521574, 3154.6870848956),
+    term(6.101e-08, 4.66632584188, 4690.4798363586),
+    term(4.33e-08, 0.24102555403, 6812.766815086),
+    term(5.041e-08, 1.42490103709, 6438.4962494256),
+    term(4.259e-08, 0.77355900599, 10447.3878396044),
+    term(5.198e-08, 1.85353197345, 801.8209311238),
+    term(3.744e-08, 2.00119516488, 8031.0922630584),
+    term(3.558e-08, 2.42901552681, 14143.4952424306),
+    term(3.372e-08, 3.86210700128, 1592.5960136328),
+    term(3.374e-08, 0.88776219727, 12036.4607348882),
+    term(3.175e-08, 3.18785710594, 4705.7323075436),
+    term(3.221e-08, 0.61599835472, 8429.2412664666),
+    term(4.132e-08, 5.23992859705, 7084.8967811152),
+    term(2.97e-08, 6.07026318493, 4292.3308329504),
+    term(2.9e-08, 2.32464208411, 20.3553193988),
+    term(3.504e-08, 4.79975694359, 6279.5527316424),
+    term(2.95e-08, 1.43108874817, 5746.271337896),
+    term(2.697e-08, 4.80368225199, 7234.794256242),
+    term(2.531e-08, 6.22290682655, 6836.6452528338),
+    term(2.745e-08, 0.93466065396, 5760.4984318976),
+    term(3.25e-08, 3.39954640038, 7632.9432596502),
+];
+static L2: [Term; 37] = [
+    term(0.0005291887, 0.0, 0.0),
+    term(8.719837e-05, 1.07209665242, 6283.0758499914),
+    term(3.09125e-06, 0.86728818832, 12566.1516999828),
+    term(2.7339e-07, 0.05297871691, 3.523118349),
+    term(1.6334e-07, 5.18826691036, 26.2983197998),
+    term(1.5752e-07, 3.6845788943, 155.4203994342),
+    term(9.541e-08, 0.75742297675, 18849.2275499742),
+    term(8.937e-08, 2.05705419118, 77713.7714681205),
+    term(6.952e-08, 0.8267330541, 775.522611324),
+    term(5.064e-08, 4.66284525271, 1577.3435424478),
+    term(4.061e-08, 1.03057162962, 7.1135470008),
+    term(3.463e-08, 5.14074632811, 796.2980068164),
+    term(3.169e-08, 6.05291851171, 5507.5532386674),
+    term(3.02e-08, 1.19246506441, 242.728603974),
+    term(2.886e-08, 6.11652627155, 529.6909650946),
+    term(3.81e-08, 3.4405080349, 5573.1428014331),
+    term(2.714e-08, 0.30637881025, 398.1490034082),
+    term(2.371e-08, 4.38118838167, 5223.6939198022),
+    term(2.538e-08, 2.27992810679, 553.5694028424),
+    term(2.079e-08, 3.75435330484, 0.9803210682),
+    term(1.675e-08, 0.90216407959, 951.7184062506),
+    term(1.534e-08, 5.75900462759, 1349.8674096588),
+    term(1.224e-08, 2.97328088405, 2146.1654164752),
+    term(1.449e-08, 4.3641591397, 1748.016413067),
+    term(1.341e-08, 3.72061130861, 1194.4470102246),
+    term(1.254e-08, 2.94846826628, 6438.4962494256),
+    term(9.99e-09, 5.98640014468, 6286.5989683404),
+    term(9.17e-09, 4.79788687522, 5088.6288397668),
+    term(8.28e-09, 3.31321076572, 213.299095438),
+    term(1.103e-08, 1.27104454479, 161000.6857376741),
+    term(7.62e-09, 3.41582762988, 5486.777843175),
+    term(1.044e-08, 0.60409577691, 3154.6870848956),
+    term(8.87e-09, 5.23465144638, 7084.8967811152),
+    term(6.45e-09, 1.60096192515, 2544.3144198834),
+    term(6.81e-09, 3.43155669169, 4694.0029547076),
+    term(7.06e-09, 6.19393222575, 4690.4798363586),
+    term(6.43e-09, 1.98042503148, 801.8209311238),
+];
+static L3: [Term; 8] = [
+    term(2.89226e-06, 5.84384198723, 6283.0758499914),
+    term(3.4955e-07, 0.0, 0.0),
+    term(1.6819e-07, 5.48766912348, 12566.1516999828),
+    term(2.962e-08, 5.19577265202, 155.4203994342),
+    term(1.288e-08, 4.72200252235, 3.523118349),
+    term(6.35e-09, 5.96925937141, 242.728603974),
+    term(7.14e-09, 5.30045809128, 18849.2275499742),
+    term(4.02e-09, 3.78682982419, 553.5694028424),
+];
+static L4: [Term; 6] = [
+    term(1.14084e-06, 3.14159265359, 0.0),
+    term(7.717e-08, 4.13446589358, 6283.0758499914),
+    term(7.65e-09, 3.83803776214, 12566.1516999828),
+    term(4.2e-09, 0.41925861858, 155.4203994342),
+    term(4e-10, 3.5984758584, 18849.2275499742),
+    term(4.1e-10, 3.14398414077, 3.523118349),
+];
+static L5: [Term; 4] = [
+    term(8.78e-09, 3.14159265359, 0.0),
+    term(1.72e-09, 2.7657906951, 6283.0758499914),
+    term(5e-10, 2.01353298182, 155.4203994342),
+    term(2.8e-10, 2.21496423926, 12566.1516999828),
+];
+
+/// The terms of the longitude of the moon: the multiples of the mean
+/// elongation of the moon, the mean anomalies of the sun and the moon and the
+/// argument of latitude of the moon, and the coefficient in millionths of a
+/// degree.
+static MOON_TERMS: [(i8, i8, i8, i8, i32); 59] = [
+    (0, 0, 1, 0, 6288774),
+    (2, 0, -1, 0, 1274027),
+    (2, 0, 0, 0, 658314),
+    (0, 0, 2, 0, 213618),
+    (0, 1, 0, 0, -185116),
+    (0, 0, 0, 2, -114332),
+    (2, 0, -2, 0, 58793),
+    (2, -1, -1, 0, 57066),
+    (2, 0, 1, 0, 53322),
+    (2, -1, 0, 0, 45758),
+    (0, 1, -1, 0, -40923),
+    (1, 0, 0, 0, -34720),
+    (0, 1, 1, 0, -30383),
+    (2, 0, 0, -2, 15327),
+    (0, 0, 1, 2, -12528),
+    (0, 0, 1, -2, 10980),
+    (4, 0, -1, 0, 10675),
+    (0, 0, 3, 0, 10034),
+    (4, 0, -2, 0, 8548),
+    (2, 1, -1, 0, -7888),
+    (2, 1, 0, 0, -6766),
+    (1, 0, -1, 0, -5163),
+    (1, 1, 0, 0, 4987),
+    (2, -1, 1, 0, 4036),
+    (2, 0, 2, 0, 3994),
+    (4, 0, 0, 0, 3861),
+    (2, 0, -3, 0, 3665),
+    (0, 1, -2, 0, -2689),
+    (2, 0, -1, 2, -2602),
+    (2, -1, -2, 0, 2390),
+    (1, 0, 1, 0, -2348),
+    (2, -2, 0, 0, 2236),
+    (0, 1, 2, 0, -2120),
+    (0, 2, 0, 0, -2069),
+    (2, -2, -1, 0, 2048),
+    (2, 0, 1, -2, -1773),
+    (2, 0, 0, 2, -1595),
+    (4, -1, -1, 0, 1215),
+    (0, 0, 2, 2, -1110),
+    (3, 0, -1, 0, -892),
+    (2, 1, 1, 0, -810),
+    (4, -1, -2, 0, 759),
+    (0, 2, -1, 0, -713),
+    (2, 2, -1, 0, -700),
+    (2, 1, -2, 0, 691),
+    (2, -1, 0, -2, 596),
+    (4, 0, 1, 0, 549),
+    (0, 0, 4, 0, 537),
+    (4, -1, 0, 0, 520),
+    (1, 0, -2, 0, -487),
+    (2, 1, 0, -2, -399),
+    (0, 0, 2, -2, -381),
+    (1, 1, 1, 0, 351),
+    (3, 0, -2, 0, -340),
+    (4, 0, -3, 0, 330),
+    (2, -1, 2, 0, 327),
+    (0, 2, 1, 0, -323),
+    (1, 1, -1, 0, 299),
+    (2, 0, 3, 0, 294),
+];
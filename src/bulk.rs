@@ -0,0 +1,149 @@
+//! Calendar data of ranges of years computed in parallel with the `rayon`
+//! feature.
+//!
+//! Each function returns an indexed parallel iterator with an item for each
+//! year, in the order of the years, so collecting it gives the same result as
+//! computing the years one by one:
+//!
+//! ```
+//! use omnical::bulk;
+//! use rayon::prelude::*;
+//!
+//! let years: Vec<_> = bulk::chinese_years(2000..=2009).unwrap().collect();
+//! assert_eq!(years.len(), 10);
+//! assert_eq!(years[4].to_string(), "甲申年");
+//! ```
+//!
+//! The years are checked before the iterator is returned, so that computing
+//! an item cannot fail.
+//!
+//! The ephemeris values computed by one thread are cached for the others,
+//! see [`astronomy::set_ephemeris_cache_capacity`].
+
+use std::ops::RangeInclusive;
+
+use rayon::prelude::*;
+
+use crate::chinese::YearOutOfRangeError;
+use crate::*;
+
+/// The years as an indexed parallel iterator, which a `RangeInclusive<i32>`
+/// is not for its length may overflow.
+fn par_years(years: RangeInclusive<i32>) -> rayon::range::Iter<i32> {
+    let (start, end) = years.into_inner();
+    (start..end.saturating_add(1)).into_par_iter()
+}
+
+/// Checks that the years are in [`chinese::SUPPORTED_YEARS`], within which
+/// the ephemeris is valid, returning the first year outside it.
+fn check_years(years: &RangeInclusive<i32>) -> Result<(), YearOutOfRangeError> {
+    if !years.is_empty() {
+        ChineseCalendar::try_from_y(*years.start())?;
+        ChineseCalendar::try_from_y(*years.end())?;
+    }
+    Ok(())
+}
+
+/// The first day of a Gregorian year and the first day of the next one.
+fn gregorian_year_bounds(year: i32) -> (Date, Date) {
+    let new_year_day =
+        |year| Date::from_jd(gregorian::proleptic_gregorian_to_julian_day(year, 1, 1.0));
+    (new_year_day(year), new_year_day(year + 1))
+}
+
+/// The Chinese years, or the first year outside
+/// [`chinese::SUPPORTED_YEARS`].
+pub fn chinese_years(
+    years: RangeInclusive<i32>,
+) -> Result<impl IndexedParallelIterator<Item = ChineseYear>, YearOutOfRangeError> {
+    check_years(&years)?;
+    Ok(par_years(years).map(|year| ChineseYear::new(year, chinese::Mode::default())))
+}
+
+/// The days with a solar term in each Gregorian year, or the first year
+/// outside [`chinese::SUPPORTED_YEARS`].
+pub fn solar_terms(
+    years: RangeInclusive<i32>,
+    tz: f64,
+) -> Result<impl IndexedParallelIterator<Item = Vec<(Date, SolarTerm)>>, YearOutOfRangeError> {
+    check_years(&years)?;
+    Ok(par_years(years).map(move |year| {
+        let (begin, end) = gregorian_year_bounds(year);
+        astronomy::find_solar_terms(begin, end, tz)
+    }))
+}
+
+/// The days of new moons, first quarters, full moons and last quarters in
+/// each Gregorian year, or the first year outside
+/// [`chinese::SUPPORTED_YEARS`].
+pub fn lunar_phases(
+    years: RangeInclusive<i32>,
+    tz: f64,
+) -> Result<impl IndexedParallelIterator<Item = Vec<(Date, LunarPhase)>>, YearOutOfRangeError> {
+    check_years(&years)?;
+    Ok(par_years(years).map(move |year| {
+        let (begin, end) = gregorian_year_bounds(year);
+        astronomy::get_lunar_phases(begin, end, tz)
+            .into_iter()
+            .enumerate()
+            .filter(|(_, phase)| phase.is_principal())
+            .map(|(i, phase)| (begin + i as i32, phase))
+            .collect()
+    }))
+}
+
+#[test]
+fn test_bulk() {
+    use crate::calendar::Calendar as _;
+
+    let years: Vec<_> = chinese_years(2020..=2025).unwrap().collect();
+    let expected: Vec<_> = (2020..=2025)
+        .map(|year| ChineseCalendar::from_y(year).unwrap())
+        .collect();
+    assert_eq!(years, expected);
+    assert_eq!(
+        years
+            .iter()
+            .map(|year| year.new_year_day())
+            .collect::<Vec<_>>(),
+        expected
+            .iter()
+            .map(|year| year.new_year_day())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        chinese_years(6000..=6001).err(),
+        Some(YearOutOfRangeError { year: 6001 })
+    );
+    #[allow(clippy::reversed_empty_ranges)]
+    let empty = 1..=0;
+    assert_eq!(chinese_years(empty).unwrap().count(), 0);
+
+    let terms: Vec<_> = solar_terms(2023..=2024, 8.0).unwrap().collect();
+    assert_eq!(terms.len(), 2);
+    assert!(terms.iter().all(|terms| terms.len() == 24));
+    let (begin, end) = gregorian_year_bounds(2024);
+    assert_eq!(
+        begin,
+        GregorianCalendar::from_ymd(2024, 1, 1).unwrap().into()
+    );
+    assert_eq!(end, GregorianCalendar::from_ymd(2025, 1, 1).unwrap().into());
+    assert_eq!(terms[1], astronomy::find_solar_terms(begin, end, 8.0));
+
+    assert_eq!(
+        solar_terms(-2001..=2000, 8.0).err(),
+        Some(YearOutOfRangeError { year: -2001 })
+    );
+
+    let phases: Vec<_> = lunar_phases(2024..=2024, 8.0).unwrap().collect();
+    let new_moons: Vec<_> = phases[0]
+        .iter()
+        .filter(|(_, phase)| *phase == NewMoon)
+        .map(|(date, _)| *date)
+        .collect();
+    assert_eq!(new_moons, astronomy::find_new_moons(begin, end, 8.0));
+    assert!(phases[0].iter().any(|&(date, phase)| {
+        phase == FullMoon && date == GregorianCalendar::from_ymd(2024, 9, 18).unwrap().into()
+    }));
+    assert!(lunar_phases(i32::MAX..=i32::MAX, 8.0).is_err());
+}
@@ -0,0 +1,64 @@
+//! A bounded memoization cache shared by the ephemeris and the lunisolar
+//! calendars.
+
+use std::collections::{BTreeMap, VecDeque};
+
+/// A map of at most a given number of entries, evicting the oldest ones
+/// first.
+pub(crate) struct Cache<K, V> {
+    values: BTreeMap<K, V>,
+    order: VecDeque<K>,
+}
+
+impl<K: Ord + Copy, V: Clone> Cache<K, V> {
+    pub(crate) const fn new() -> Self {
+        Self {
+            values: BTreeMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    pub(crate) fn get(&self, key: &K) -> Option<V> {
+        self.values.get(key).cloned()
+    }
+
+    pub(crate) fn insert(&mut self, key: K, value: V, capacity: usize) {
+        if self.values.contains_key(&key) {
+            return;
+        }
+        self.shrink_to(capacity.saturating_sub(1));
+        if capacity > 0 {
+            self.values.insert(key, value);
+            self.order.push_back(key);
+        }
+    }
+
+    pub(crate) fn shrink_to(&mut self, capacity: usize) {
+        while self.order.len() > capacity {
+            let key = self.order.pop_front().unwrap();
+            self.values.remove(&key);
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> usize {
+        self.order.len()
+    }
+}
+
+#[test]
+fn test_cache() {
+    let mut cache = Cache::new();
+    cache.insert(1, 10.0, 2);
+    cache.insert(2, 20.0, 2);
+    cache.insert(1, 11.0, 2);
+    assert_eq!(cache.get(&1), Some(10.0));
+    assert_eq!(cache.len(), 2);
+    cache.insert(3, 30.0, 2);
+    assert_eq!(cache.get(&1), None);
+    assert_eq!((cache.get(&2), cache.get(&3)), (Some(20.0), Some(30.0)));
+    cache.shrink_to(0);
+    assert_eq!(cache.len(), 0);
+    cache.insert(1, 12.0, 0);
+    assert_eq!(cache.get(&1), None);
+}
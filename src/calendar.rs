@@ -1,15 +1,44 @@
 use crate::*;
 
+/// The type of the ordinals of months in a year or days in a month, numbered
+/// from 1, e.g. `u8` for most calendars, or `u16` for a calendar with months
+/// of more than 255 days.
+pub trait Ordinal:
+    Copy + Ord + std::fmt::Debug + std::fmt::Display + Into<u32> + TryFrom<u32> + 'static
+{
+    /// The ordinal of the number, if it fits.
+    fn from_u32(n: u32) -> Option<Self> {
+        Self::try_from(n).ok()
+    }
+    fn to_u32(self) -> u32 {
+        self.into()
+    }
+}
+
+impl<T> Ordinal for T where
+    T: Copy + Ord + std::fmt::Debug + std::fmt::Display + Into<u32> + TryFrom<u32> + 'static
+{
+}
+
+/// The ordinals from 1 to `n`, as many as fit in the type.
+fn ordinals<O: Ordinal>(n: usize) -> impl Iterator<Item = O> {
+    (1..=n as u32).map_while(O::from_u32)
+}
+
 pub trait Calendar: Sized {
     type Year: Year<Self>;
     type Month: Month<Self>;
     type Day: Day<Self>;
+    /// The type of [`Month::ord`].
+    type MonthOrd: Ordinal;
+    /// The type of [`Day::ord`].
+    type DayOrd: Ordinal;
 
     fn from_y(year: i32) -> Option<Self::Year>;
-    fn from_ym(year: i32, month: u8) -> Option<Self::Month> {
+    fn from_ym(year: i32, month: Self::MonthOrd) -> Option<Self::Month> {
         Self::from_y(year)?.month(month)
     }
-    fn from_ymd(year: i32, month: u8, day: u8) -> Option<Self::Day> {
+    fn from_ymd(year: i32, month: Self::MonthOrd, day: Self::DayOrd) -> Option<Self::Day> {
         Self::from_ym(year, month)?.day(day)
     }
     fn from_yo(year: i32, day_ord: u16) -> Option<Self::Day> {
@@ -17,21 +46,42 @@ pub trait Calendar: Sized {
     }
 }
 
+/// What a [`Calendar`] supports, so that generic code, e.g. a user interface,
+/// can adapt to a calendar without knowing which one it is.
+pub trait CalendarInfo: Calendar {
+    /// The name of the calendar for display, e.g. "Gregorian".
+    fn display_name() -> &'static str;
+    /// The years accepted by [`Calendar::from_y`].
+    fn supported_years() -> std::ops::RangeInclusive<i32>;
+    /// The fewest and the most months of a year.
+    fn months_per_year() -> std::ops::RangeInclusive<usize>;
+    /// Whether some years have a leap month, see [`Month::is_leap`].
+    fn has_leap_months() -> bool {
+        let months = Self::months_per_year();
+        months.start() != months.end()
+    }
+    /// The first day of the year 1, from which the years are numbered.
+    fn epoch() -> Date {
+        Self::from_y(1).unwrap().first_day().into()
+    }
+}
+
 pub trait Year<C: Calendar>: Sized + std::fmt::Display {
     fn ord(&self) -> i32;
     fn succ(&self) -> Self;
     fn pred(&self) -> Self;
 
     fn num_months(&self) -> usize;
-    fn month(&self, ord: u8) -> Option<C::Month>;
+    fn month(&self, ord: C::MonthOrd) -> Option<C::Month>;
     fn first_month(&self) -> C::Month {
-        self.month(1).unwrap()
+        self.month(C::MonthOrd::from_u32(1).unwrap()).unwrap()
     }
     fn last_month(&self) -> C::Month {
-        self.month(self.num_months() as u8).unwrap()
+        self.month(C::MonthOrd::from_u32(self.num_months() as u32).unwrap())
+            .unwrap()
     }
     fn months(&self) -> impl Iterator<Item = C::Month> {
-        (1..=self.num_months() as u8).filter_map(|i| self.month(i))
+        ordinals(self.num_months()).filter_map(|i| self.month(i))
     }
 
     fn num_days(&self) -> usize {
@@ -42,7 +92,7 @@ pub trait Year<C: Calendar>: Sized + std::fmt::Display {
         for month in self.months() {
             let num_days = month.num_days() as u16;
             if ord <= num_days {
-                return month.day(ord as u8);
+                return month.day(C::DayOrd::from_u32(ord as u32)?);
             }
             ord -= num_days;
         }
@@ -64,22 +114,23 @@ pub trait Year<C: Calendar>: Sized + std::fmt::Display {
 }
 
 pub trait Month<C: Calendar>: Sized + std::fmt::Display {
-    fn ord(&self) -> u8;
+    fn ord(&self) -> C::MonthOrd;
     fn succ(&self) -> Self;
     fn pred(&self) -> Self;
 
     fn the_year(&self) -> C::Year;
 
     fn num_days(&self) -> usize;
-    fn day(&self, ord: u8) -> Option<C::Day>;
+    fn day(&self, ord: C::DayOrd) -> Option<C::Day>;
     fn first_day(&self) -> C::Day {
-        self.day(1).unwrap()
+        self.day(C::DayOrd::from_u32(1).unwrap()).unwrap()
     }
     fn last_day(&self) -> C::Day {
-        self.day(self.num_days() as u8).unwrap()
+        self.day(C::DayOrd::from_u32(self.num_days() as u32).unwrap())
+            .unwrap()
     }
     fn days(&self) -> impl Iterator<Item = C::Day> {
-        (1..=self.num_days() as u8).filter_map(|i| self.day(i))
+        ordinals(self.num_days()).filter_map(|i| self.day(i))
     }
 
     fn is_leap(&self) -> bool {
@@ -87,14 +138,44 @@ pub trait Month<C: Calendar>: Sized + std::fmt::Display {
     }
 }
 
-pub trait Day<C: Calendar>: Sized + Clone + Copy + std::fmt::Display + Into<Date> {
-    fn ord(&self) -> u8;
+/// Anything that is a day: [`Date`] and the days of all calendars.
+///
+/// Generic functions can take `impl DateLike` instead of converting days to
+/// [`Date`] first.
+pub trait DateLike: Copy {
+    fn to_date(self) -> Date;
+
+    fn jdn(&self) -> i32 {
+        self.to_date().jdn()
+    }
+    fn weekday(&self) -> Weekday {
+        self.to_date().weekday()
+    }
+    #[cfg(feature = "astronomy")]
+    fn lunar_phase(&self, tz: f64) -> LunarPhase {
+        self.to_date().lunar_phase(tz)
+    }
+    #[cfg(feature = "astronomy")]
+    fn solar_term(&self, tz: f64) -> Option<SolarTerm> {
+        self.to_date().solar_term(tz)
+    }
+}
+
+impl<T: Copy + Into<Date>> DateLike for T {
+    fn to_date(self) -> Date {
+        self.into()
+    }
+}
+
+pub trait Day<C: Calendar>:
+    Sized + Clone + Copy + std::fmt::Display + Into<Date> + DateLike
+{
+    fn ord(&self) -> C::DayOrd;
     fn ord_in_year(&self) -> u16 {
-        (1..self.the_month().ord())
+        ordinals(self.the_month().ord().to_u32() as usize - 1)
             .map(|m| self.the_year().month(m).unwrap().num_days() as u16)
             .sum::<u16>()
-            + self.ord() as u16
-            + 1
+            + self.ord().to_u32() as u16
     }
     fn succ(&self) -> Self;
     fn pred(&self) -> Self;
@@ -105,12 +186,157 @@ pub trait Day<C: Calendar>: Sized + Clone + Copy + std::fmt::Display + Into<Date
     fn is_leap(&self) -> bool {
         false
     }
-    fn jdn(&self) -> i32 {
-        let date: Date = (*self).into();
-        date.jdn()
+}
+
+#[cfg(feature = "astronomy")]
+#[test]
+fn test_date_like() {
+    fn describe(day: impl DateLike) -> (i32, Weekday, LunarPhase, Option<SolarTerm>) {
+        (
+            day.jdn(),
+            day.weekday(),
+            day.lunar_phase(8.0),
+            day.solar_term(8.0),
+        )
     }
-    fn weekday(&self) -> Weekday {
-        let date: Date = (*self).into();
-        date.weekday()
+
+    let gregorian = GregorianCalendar::from_ymd(2024, 2, 10).unwrap();
+    let chinese = ChineseCalendar::from_ymd(2024, 1, 1).unwrap();
+    let date = Date::from(gregorian);
+    assert_eq!(chinese.to_date(), date);
+    assert_eq!(describe(gregorian), (2460351, Saturday, NewMoon, None));
+    assert_eq!(describe(chinese), describe(date));
+    assert_eq!(
+        describe(GregorianCalendar::from_ymd(2024, 2, 4).unwrap()).3,
+        Some(BeginningOfSpring)
+    );
+}
+
+#[test]
+fn test_ordinal() {
+    // A calendar of years of a single month of 300 days from 2000-01-01.
+    struct Long;
+    #[derive(Clone, Copy)]
+    struct LongYear(i32);
+    #[derive(Clone, Copy)]
+    struct LongMonth(i32);
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct LongDay(i32, u16);
+
+    const EPOCH_JDN: i32 = 2451545;
+
+    impl std::fmt::Display for LongYear {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+    impl std::fmt::Display for LongMonth {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}-1", self.0)
+        }
+    }
+    impl std::fmt::Display for LongDay {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}-1-{}", self.0, self.1)
+        }
+    }
+    impl From<LongDay> for Date {
+        fn from(day: LongDay) -> Self {
+            Date::from_jdn(EPOCH_JDN + day.0 * 300 + day.1 as i32 - 1)
+        }
+    }
+    impl From<Date> for LongDay {
+        fn from(date: Date) -> Self {
+            let days = date.jdn() - EPOCH_JDN;
+            LongDay(days.div_euclid(300), days.rem_euclid(300) as u16 + 1)
+        }
+    }
+
+    impl Calendar for Long {
+        type Year = LongYear;
+        type Month = LongMonth;
+        type Day = LongDay;
+        type MonthOrd = u8;
+        type DayOrd = u16;
+
+        fn from_y(year: i32) -> Option<LongYear> {
+            (-1000..=1000).contains(&year).then_some(LongYear(year))
+        }
+    }
+    impl CalendarInfo for Long {
+        fn display_name() -> &'static str {
+            "Long"
+        }
+        fn supported_years() -> std::ops::RangeInclusive<i32> {
+            -1000..=1000
+        }
+        fn months_per_year() -> std::ops::RangeInclusive<usize> {
+            1..=1
+        }
+    }
+    impl Year<Long> for LongYear {
+        fn ord(&self) -> i32 {
+            self.0
+        }
+        fn succ(&self) -> Self {
+            LongYear(self.0 + 1)
+        }
+        fn pred(&self) -> Self {
+            LongYear(self.0 - 1)
+        }
+        fn num_months(&self) -> usize {
+            1
+        }
+        fn month(&self, ord: u8) -> Option<LongMonth> {
+            (ord == 1).then_some(LongMonth(self.0))
+        }
+    }
+    impl Month<Long> for LongMonth {
+        fn ord(&self) -> u8 {
+            1
+        }
+        fn succ(&self) -> Self {
+            LongMonth(self.0 + 1)
+        }
+        fn pred(&self) -> Self {
+            LongMonth(self.0 - 1)
+        }
+        fn the_year(&self) -> LongYear {
+            LongYear(self.0)
+        }
+        fn num_days(&self) -> usize {
+            300
+        }
+        fn day(&self, ord: u16) -> Option<LongDay> {
+            (1..=300).contains(&ord).then_some(LongDay(self.0, ord))
+        }
     }
+    impl Day<Long> for LongDay {
+        fn ord(&self) -> u16 {
+            self.1
+        }
+        fn succ(&self) -> Self {
+            Date::from(*self).succ().into()
+        }
+        fn pred(&self) -> Self {
+            Date::from(*self).pred().into()
+        }
+        fn the_year(&self) -> LongYear {
+            LongYear(self.0)
+        }
+        fn the_month(&self) -> LongMonth {
+            LongMonth(self.0)
+        }
+    }
+
+    let day = Long::from_ymd(0, 1, 300).unwrap();
+    assert_eq!(day.ord_in_year(), 300);
+    assert_eq!(day.succ(), Long::from_ymd(1, 1, 1).unwrap());
+    assert_eq!(LongYear(0).last_day(), day);
+    assert_eq!(LongYear(0).days().count(), 300);
+    assert_eq!(LongYear(0).day(256), Long::from_ymd(0, 1, 256));
+    testing::check_calendar::<Long>(1, 1990..=2010, 100).unwrap();
+
+    assert!(!Long::has_leap_months());
+    assert_eq!(Long::epoch(), Date::from_jdn(EPOCH_JDN + 300));
 }
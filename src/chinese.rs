@@ -1,10 +1,33 @@
+use std::ops::RangeInclusive;
+use std::sync::Mutex;
+
 use derivative::Derivative;
 use strum::{Display, EnumCount, EnumProperty, EnumString, FromRepr, VariantArray};
 
+use crate::cache::Cache;
 use crate::calendar::{Calendar as _, Day as _, Month as _, Year as _};
 use crate::*;
 
-const BEIJING_TZ: f64 = 8.0;
+pub mod anniversary;
+mod cycles;
+#[cfg(feature = "chinese-era")]
+pub mod era;
+pub mod festivals;
+pub mod huangli;
+mod parse;
+pub mod periods;
+mod solar_month;
+#[cfg(feature = "chinese-table")]
+mod table;
+
+pub use cycles::{NineStar, Rokuyo};
+pub use parse::{parse_month_day, ParseDayError};
+pub use solar_month::SolarMonth;
+
+pub(crate) const BEIJING_TZ: f64 = 8.0;
+/// The position in the sexagenary cycle, from Jia-Zi (甲子) as 0, of the day
+/// with the Julian day number 0, a Gui-Chou (癸丑) day.
+const DAY_CYCLE_OFFSET: i32 = 49;
 const LEAP_NAMES: [&str; 2] = ["", "闰"];
 const MONTH_NAMES: [&str; 12] = [
     "正月",
@@ -20,109 +43,185 @@ const MONTH_NAMES: [&str; 12] = [
     "十一月",
     "十二月",
 ];
+const MONTH_PINYIN: [&str; 12] = [
+    "Zhēngyuè",
+    "Èryuè",
+    "Sānyuè",
+    "Sìyuè",
+    "Wǔyuè",
+    "Liùyuè",
+    "Qīyuè",
+    "Bāyuè",
+    "Jiǔyuè",
+    "Shíyuè",
+    "Shíyīyuè",
+    "Shí'èryuè",
+];
+const MONTH_ENGLISH: [&str; 12] = [
+    "First Month",
+    "Second Month",
+    "Third Month",
+    "Fourth Month",
+    "Fifth Month",
+    "Sixth Month",
+    "Seventh Month",
+    "Eighth Month",
+    "Ninth Month",
+    "Tenth Month",
+    "Eleventh Month",
+    "Twelfth Month",
+];
 const DAY_NAMES: [&str; 30] = [
     "初一", "初二", "初三", "初四", "初五", "初六", "初七", "初八", "初九", "初十", "十一", "十二",
     "十三", "十四", "十五", "十六", "十七", "十八", "十九", "二十", "廿一", "廿二", "廿三", "廿四",
     "廿五", "廿六", "廿七", "廿八", "廿九", "三十",
 ];
+const DAY_PINYIN: [&str; 30] = [
+    "Chūyī",
+    "Chū'èr",
+    "Chūsān",
+    "Chūsì",
+    "Chūwǔ",
+    "Chūliù",
+    "Chūqī",
+    "Chūbā",
+    "Chūjiǔ",
+    "Chūshí",
+    "Shíyī",
+    "Shí'èr",
+    "Shísān",
+    "Shísì",
+    "Shíwǔ",
+    "Shíliù",
+    "Shíqī",
+    "Shíbā",
+    "Shíjiǔ",
+    "Èrshí",
+    "Niànyī",
+    "Niàn'èr",
+    "Niànsān",
+    "Niànsì",
+    "Niànwǔ",
+    "Niànliù",
+    "Niànqī",
+    "Niànbā",
+    "Niànjiǔ",
+    "Sānshí",
+];
 
-fn get_winter_solstice(year: i32, tz: f64) -> Date {
-    let mut d: Date = GregorianCalendar::from_ymd(year, 12, 21).unwrap().into();
-    while d.solar_term(tz) != Some(WinterSolstice) {
-        d = d.succ();
-    }
-    d
-}
-
-fn get_prev_new_moon(date: Date, tz: f64) -> Date {
-    let mut d = date;
-    while d.lunar_phase(tz) != NewMoon {
-        d = d.pred();
-    }
-    d
-}
-
-fn calc_chinese_year_period_data(year: i32) -> (Date, Vec<u8>, Option<usize>) {
-    let mut data = Vec::new();
-    let last_ws = get_winter_solstice(year - 1, BEIJING_TZ);
-    let next_ws_p1 = get_winter_solstice(year, BEIJING_TZ).succ();
-    let nm_before_last_ws = get_prev_new_moon(last_ws, BEIJING_TZ);
-    let mut d = nm_before_last_ws;
-    let mut last_nm = None;
-    let mut has_mt = false;
-    while d != next_ws_p1 {
-        let lp = d.lunar_phase(BEIJING_TZ);
-        let st = d.solar_term(BEIJING_TZ);
-        if lp == NewMoon || st.is_some() {
-            if lp == NewMoon {
-                if let Some(last_nm) = last_nm {
-                    let num_days = d - last_nm;
-                    data.push((num_days as u8, has_mt));
-                }
-                last_nm = Some(d);
-                has_mt = false;
-            }
-            if let Some(st) = st {
-                if st.is_mid_term() {
-                    has_mt = true;
-                }
+/// The rules used to compute the Chinese calendar.
+///
+/// Historical calendars determined months by new moons and leap months by
+/// mid-terms (中气) as the current one does, but computed those moments
+/// differently.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub enum Mode {
+    /// True new moons and true solar terms (定朔定气), used since the Shixian
+    /// calendar (时宪历) of 1645.
+    #[default]
+    TrueMoonTrueTerms,
+    /// True new moons and mean solar terms (定朔平气), used from the Wuyin
+    /// calendar (戊寅元历) of 619 to 1644.
+    TrueMoonMeanTerms,
+    /// Mean new moons and mean solar terms (平朔平气), used before 619.
+    MeanMoonMeanTerms,
+    /// Same as [`TrueMoonTrueTerms`](Mode::TrueMoonTrueTerms), but computed
+    /// in integer arithmetic by [`astronomy::fixed`], so that the years are
+    /// the same on every platform, e.g. for systems that must agree on them.
+    Deterministic,
+}
+
+impl Mode {
+    pub(crate) fn lunar_phases(&self, begin: Date, end: Date, tz: f64) -> Vec<LunarPhase> {
+        match self {
+            Mode::TrueMoonTrueTerms | Mode::TrueMoonMeanTerms => {
+                astronomy::get_lunar_phases(begin, end, tz)
             }
+            Mode::MeanMoonMeanTerms => astronomy::get_mean_lunar_phases(begin, end, tz),
+            Mode::Deterministic => astronomy::fixed::get_lunar_phases(begin, end, tz),
         }
-        d = d.succ();
     }
-    let is_leap_year = data.len() > 12;
-    let leap_month = if is_leap_year {
-        let mut i = 0;
-        loop {
-            if !data[i].1 {
-                break Some(i);
-            }
-            i += 1;
-        }
-    } else {
-        None
-    };
-    let data = data.into_iter().map(|(x, _)| x).collect();
-    (nm_before_last_ws, data, leap_month)
-}
-
-fn calc_chinese_year_data(year: i32) -> (Date, [u8; 13], u8) {
-    let (fd1, data1, lm1) = calc_chinese_year_period_data(year);
-    let (_, data2, lm2) = calc_chinese_year_period_data(year + 1);
-    let (off1, nlm1) = match lm1 {
-        Some(lm1) => {
-            if lm1 <= 2 {
-                (3, None)
-            } else {
-                (2, Some(lm1 - 2))
+
+    pub(crate) fn solar_terms(&self, begin: Date, end: Date, tz: f64) -> Vec<Option<SolarTerm>> {
+        match self {
+            Mode::TrueMoonTrueTerms => astronomy::get_solar_terms(begin, end, tz),
+            Mode::TrueMoonMeanTerms | Mode::MeanMoonMeanTerms => {
+                astronomy::get_mean_solar_terms(begin, end, tz)
             }
+            Mode::Deterministic => astronomy::fixed::get_solar_terms(begin, end, tz),
         }
-        None => (2, None),
+    }
+}
+
+/// The boundary between two years in the sexagenary (干支) cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Boundary {
+    /// The Chinese New Year (春节), as used in the civil calendar.
+    #[default]
+    SpringFestival,
+    /// The Beginning of Spring (立春), as used in the Four Pillars (八字).
+    Lichun,
+}
+
+/// The first day, the number of days of each month, and the leap month index
+/// (13 if none) of a Chinese year.
+type ChineseYearData = (Date, [u8; 13], u8);
+
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug"))]
+fn calc_chinese_year_data(year: i32, mode: Mode) -> ChineseYearData {
+    let rules = lunisolar::Rules {
+        tz: BEIJING_TZ,
+        mode,
     };
-    let (off2, nlm2) = match lm2 {
-        Some(lm2) => {
-            if lm2 <= 2 {
-                (3, Some(lm2 + 10))
-            } else {
-                (2, None)
-            }
+    let data = lunisolar::calc_year_data(year, &rules);
+    let mut num_days_of_months = [0; 13];
+    num_days_of_months[..data.num_days_of_months.len()].copy_from_slice(&data.num_days_of_months);
+    let leap_month = data.leap_month.map_or(13, |lm| lm as u8);
+    (data.first_day, num_days_of_months, leap_month)
+}
+
+static CHINESE_YEAR_DATA_CACHE: Mutex<Cache<(i32, Mode), ChineseYearData>> =
+    Mutex::new(Cache::new());
+
+/// Same as `calc_chinese_year_data`, but memoized across all threads, keeping
+/// at most [`lunisolar::YEAR_CACHE_CAPACITY`] years.
+///
+/// With the `chinese-table` feature, years in the precomputed table are looked
+/// up directly in [`Mode::Deterministic`], the mode the table is computed in.
+fn get_chinese_year_data(year: i32, mode: Mode) -> ChineseYearData {
+    #[cfg(feature = "chinese-table")]
+    if mode == Mode::Deterministic {
+        if let Some(data) = table::lookup(year) {
+            #[cfg(feature = "tracing")]
+            tracing::trace!(year, "Chinese year found in the table");
+            return data;
         }
-        None => (2, None),
-    };
-    let mut data = [&data1[off1..], &data2[..off2]].concat();
-    if data.len() == 12 {
-        data.push(0);
     }
-    let num_days_of_months: [u8; 13] = data.try_into().unwrap();
-    let nlm = nlm1.or(nlm2);
-    let fd = fd1 + data1[..off1].iter().sum::<u8>() as i32;
-    let leap_month = nlm.unwrap_or(13) as u8;
-    (fd, num_days_of_months, leap_month)
+    if let Some(data) = CHINESE_YEAR_DATA_CACHE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(&(year, mode))
+    {
+        #[cfg(feature = "tracing")]
+        tracing::trace!(year, ?mode, "Chinese year found in the cache");
+        return data;
+    }
+    let data = calc_chinese_year_data(year, mode);
+    CHINESE_YEAR_DATA_CACHE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert((year, mode), data, lunisolar::YEAR_CACHE_CAPACITY);
+    data
 }
 
 #[test]
 fn test_calc_chinese_year_data() {
-    let result = calc_chinese_year_data(2014);
+    let result = calc_chinese_year_data(2014, Mode::default());
     assert_eq!(
         result,
         (
@@ -131,7 +230,9 @@ fn test_calc_chinese_year_data() {
             9
         )
     );
-    let result = calc_chinese_year_data(2023);
+    assert_eq!(get_chinese_year_data(2014, Mode::default()), result);
+    assert_eq!(get_chinese_year_data(2014, Mode::default()), result);
+    let result = calc_chinese_year_data(2023, Mode::default());
     assert_eq!(
         result,
         (
@@ -156,25 +257,25 @@ fn test_calc_chinese_year_data() {
     EnumProperty,
 )]
 pub enum Stem {
-    #[strum(props(zh = "甲"))]
+    #[strum(props(zh = "甲", pinyin = "Jiǎ", english = "Yang Wood"))]
     Jia,
-    #[strum(props(zh = "乙"))]
+    #[strum(props(zh = "乙", pinyin = "Yǐ", english = "Yin Wood"))]
     Yi,
-    #[strum(props(zh = "丙"))]
+    #[strum(props(zh = "丙", pinyin = "Bǐng", english = "Yang Fire"))]
     Bing,
-    #[strum(props(zh = "丁"))]
+    #[strum(props(zh = "丁", pinyin = "Dīng", english = "Yin Fire"))]
     Ding,
-    #[strum(props(zh = "戊"))]
+    #[strum(props(zh = "戊", pinyin = "Wù", english = "Yang Earth"))]
     Wu,
-    #[strum(props(zh = "己"))]
+    #[strum(props(zh = "己", pinyin = "Jǐ", english = "Yin Earth"))]
     Ji,
-    #[strum(props(zh = "庚"))]
+    #[strum(props(zh = "庚", pinyin = "Gēng", english = "Yang Metal"))]
     Geng,
-    #[strum(props(zh = "辛"))]
+    #[strum(props(zh = "辛", pinyin = "Xīn", english = "Yin Metal"))]
     Xin,
-    #[strum(props(zh = "壬"))]
+    #[strum(props(zh = "壬", pinyin = "Rén", english = "Yang Water"))]
     Ren,
-    #[strum(props(zh = "癸"))]
+    #[strum(props(zh = "癸", pinyin = "Guǐ", english = "Yin Water"))]
     Gui,
 }
 
@@ -188,12 +289,24 @@ impl Stem {
     }
 
     pub fn from_year(year: i32) -> Self {
-        Self::from_repr((year - 4).rem_euclid(Self::COUNT as i32) as usize).unwrap()
+        Self::from_repr((i64::from(year) - 4).rem_euclid(Self::COUNT as i64) as usize).unwrap()
+    }
+
+    pub fn five_element(&self) -> FiveElement {
+        FiveElement::from_repr(*self as usize / 2).unwrap()
     }
 
     pub fn chinese(&self) -> &str {
         self.get_str("zh").unwrap()
     }
+
+    pub fn pinyin(&self) -> &str {
+        self.get_str("pinyin").unwrap()
+    }
+
+    pub fn english(&self) -> &str {
+        self.get_str("english").unwrap()
+    }
 }
 
 #[derive(
@@ -210,29 +323,29 @@ impl Stem {
     EnumProperty,
 )]
 pub enum Branch {
-    #[strum(props(zh = "子"))]
+    #[strum(props(zh = "子", animal = "鼠", pinyin = "Zǐ", english = "Rat"))]
     Zi,
-    #[strum(props(zh = "丑"))]
+    #[strum(props(zh = "丑", animal = "牛", pinyin = "Chǒu", english = "Ox"))]
     Chou,
-    #[strum(props(zh = "寅"))]
+    #[strum(props(zh = "寅", animal = "虎", pinyin = "Yín", english = "Tiger"))]
     Yin,
-    #[strum(props(zh = "卯"))]
+    #[strum(props(zh = "卯", animal = "兔", pinyin = "Mǎo", english = "Rabbit"))]
     Mao,
-    #[strum(props(zh = "辰"))]
+    #[strum(props(zh = "辰", animal = "龙", pinyin = "Chén", english = "Dragon"))]
     Chen,
-    #[strum(props(zh = "巳"))]
+    #[strum(props(zh = "巳", animal = "蛇", pinyin = "Sì", english = "Snake"))]
     Si,
-    #[strum(props(zh = "午"))]
+    #[strum(props(zh = "午", animal = "马", pinyin = "Wǔ", english = "Horse"))]
     Wu,
-    #[strum(props(zh = "未"))]
+    #[strum(props(zh = "未", animal = "羊", pinyin = "Wèi", english = "Goat"))]
     Wei,
-    #[strum(props(zh = "申"))]
+    #[strum(props(zh = "申", animal = "猴", pinyin = "Shēn", english = "Monkey"))]
     Shen,
-    #[strum(props(zh = "酉"))]
+    #[strum(props(zh = "酉", animal = "鸡", pinyin = "Yǒu", english = "Rooster"))]
     You,
-    #[strum(props(zh = "戌"))]
+    #[strum(props(zh = "戌", animal = "狗", pinyin = "Xū", english = "Dog"))]
     Xu,
-    #[strum(props(zh = "亥"))]
+    #[strum(props(zh = "亥", animal = "猪", pinyin = "Hài", english = "Pig"))]
     Hai,
 }
 
@@ -246,12 +359,67 @@ impl Branch {
     }
 
     pub fn from_year(year: i32) -> Self {
-        Self::from_repr((year - 4).rem_euclid(Self::COUNT as i32) as usize).unwrap()
+        Self::from_repr((i64::from(year) - 4).rem_euclid(Self::COUNT as i64) as usize).unwrap()
+    }
+
+    pub fn five_element(&self) -> FiveElement {
+        use FiveElement::*;
+        [
+            Water, Earth, Wood, Wood, Earth, Fire, Fire, Earth, Metal, Metal, Earth, Water,
+        ][*self as usize]
     }
 
     pub fn chinese(&self) -> &str {
         self.get_str("zh").unwrap()
     }
+
+    pub fn pinyin(&self) -> &str {
+        self.get_str("pinyin").unwrap()
+    }
+
+    pub fn english(&self) -> &str {
+        self.get_str("english").unwrap()
+    }
+
+    /// The Chinese zodiac animal (生肖) of the branch.
+    pub fn animal(&self) -> &str {
+        self.get_str("animal").unwrap()
+    }
+
+    /// The branch opposite to this one, which it clashes with (冲).
+    pub fn clash(&self) -> Self {
+        Self::from_repr((*self as usize + 6) % Self::COUNT).unwrap()
+    }
+
+    /// The branch this one harms (害), of the six harms (六害).
+    pub fn harm(&self) -> Self {
+        Self::from_repr((Self::COUNT + 7 - *self as usize) % Self::COUNT).unwrap()
+    }
+
+    /// The branch this one destroys (破), of the six destructions (六破).
+    // `is_multiple_of` would need Rust 1.87.
+    #[allow(clippy::manual_is_multiple_of)]
+    pub fn destruction(&self) -> Self {
+        let offset = if *self as usize % 2 == 0 { 9 } else { 3 };
+        Self::from_repr((*self as usize + offset) % Self::COUNT).unwrap()
+    }
+
+    /// The branch this one punishes (刑), of the three punishments (三刑);
+    /// Chen (辰), Wu (午), You (酉) and Hai (亥) punish themselves.
+    pub fn punishment(&self) -> Self {
+        use Branch::*;
+        match self {
+            Zi => Mao,
+            Mao => Zi,
+            Yin => Si,
+            Si => Shen,
+            Shen => Yin,
+            Chou => Xu,
+            Xu => Wei,
+            Wei => Chou,
+            Chen | Wu | You | Hai => *self,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -294,10 +462,55 @@ impl StemBranch {
     pub fn from_year(year: i32) -> Self {
         Self::new(Stem::from_year(year), Branch::from_year(year))
     }
+
+    /// The stem-branch of the day of the given date, which follows the
+    /// sexagenary cycle of days regardless of the calendar.
+    pub fn from_date(date: Date) -> Self {
+        Self::new_with_repr((date.jdn() as i64 + DAY_CYCLE_OFFSET as i64).rem_euclid(60) as usize)
+    }
+
+    /// The years of this stem-branch from `start` (inclusive) to `end`
+    /// (exclusive).
+    pub fn years_in_range(&self, start: i32, end: i32) -> impl Iterator<Item = i32> {
+        let offset = self.ord() as i32 - Self::from_year(start).ord() as i32;
+        (start.saturating_add(offset.rem_euclid(60))..end).step_by(60)
+    }
+
+    pub fn stem(&self) -> Stem {
+        self.stem
+    }
+
+    pub fn branch(&self) -> Branch {
+        self.branch
+    }
+
+    pub fn nayin(&self) -> Nayin {
+        Nayin::from_repr((self.ord() - 1) / 2).unwrap()
+    }
+
+    /// The stem-branch `n` steps after this one in the sexagenary cycle.
+    pub fn add(&self, n: i32) -> Self {
+        Self::new_with_repr((self.ord() as i32 - 1 + n).rem_euclid(60) as usize)
+    }
+}
+
+impl std::fmt::Display for StemBranch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            write!(f, "{}{}", self.stem.chinese(), self.branch.chinese())
+        } else {
+            write!(f, "{}{}", self.stem, self.branch)
+        }
+    }
 }
 
 #[test]
 fn test_stem_branch() {
+    assert_eq!(Stem::Geng.pinyin(), "Gēng");
+    assert_eq!(Stem::Geng.english(), "Yang Metal");
+    assert_eq!(Branch::You.pinyin(), "Yǒu");
+    assert_eq!(Branch::You.english(), "Rooster");
+
     assert_eq!(
         StemBranch::from_ord(1),
         StemBranch::from_stem_branch(Stem::Jia, Branch::Zi)
@@ -348,6 +561,353 @@ fn test_stem_branch() {
             .ord(),
         60
     );
+
+    let jia_zi = StemBranch::from_ord(1).unwrap();
+    assert_eq!(jia_zi.stem(), Stem::Jia);
+    assert_eq!(jia_zi.branch(), Branch::Zi);
+    assert_eq!(jia_zi.add(1), StemBranch::from_ord(2).unwrap());
+    assert_eq!(jia_zi.add(-1), StemBranch::from_ord(60).unwrap());
+    assert_eq!(jia_zi.add(120), jia_zi);
+    assert_eq!(jia_zi.to_string(), "JiaZi");
+    assert_eq!(format!("{:#}", jia_zi), "甲子");
+
+    assert_eq!(jia_zi.nayin(), Nayin::GoldInTheSea);
+    assert_eq!(jia_zi.add(1).nayin().chinese(), "海中金");
+    assert_eq!(jia_zi.add(59).nayin().chinese(), "大海水");
+    assert_eq!(jia_zi.nayin().english(), "Gold in the Sea");
+    assert_eq!(jia_zi.nayin().five_element(), FiveElement::Metal);
+    assert_eq!(jia_zi.add(2).nayin().five_element(), FiveElement::Fire);
+    for nayin in Nayin::VARIANTS {
+        let element = nayin.chinese().chars().last().unwrap().to_string();
+        assert_eq!(nayin.five_element().chinese(), element, "{}", nayin);
+    }
+    assert_eq!(Stem::Jia.five_element(), FiveElement::Wood);
+    assert_eq!(Stem::Gui.five_element(), FiveElement::Water);
+    assert_eq!(Branch::Zi.five_element(), FiveElement::Water);
+    assert_eq!(Branch::Wei.five_element().chinese(), "土");
+    assert_eq!(Branch::You.five_element().english(), "Metal");
+    assert_eq!(FiveElement::Water.generates(), FiveElement::Wood);
+    assert_eq!(FiveElement::Water.overcomes(), FiveElement::Fire);
+    assert_eq!(Branch::Zi.animal(), "鼠");
+    assert_eq!(Branch::Zi.clash(), Branch::Wu);
+    assert_eq!(Branch::Hai.clash(), Branch::Si);
+    assert_eq!(Branch::Zi.harm(), Branch::Wei);
+    assert_eq!(Branch::Shen.harm(), Branch::Hai);
+    assert_eq!(Branch::Zi.destruction(), Branch::You);
+    assert_eq!(Branch::Chou.destruction(), Branch::Chen);
+    assert_eq!(Branch::Hai.destruction(), Branch::Yin);
+    for branch in Branch::VARIANTS {
+        assert_eq!(branch.harm().harm(), *branch);
+        assert_eq!(branch.destruction().destruction(), *branch);
+    }
+    assert_eq!(Branch::Yin.punishment(), Branch::Si);
+    assert_eq!(Branch::Chen.punishment(), Branch::Chen);
+}
+
+/// The 30 "received sounds" (纳音) of the sexagenary cycle, each shared by
+/// two consecutive stem-branches.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    EnumCount,
+    VariantArray,
+    Display,
+    EnumString,
+    FromRepr,
+    EnumProperty,
+)]
+pub enum Nayin {
+    #[strum(props(zh = "海中金", english = "Gold in the Sea"))]
+    GoldInTheSea,
+    #[strum(props(zh = "炉中火", english = "Fire in the Furnace"))]
+    FireInTheFurnace,
+    #[strum(props(zh = "大林木", english = "Wood of the Great Forest"))]
+    WoodOfTheGreatForest,
+    #[strum(props(zh = "路旁土", english = "Earth by the Roadside"))]
+    EarthByTheRoadside,
+    #[strum(props(zh = "剑锋金", english = "Gold of the Sword Blade"))]
+    GoldOfTheSwordBlade,
+    #[strum(props(zh = "山头火", english = "Fire on the Mountain"))]
+    FireOnTheMountain,
+    #[strum(props(zh = "涧下水", english = "Water in the Ravine"))]
+    WaterInTheRavine,
+    #[strum(props(zh = "城头土", english = "Earth on the City Wall"))]
+    EarthOnTheCityWall,
+    #[strum(props(zh = "白蜡金", english = "White Wax Gold"))]
+    WhiteWaxGold,
+    #[strum(props(zh = "杨柳木", english = "Willow Wood"))]
+    WillowWood,
+    #[strum(props(zh = "泉中水", english = "Water in the Spring"))]
+    WaterInTheSpring,
+    #[strum(props(zh = "屋上土", english = "Earth on the Roof"))]
+    EarthOnTheRoof,
+    #[strum(props(zh = "霹雳火", english = "Thunderbolt Fire"))]
+    ThunderboltFire,
+    #[strum(props(zh = "松柏木", english = "Pine and Cypress Wood"))]
+    PineAndCypressWood,
+    #[strum(props(zh = "长流水", english = "Long Flowing Water"))]
+    LongFlowingWater,
+    #[strum(props(zh = "砂中金", english = "Gold in the Sand"))]
+    GoldInTheSand,
+    #[strum(props(zh = "山下火", english = "Fire at the Foot of the Mountain"))]
+    FireAtTheFootOfTheMountain,
+    #[strum(props(zh = "平地木", english = "Wood of the Flatland"))]
+    WoodOfTheFlatland,
+    #[strum(props(zh = "壁上土", english = "Earth on the Wall"))]
+    EarthOnTheWall,
+    #[strum(props(zh = "金箔金", english = "Gold Leaf Gold"))]
+    GoldLeafGold,
+    #[strum(props(zh = "覆灯火", english = "Lamp Fire"))]
+    LampFire,
+    #[strum(props(zh = "天河水", english = "Water of the Heavenly River"))]
+    WaterOfTheHeavenlyRiver,
+    #[strum(props(zh = "大驿土", english = "Earth of the Great Post Station"))]
+    EarthOfTheGreatPostStation,
+    #[strum(props(zh = "钗钏金", english = "Hairpin Gold"))]
+    HairpinGold,
+    #[strum(props(zh = "桑柘木", english = "Mulberry Wood"))]
+    MulberryWood,
+    #[strum(props(zh = "大溪水", english = "Water of the Great Stream"))]
+    WaterOfTheGreatStream,
+    #[strum(props(zh = "沙中土", english = "Earth in the Sand"))]
+    EarthInTheSand,
+    #[strum(props(zh = "天上火", english = "Fire in the Sky"))]
+    FireInTheSky,
+    #[strum(props(zh = "石榴木", english = "Pomegranate Wood"))]
+    PomegranateWood,
+    #[strum(props(zh = "大海水", english = "Water of the Great Sea"))]
+    WaterOfTheGreatSea,
+}
+
+impl Nayin {
+    /// The element (五行) of the sound, the last character of its name.
+    pub fn five_element(&self) -> FiveElement {
+        use Nayin::*;
+        match self {
+            GoldInTheSea | GoldOfTheSwordBlade | WhiteWaxGold | GoldInTheSand | GoldLeafGold
+            | HairpinGold => FiveElement::Metal,
+            WoodOfTheGreatForest | WillowWood | PineAndCypressWood | WoodOfTheFlatland
+            | MulberryWood | PomegranateWood => FiveElement::Wood,
+            WaterInTheRavine
+            | WaterInTheSpring
+            | LongFlowingWater
+            | WaterOfTheHeavenlyRiver
+            | WaterOfTheGreatStream
+            | WaterOfTheGreatSea => FiveElement::Water,
+            FireInTheFurnace
+            | FireOnTheMountain
+            | ThunderboltFire
+            | FireAtTheFootOfTheMountain
+            | LampFire
+            | FireInTheSky => FiveElement::Fire,
+            EarthByTheRoadside
+            | EarthOnTheCityWall
+            | EarthOnTheRoof
+            | EarthOnTheWall
+            | EarthOfTheGreatPostStation
+            | EarthInTheSand => FiveElement::Earth,
+        }
+    }
+
+    pub fn chinese(&self) -> &str {
+        self.get_str("zh").unwrap()
+    }
+
+    pub fn english(&self) -> &str {
+        self.get_str("english").unwrap()
+    }
+}
+
+/// The five elements (五行), in the order in which they generate one another.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    EnumCount,
+    VariantArray,
+    Display,
+    EnumString,
+    FromRepr,
+    EnumProperty,
+)]
+pub enum FiveElement {
+    #[strum(props(zh = "木", pinyin = "Mù", english = "Wood"))]
+    Wood,
+    #[strum(props(zh = "火", pinyin = "Huǒ", english = "Fire"))]
+    Fire,
+    #[strum(props(zh = "土", pinyin = "Tǔ", english = "Earth"))]
+    Earth,
+    #[strum(props(zh = "金", pinyin = "Jīn", english = "Metal"))]
+    Metal,
+    #[strum(props(zh = "水", pinyin = "Shuǐ", english = "Water"))]
+    Water,
+}
+
+impl FiveElement {
+    /// The element this one generates (相生), e.g. Wood generates Fire.
+    pub fn generates(&self) -> Self {
+        Self::from_repr((*self as usize + 1) % Self::COUNT).unwrap()
+    }
+
+    /// The element this one overcomes (相克), e.g. Wood overcomes Earth.
+    pub fn overcomes(&self) -> Self {
+        Self::from_repr((*self as usize + 2) % Self::COUNT).unwrap()
+    }
+
+    pub fn chinese(&self) -> &str {
+        self.get_str("zh").unwrap()
+    }
+
+    pub fn pinyin(&self) -> &str {
+        self.get_str("pinyin").unwrap()
+    }
+
+    pub fn english(&self) -> &str {
+        self.get_str("english").unwrap()
+    }
+}
+
+/// The 28 lunar mansions (二十八宿), assigned to days in a continuous cycle.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    EnumCount,
+    VariantArray,
+    Display,
+    EnumString,
+    FromRepr,
+    EnumProperty,
+)]
+pub enum LunarMansion {
+    #[strum(props(zh = "角木蛟", zh1 = "角"))]
+    Horn,
+    #[strum(props(zh = "亢金龙", zh1 = "亢"))]
+    Neck,
+    #[strum(props(zh = "氐土貉", zh1 = "氐"))]
+    Root,
+    #[strum(props(zh = "房日兔", zh1 = "房"))]
+    Room,
+    #[strum(props(zh = "心月狐", zh1 = "心"))]
+    Heart,
+    #[strum(props(zh = "尾火虎", zh1 = "尾"))]
+    Tail,
+    #[strum(props(zh = "箕水豹", zh1 = "箕"))]
+    WinnowingBasket,
+    #[strum(props(zh = "斗木獬", zh1 = "斗"))]
+    Dipper,
+    #[strum(props(zh = "牛金牛", zh1 = "牛"))]
+    Ox,
+    #[strum(props(zh = "女土蝠", zh1 = "女"))]
+    Girl,
+    #[strum(props(zh = "虚日鼠", zh1 = "虚"))]
+    Emptiness,
+    #[strum(props(zh = "危月燕", zh1 = "危"))]
+    Rooftop,
+    #[strum(props(zh = "室火猪", zh1 = "室"))]
+    Encampment,
+    #[strum(props(zh = "壁水貐", zh1 = "壁"))]
+    Wall,
+    #[strum(props(zh = "奎木狼", zh1 = "奎"))]
+    Legs,
+    #[strum(props(zh = "娄金狗", zh1 = "娄"))]
+    Bond,
+    #[strum(props(zh = "胃土雉", zh1 = "胃"))]
+    Stomach,
+    #[strum(props(zh = "昴日鸡", zh1 = "昴"))]
+    HairyHead,
+    #[strum(props(zh = "毕月乌", zh1 = "毕"))]
+    Net,
+    #[strum(props(zh = "觜火猴", zh1 = "觜"))]
+    TurtleBeak,
+    #[strum(props(zh = "参水猿", zh1 = "参"))]
+    ThreeStars,
+    #[strum(props(zh = "井木犴", zh1 = "井"))]
+    Well,
+    #[strum(props(zh = "鬼金羊", zh1 = "鬼"))]
+    Ghost,
+    #[strum(props(zh = "柳土獐", zh1 = "柳"))]
+    Willow,
+    #[strum(props(zh = "星日马", zh1 = "星"))]
+    Star,
+    #[strum(props(zh = "张月鹿", zh1 = "张"))]
+    ExtendedNet,
+    #[strum(props(zh = "翼火蛇", zh1 = "翼"))]
+    Wings,
+    #[strum(props(zh = "轸水蚓", zh1 = "轸"))]
+    Chariot,
+}
+
+impl LunarMansion {
+    pub fn ord(&self) -> u8 {
+        *self as u8 + 1
+    }
+
+    pub fn from_ord(ord: u8) -> Option<Self> {
+        Self::from_repr((ord as i8 - 1) as usize)
+    }
+
+    pub fn succ(&self) -> Self {
+        Self::from_repr((*self as i8 + 1).rem_euclid(Self::COUNT as i8) as usize).unwrap()
+    }
+
+    pub fn pred(&self) -> Self {
+        Self::from_repr((*self as i8 - 1).rem_euclid(Self::COUNT as i8) as usize).unwrap()
+    }
+
+    /// The mansion on duty for the given Julian day number.
+    pub fn from_jdn(jdn: i32) -> Self {
+        Self::from_repr((jdn + 4).rem_euclid(Self::COUNT as i32) as usize).unwrap()
+    }
+
+    /// The day of the week traditionally associated with the mansion (七曜).
+    pub fn weekday(&self) -> Weekday {
+        Weekday::from_repr((*self as usize + 3) % Weekday::COUNT).unwrap()
+    }
+
+    /// Chinese name of the variant.
+    ///
+    /// # Arguments
+    ///
+    /// * `length` - The length of the Chinese name, see examples below.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use omnical::*;
+    /// assert_eq!(LunarMansion::Horn.chinese(1), "角");
+    /// assert_eq!(LunarMansion::Horn.chinese(3), "角木蛟");
+    /// assert_eq!(LunarMansion::Horn.chinese(0), "角木蛟");
+    /// ```
+    pub fn chinese(&self, length: usize) -> &str {
+        match length {
+            1 => self.get_str("zh1").unwrap(),
+            _ => self.get_str("zh").unwrap(),
+        }
+    }
+}
+
+#[test]
+fn test_lunar_mansion() {
+    assert_eq!(LunarMansion::COUNT, 28);
+    assert_eq!(LunarMansion::Horn.pred(), LunarMansion::Chariot);
+    assert_eq!(LunarMansion::Chariot.succ(), LunarMansion::Horn);
+    assert_eq!(LunarMansion::Horn.weekday(), Thursday);
+    assert_eq!(LunarMansion::Room.weekday(), Sunday);
+    assert_eq!(LunarMansion::Net.weekday(), Monday);
+
+    for jdn in 2460000..2460028 {
+        let mansion = LunarMansion::from_jdn(jdn);
+        assert_eq!(mansion.weekday(), Date::from_jdn(jdn).weekday());
+    }
+    assert_eq!(LunarMansion::from_jdn(2460351), LunarMansion::Willow);
 }
 
 pub struct Calendar;
@@ -356,18 +916,77 @@ impl calendar::Calendar for Calendar {
     type Year = Year;
     type Month = Month;
     type Day = Day;
+    type MonthOrd = u8;
+    type DayOrd = u8;
 
     fn from_y(year: i32) -> Option<Year> {
-        // TODO: More precise validation
-        if (-5_000_000..=5_000_000).contains(&year) {
-            Some(Year::new(year))
-        } else {
-            None
-        }
+        Self::from_y_with_mode(year, Mode::default())
+    }
+}
+
+impl calendar::CalendarInfo for Calendar {
+    fn display_name() -> &'static str {
+        "Chinese"
+    }
+
+    fn supported_years() -> RangeInclusive<i32> {
+        SUPPORTED_YEARS
+    }
+
+    fn months_per_year() -> RangeInclusive<usize> {
+        12..=13
     }
 }
 
+/// The years supported by the Chinese calendar, within which the ephemeris
+/// (VSOP87) is valid.
+pub const SUPPORTED_YEARS: RangeInclusive<i32> = -2000..=6000;
+
+/// The years in which the Chinese calendar is accurate to the day.  Outside
+/// them, the growing difference between terrestrial and universal time (ΔT),
+/// which is not modeled, may shift a new moon or solar term near midnight to
+/// the adjacent day.
+pub const ACCURATE_YEARS: RangeInclusive<i32> = 1600..=2200;
+
+/// The error returned when a year is outside [`SUPPORTED_YEARS`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct YearOutOfRangeError {
+    pub year: i32,
+}
+
+impl std::fmt::Display for YearOutOfRangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "year {} is outside the supported range {}..={}",
+            self.year,
+            SUPPORTED_YEARS.start(),
+            SUPPORTED_YEARS.end()
+        )
+    }
+}
+
+impl std::error::Error for YearOutOfRangeError {}
+
 impl Calendar {
+    pub fn from_y_with_mode(year: i32, mode: Mode) -> Option<Year> {
+        Self::try_from_y_with_mode(year, mode).ok()
+    }
+
+    /// Like [`from_y`](calendar::Calendar::from_y), but tells why a year is
+    /// rejected.
+    pub fn try_from_y(year: i32) -> Result<Year, YearOutOfRangeError> {
+        Self::try_from_y_with_mode(year, Mode::default())
+    }
+
+    pub fn try_from_y_with_mode(year: i32, mode: Mode) -> Result<Year, YearOutOfRangeError> {
+        if SUPPORTED_YEARS.contains(&year) {
+            Ok(Year::new(year, mode))
+        } else {
+            Err(YearOutOfRangeError { year })
+        }
+    }
+
     pub fn from_ylm(year: i32, leap: bool, month: u8) -> Option<Month> {
         let year = Self::from_y(year)?;
         if leap && month != year.leap_month {
@@ -386,8 +1005,14 @@ impl Calendar {
 
 #[derive(Debug, Clone, Copy, Derivative)]
 #[derivative(PartialEq, Eq)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub struct Year {
     year: i32,
+    mode: Mode,
     #[derivative(PartialEq = "ignore")]
     first_day: Date,
     #[derivative(PartialEq = "ignore")]
@@ -397,16 +1022,36 @@ pub struct Year {
 }
 
 impl Year {
-    fn new(year: i32) -> Self {
-        let (first_day, num_days_of_months, leap_month) = calc_chinese_year_data(year);
+    /// The year, which must be in [`SUPPORTED_YEARS`].
+    pub(crate) fn new(year: i32, mode: Mode) -> Self {
+        let (first_day, num_days_of_months, leap_month) = get_chinese_year_data(year, mode);
         Self {
             year,
+            mode,
             first_day,
             num_days_of_months,
             leap_month,
         }
     }
 
+    /// The year of the given stem-branch nearest to `hint_year`, i.e. within
+    /// 30 years before and 29 years after it.
+    pub fn from_stem_branch_near(stem_branch: StemBranch, hint_year: i32) -> Option<Self> {
+        let year = stem_branch
+            .years_in_range(hint_year.saturating_sub(30), hint_year.saturating_add(30))
+            .next()?;
+        Calendar::from_y(year)
+    }
+
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Whether the year is within [`ACCURATE_YEARS`].
+    pub fn is_accurate(&self) -> bool {
+        ACCURATE_YEARS.contains(&self.year)
+    }
+
     pub fn stem(&self) -> Stem {
         Stem::from_year(self.year)
     }
@@ -418,6 +1063,41 @@ impl Year {
     pub fn stem_branch(&self) -> StemBranch {
         StemBranch::from_year(self.year)
     }
+
+    /// The traditional number of the leap month, i.e. that of the month it
+    /// follows, if the year has one.
+    pub fn leap_month_number(&self) -> Option<u8> {
+        (self.leap_month < 13).then_some(self.leap_month)
+    }
+
+    /// The date of the Chinese New Year (春节), i.e. the first day of the year.
+    pub fn new_year_day(&self) -> Date {
+        self.first_day
+    }
+
+    /// The dates of the first days of the months, including the leap month if
+    /// any, each of which is a day of new moon.
+    pub fn month_first_days(&self) -> Vec<Date> {
+        self.num_days_of_months[..self.num_months()]
+            .iter()
+            .scan(self.first_day, |date, &n| {
+                let first_day = *date;
+                *date += n as i32;
+                Some(first_day)
+            })
+            .collect()
+    }
+
+    /// The mid-term (中气) of each month, including the leap month if any,
+    /// which has none.
+    pub fn mid_term_table(&self) -> Vec<Option<(Date, SolarTerm)>> {
+        self.months().map(|m| m.zhong()).collect()
+    }
+
+    /// The number of days of each month, including the leap month if any.
+    pub fn month_lengths(&self) -> Vec<u8> {
+        self.num_days_of_months[..self.num_months()].to_vec()
+    }
 }
 
 impl calendar::Year<Calendar> for Year {
@@ -426,11 +1106,11 @@ impl calendar::Year<Calendar> for Year {
     }
 
     fn succ(&self) -> Self {
-        Self::new(self.year + 1)
+        Self::new(self.year + 1, self.mode)
     }
 
     fn pred(&self) -> Self {
-        Self::new(self.year - 1)
+        Self::new(self.year - 1, self.mode)
     }
 
     fn num_months(&self) -> usize {
@@ -466,11 +1146,98 @@ fn test_year() {
     );
     assert!(!year.is_leap());
     assert!(Calendar::from_y(2023).unwrap().is_leap());
+    assert_eq!(year.leap_month_number(), None);
+    assert_eq!(Calendar::from_y(2023).unwrap().leap_month_number(), Some(2));
 
     assert_eq!(year.day(1), Calendar::from_ymd(2021, 1, 1));
+    assert!(year.is_accurate());
+    assert!(!Calendar::from_y(1000).unwrap().is_accurate());
+    assert_eq!(Calendar::try_from_y(2021), Ok(year));
+    assert_eq!(
+        Calendar::try_from_y(10000),
+        Err(YearOutOfRangeError { year: 10000 })
+    );
+    assert_eq!(Calendar::from_y(-3000), None);
+
+    let lengths = Calendar::from_y(2023).unwrap().month_lengths();
+    assert_eq!(
+        lengths,
+        [29, 30, 29, 29, 30, 30, 29, 30, 30, 29, 30, 29, 30]
+    );
+    assert_eq!(year.month_lengths().len(), 12);
+    assert_eq!(year.new_year_day(), Date::from(year.first_day()));
+    assert_eq!(
+        year.month_first_days(),
+        year.months()
+            .map(|m| Date::from(m.first_day()))
+            .collect::<Vec<_>>()
+    );
+
+    let ren_yin = StemBranch::from_stem_branch(Stem::Ren, Branch::Yin).unwrap();
+    assert_eq!(
+        ren_yin.years_in_range(1900, 2100).collect::<Vec<_>>(),
+        [1902, 1962, 2022, 2082]
+    );
+    assert_eq!(ren_yin.years_in_range(2023, 2082).count(), 0);
+    assert_eq!(
+        Year::from_stem_branch_near(ren_yin, 2000).map(|y| y.ord()),
+        Some(2022)
+    );
+    assert_eq!(
+        Year::from_stem_branch_near(ren_yin, 1990).map(|y| y.ord()),
+        Some(1962)
+    );
+}
+
+#[test]
+fn test_mode() {
+    assert_eq!(
+        Calendar::from_y_with_mode(2023, Mode::default()),
+        Calendar::from_y(2023)
+    );
+    assert_ne!(
+        Calendar::from_y_with_mode(2023, Mode::TrueMoonMeanTerms),
+        Calendar::from_y(2023)
+    );
+    for mode in [
+        Mode::TrueMoonTrueTerms,
+        Mode::TrueMoonMeanTerms,
+        Mode::MeanMoonMeanTerms,
+        Mode::Deterministic,
+    ] {
+        for year in [500, 1600, 2023] {
+            let year = Calendar::from_y_with_mode(year, mode).unwrap();
+            assert_eq!(year.mode(), mode);
+            assert!((353..=385).contains(&year.num_days()));
+            assert_eq!(
+                Date::from(year.first_day()) + year.num_days() as i32,
+                Date::from(year.succ().first_day())
+            );
+            if mode == Mode::MeanMoonMeanTerms {
+                assert!(year
+                    .months()
+                    .all(|m| m.num_days() == 29 || m.num_days() == 30));
+            }
+            let date = Date::from(year.day(100).unwrap());
+            let day = Day::from_date_with_mode(date, mode);
+            assert_eq!(day, year.day(100).unwrap());
+            assert_eq!(day.succ().the_year().mode(), mode);
+        }
+    }
+    for year in [1900, 2023, 2057] {
+        assert_eq!(
+            calc_chinese_year_data(year, Mode::Deterministic),
+            calc_chinese_year_data(year, Mode::default())
+        );
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub struct Month {
     year: Year,
     month: u8,
@@ -481,6 +1248,9 @@ impl Month {
         Self { year, month }
     }
 
+    /// The traditional number of the month, shared by a leap month and the
+    /// month before it, as opposed to [`ord`](calendar::Month::ord), which is
+    /// the position of the month in the year.
     pub fn ord_no_leap(&self) -> u8 {
         if self.month < self.year.leap_month {
             self.month + 1
@@ -488,6 +1258,97 @@ impl Month {
             self.month
         }
     }
+
+    /// The solar terms falling within the month, with their days.
+    fn solar_terms(&self) -> Vec<(Date, SolarTerm)> {
+        let begin = Date::from(self.first_day());
+        let end = begin + self.num_days() as i32;
+        self.year
+            .mode
+            .solar_terms(begin, end, BEIJING_TZ)
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, st)| st.map(|st| (begin + i as i32, st)))
+            .collect()
+    }
+
+    /// The sectional term (节) falling within the month, if any.
+    pub fn jie(&self) -> Option<(Date, SolarTerm)> {
+        self.solar_terms()
+            .into_iter()
+            .find(|(_, st)| !st.is_mid_term())
+    }
+
+    /// The mid-term (中气) falling within the month, if any; a leap month is
+    /// one without.
+    pub fn zhong(&self) -> Option<(Date, SolarTerm)> {
+        self.solar_terms()
+            .into_iter()
+            .find(|(_, st)| st.is_mid_term())
+    }
+
+    /// The Chinese name of the month, e.g. "闰二月".
+    pub fn name(&self) -> String {
+        format!(
+            "{}{}",
+            self.leap_tag(),
+            MONTH_NAMES[self.ord_no_leap() as usize - 1]
+        )
+    }
+
+    /// The name of the month in pinyin, e.g. "Rùn Èryuè".
+    pub fn pinyin(&self) -> String {
+        let leap = if self.is_leap() { "Rùn " } else { "" };
+        format!("{}{}", leap, MONTH_PINYIN[self.ord_no_leap() as usize - 1])
+    }
+
+    /// The name of the month in English, e.g. "Leap Second Month".
+    pub fn english(&self) -> String {
+        let leap = if self.is_leap() { "Leap " } else { "" };
+        format!("{}{}", leap, MONTH_ENGLISH[self.ord_no_leap() as usize - 1])
+    }
+
+    /// The prefix marking a leap month, i.e. "闰" for a leap month and an empty
+    /// string otherwise.
+    pub fn leap_tag(&self) -> &'static str {
+        LEAP_NAMES[self.is_leap() as usize]
+    }
+
+    /// The stem-branch of the month (月建), with the first month always being
+    /// a Yin (寅) month; a leap month shares that of the month before it.
+    ///
+    /// For the month pillar of Four Pillars, which changes at solar terms
+    /// rather than new moons, see [`FourPillars`].
+    pub fn stem_branch(&self) -> StemBranch {
+        first_month_stem_branch(self.year.stem()).add(self.ord_no_leap() as i32 - 1)
+    }
+
+    /// Whether the month is a long month (大月) of 30 days rather than a short
+    /// month (小月) of 29 days.
+    pub fn is_long(&self) -> bool {
+        self.num_days() == 30
+    }
+
+    /// The name of the month followed by its length, e.g. "八月大" or "闰二月小".
+    pub fn name_with_length(&self) -> String {
+        format!(
+            "{}{}",
+            self.name(),
+            if self.is_long() { "大" } else { "小" }
+        )
+    }
+}
+
+/// The stem-branch of the first (Yin) month of a year with the given stem (五虎遁).
+fn first_month_stem_branch(year_stem: Stem) -> StemBranch {
+    let stem = Stem::from_repr((year_stem as usize % 5 * 2 + 2) % Stem::COUNT).unwrap();
+    StemBranch::new(stem, Branch::Yin)
+}
+
+/// The stem-branch of the first (Zi) double-hour of a day with the given stem (五鼠遁).
+fn first_shichen_stem_branch(day_stem: Stem) -> StemBranch {
+    let stem = Stem::from_repr(day_stem as usize % 5 * 2).unwrap();
+    StemBranch::new(stem, Branch::Zi)
 }
 
 impl calendar::Month<Calendar> for Month {
@@ -542,6 +1403,37 @@ fn test_month() {
     assert_eq!(Calendar::from_ym(2023, 2).unwrap().ord_no_leap(), 2);
     assert_eq!(Calendar::from_ym(2023, 3).unwrap().ord_no_leap(), 2);
     assert_eq!(Calendar::from_ym(2023, 4).unwrap().ord_no_leap(), 3);
+    let ymd = |y, m, d| Date::from(GregorianCalendar::from_ymd(y, m, d).unwrap());
+    assert_eq!(
+        Calendar::from_ym(2023, 2).unwrap().zhong(),
+        Some((ymd(2023, 3, 21), SpringEquinox))
+    );
+    assert_eq!(
+        Calendar::from_ym(2023, 2).unwrap().jie(),
+        Some((ymd(2023, 3, 6), AwakeningOfInsects))
+    );
+    assert_eq!(Calendar::from_ym(2023, 3).unwrap().zhong(), None);
+    assert_eq!(
+        Calendar::from_ym(2023, 3).unwrap().jie(),
+        Some((ymd(2023, 4, 5), PureBrightness))
+    );
+    let table = year.mid_term_table();
+    assert_eq!(table.iter().filter(|mt| mt.is_none()).count(), 1);
+    assert_eq!(table[2], None);
+    assert_eq!(
+        Calendar::from_y(2024).unwrap().mid_term_table()[0]
+            .unwrap()
+            .1,
+        RainWater
+    );
+    assert_eq!(Calendar::from_ym(2023, 2).unwrap().leap_tag(), "");
+    assert_eq!(Calendar::from_ym(2023, 3).unwrap().leap_tag(), "闰");
+    assert_eq!(Calendar::from_ym(2023, 1).unwrap().pinyin(), "Zhēngyuè");
+    assert_eq!(Calendar::from_ym(2023, 3).unwrap().pinyin(), "Rùn Èryuè");
+    assert_eq!(
+        Calendar::from_ym(2023, 3).unwrap().english(),
+        "Leap Second Month"
+    );
     assert_eq!(
         Calendar::from_ylm(2023, false, 1).unwrap(),
         Month::new(year, 0)
@@ -560,9 +1452,43 @@ fn test_month() {
         Month::new(year, 2)
     );
     assert_eq!(Calendar::from_ylm(2023, true, 3), None);
+
+    assert_eq!(
+        Calendar::from_ym(2024, 1).unwrap().stem_branch(),
+        StemBranch::from_stem_branch(Stem::Bing, Branch::Yin).unwrap()
+    );
+    assert_eq!(
+        Calendar::from_ym(2023, 3).unwrap().stem_branch(),
+        Calendar::from_ym(2023, 2).unwrap().stem_branch()
+    );
+    assert_eq!(
+        Calendar::from_ym(2023, 13).unwrap().stem_branch(),
+        StemBranch::from_stem_branch(Stem::Yi, Branch::Chou).unwrap()
+    );
+
+    assert!(!Calendar::from_ym(2023, 1).unwrap().is_long());
+    assert!(Calendar::from_ym(2023, 2).unwrap().is_long());
+    assert_eq!(
+        Calendar::from_ym(2023, 2).unwrap().name_with_length(),
+        "二月大"
+    );
+    assert_eq!(
+        Calendar::from_ym(2023, 3).unwrap().name_with_length(),
+        "闰二月小"
+    );
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Text)
+)]
 pub struct Day {
     month: Month,
     day: u8,
@@ -573,25 +1499,115 @@ impl Day {
         Self { month, day }
     }
 
+    /// Same as [`Calendar::from_ymd`](calendar::Calendar::from_ymd), for use
+    /// without importing the trait.
+    pub fn from_ymd(year: i32, month: u8, day: u8) -> Option<Self> {
+        <Calendar as calendar::Calendar>::from_ymd(year, month, day)
+    }
+
+    /// Same as [`Calendar::from_ylmd`].
+    pub fn from_ylmd(year: i32, leap: bool, month: u8, day: u8) -> Option<Self> {
+        Calendar::from_ylmd(year, leap, month, day)
+    }
+
+    /// Converts a date to a Chinese day, panicking for a date outside
+    /// [`SUPPORTED_YEARS`], as `From<Date>` did.
+    ///
+    /// `Day` cannot implement both `From<Date>` and [`TryFrom<Date>`](TryFrom),
+    /// so this keeps `ChineseDay::from(date)` compiling, though not
+    /// `date.into()`.
+    #[deprecated(note = "use `ChineseDay::try_from(date)` instead")]
+    pub fn from(date: Date) -> Self {
+        Self::try_from(date).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Converts a date to a Chinese day, panicking for a date outside
+    /// [`SUPPORTED_YEARS`].
+    ///
+    /// The Chinese calendar is always reckoned in Beijing time, so the day is
+    /// the same in every timezone, and converts back with [`From<Day>`](From).
     pub fn from_date_with_tz(date: Date, tz: f64) -> Self {
-        let gd = GregorianDay::from_date_with_tz(date, tz);
-        let cy = Calendar::from_y(gd.the_year().ord()).unwrap();
-        let cd = cy.first_day();
-        let cd_date = Date::from(cd);
-        if date >= cd_date {
-            cy.day((date - cd_date) as u16 + 1).unwrap()
-        } else {
-            let cy = cy.pred();
-            let cd = cy.first_day();
-            let cd_date = Date::from(cd);
-            cy.day((date - cd_date) as u16 + 1).unwrap()
+        Self::from_date_with_tz_and_mode(date, tz, Mode::default())
+    }
+
+    /// Converts a date to a Chinese day computed by the given rules,
+    /// panicking for a date outside [`SUPPORTED_YEARS`].
+    pub fn from_date_with_mode(date: Date, mode: Mode) -> Self {
+        Self::from_date_with_tz_and_mode(date, BEIJING_TZ, mode)
+    }
+
+    /// Converts a date to a Chinese day, or `None` for a date outside
+    /// [`SUPPORTED_YEARS`], as [`TryFrom<Date>`](TryFrom) does.
+    pub fn checked_from_date(date: Date) -> Option<Self> {
+        Self::checked_from_date_with_mode(date, Mode::default())
+    }
+
+    /// Like [`from_date_with_mode`](Self::from_date_with_mode), but returns
+    /// `None` instead of panicking for a date outside [`SUPPORTED_YEARS`].
+    pub fn checked_from_date_with_mode(date: Date, mode: Mode) -> Option<Self> {
+        Self::try_from_date_with_tz_and_mode(date, BEIJING_TZ, mode).ok()
+    }
+
+    fn from_date_with_tz_and_mode(date: Date, tz: f64, mode: Mode) -> Self {
+        Self::try_from_date_with_tz_and_mode(date, tz, mode).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Fails with the Chinese year that is outside [`SUPPORTED_YEARS`].
+    fn try_from_date_with_tz_and_mode(
+        date: Date,
+        tz: f64,
+        mode: Mode,
+    ) -> Result<Self, YearOutOfRangeError> {
+        // Past the Gregorian calendar, so is the Chinese year.
+        let year = match GregorianDay::checked_from_date_with_tz(date, tz) {
+            Some(gd) => gd.the_year().ord(),
+            None if date.jdn() < 0 => *gregorian::SUPPORTED_YEARS.start(),
+            None => *gregorian::SUPPORTED_YEARS.end(),
+        };
+        let mut cy = Calendar::try_from_y_with_mode(year, mode)?;
+        if date < Date::from(cy.first_day()) {
+            cy = Calendar::try_from_y_with_mode(year - 1, mode)?;
         }
+        let cd_date = Date::from(cy.first_day());
+        Ok(cy.day((date - cd_date) as u16 + 1).unwrap())
     }
 
     pub fn stem_branch(&self) -> StemBranch {
-        let date = Date::from(*self);
-        let repr = (date.jdn() + 18).rem_euclid(60) as usize;
-        StemBranch::new_with_repr(repr)
+        StemBranch::from_date(Date::from(*self))
+    }
+
+    /// The Chinese name of the day, e.g. "初一".
+    pub fn name(&self) -> &str {
+        DAY_NAMES[self.day as usize]
+    }
+
+    /// The ordinal of a day by its Chinese name, e.g. 1 for "初一".
+    pub fn ord_from_name(name: &str) -> Option<u8> {
+        DAY_NAMES
+            .iter()
+            .position(|day_name| *day_name == name)
+            .map(|i| i as u8 + 1)
+    }
+
+    /// The name of the day in pinyin, e.g. "Chūyī".
+    pub fn pinyin(&self) -> &str {
+        DAY_PINYIN[self.day as usize]
+    }
+
+    /// The name of the day in English, e.g. "Day 1".
+    pub fn english(&self) -> String {
+        format!("Day {}", self.day + 1)
+    }
+
+    /// The stem-branch of the year the day belongs to, with years divided at
+    /// the given boundary.
+    pub fn stem_branch_year(&self, boundary: Boundary) -> StemBranch {
+        match boundary {
+            Boundary::SpringFestival => self.the_year().stem_branch(),
+            Boundary::Lichun => {
+                StemBranch::from_year(Date::from(*self).solar_month(BEIJING_TZ).year())
+            }
+        }
     }
 }
 
@@ -635,19 +1651,444 @@ impl From<Day> for Date {
     }
 }
 
-impl From<Date> for Day {
-    fn from(date: Date) -> Self {
-        Self::from_date_with_tz(date, BEIJING_TZ)
+impl TryFrom<Date> for Day {
+    type Error = YearOutOfRangeError;
+
+    /// Converts a date to a Chinese day, failing for a date outside
+    /// [`SUPPORTED_YEARS`].
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        Self::try_from_date_with_tz_and_mode(date, BEIJING_TZ, Mode::default())
+    }
+}
+
+/// The error returned when a (year, leap, month, day) is not a valid Chinese
+/// day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidDayError {
+    pub year: i32,
+    pub leap: bool,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl std::fmt::Display for InvalidDayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid Chinese date: {}-{}{}-{}",
+            self.year,
+            if self.leap { "leap " } else { "" },
+            self.month,
+            self.day
+        )
+    }
+}
+
+impl std::error::Error for InvalidDayError {}
+
+/// A day from its year, whether its month is a leap month, the number of the
+/// month and the day, as in [`Calendar::from_ylmd`].
+impl TryFrom<(i32, bool, u8, u8)> for Day {
+    type Error = InvalidDayError;
+
+    fn try_from((year, leap, month, day): (i32, bool, u8, u8)) -> Result<Self, Self::Error> {
+        Calendar::from_ylmd(year, leap, month, day).ok_or(InvalidDayError {
+            year,
+            leap,
+            month,
+            day,
+        })
+    }
+}
+
+impl From<Day> for (i32, bool, u8, u8) {
+    fn from(day: Day) -> Self {
+        let month = day.the_month();
+        (
+            day.the_year().ord(),
+            month.is_leap(),
+            month.ord_no_leap(),
+            day.ord(),
+        )
     }
 }
 
+#[test]
+fn test_tuple() {
+    let day = Day::try_from((2023, true, 2, 5)).unwrap();
+    assert_eq!(day, Calendar::from_ylmd(2023, true, 2, 5).unwrap());
+    assert_eq!(<(i32, bool, u8, u8)>::from(day), (2023, true, 2, 5));
+    let error = Day::try_from((2024, true, 2, 5)).unwrap_err();
+    assert_eq!(error.to_string(), "invalid Chinese date: 2024-leap 2-5");
+    assert!(Day::try_from((9999, false, 1, 1)).is_err());
+}
+
 #[test]
 fn test_day() {
-    let day = Calendar::from_ymd(1949, 8, 10).unwrap();
+    // 1949-10-01, a well-known Jia-Zi (甲子) day; 1949 has a leap 7th month.
+    let day = Calendar::from_ylmd(1949, false, 8, 10).unwrap();
     assert_eq!(
         day.stem_branch(),
         StemBranch::from_stem_branch(Stem::Jia, Branch::Zi).unwrap()
     );
+    let day = Day::try_from(Date::from(GregorianCalendar::from_ymd(2000, 1, 1).unwrap())).unwrap();
+    assert_eq!(
+        day.stem_branch(),
+        StemBranch::from_stem_branch(Stem::Wu, Branch::Wu).unwrap()
+    );
+    assert_eq!(day.succ().stem_branch(), day.stem_branch().add(1));
+    assert_eq!(
+        StemBranch::from_date(Date::from(day) + 60),
+        day.stem_branch()
+    );
+    assert_eq!(Day::from_ymd(2024, 1, 22), Calendar::from_ymd(2024, 1, 22));
+    assert_eq!(Day::ord_from_name("初一"), Some(1));
+    assert_eq!(Day::ord_from_name("三十"), Some(30));
+    assert_eq!(Day::ord_from_name("三一"), None);
+    // The first month of 2024 has 29 days.
+    assert_eq!(Calendar::from_ymd(2024, 1, 1).unwrap().ord_in_year(), 1);
+    assert_eq!(Calendar::from_ymd(2024, 2, 1).unwrap().ord_in_year(), 30);
+    let year = Calendar::from_y(2023).unwrap();
+    assert_eq!(year.last_day().ord_in_year() as usize, year.num_days());
+    assert_eq!(
+        Day::from_ylmd(2023, true, 2, 1),
+        Calendar::from_ylmd(2023, true, 2, 1)
+    );
+    // The first day of the year, whatever the timezone.
+    let date = Date::from(GregorianCalendar::from_ymd(2024, 2, 10).unwrap());
+    for tz in [-12.0, 0.0, 8.0, 14.0] {
+        let day = Day::from_date_with_tz(date, tz);
+        assert_eq!(day, Calendar::from_ymd(2024, 1, 1).unwrap());
+        assert_eq!(Date::from(day), date);
+    }
+    let date = Date::from(GregorianCalendar::from_ymd(2024, 3, 1).unwrap());
+    assert_eq!(Day::checked_from_date(date), Day::try_from(date).ok());
+    #[allow(deprecated)]
+    let day = Day::from(date);
+    assert_eq!(Ok(day), Day::try_from(date));
+    // The Chinese year of 2000 BC January 1 began in 2001 BC.
+    for (y, m, d, year) in [(-2000, 1, 1, -2001), (6001, 12, 31, 6001)] {
+        let date = Date::from(GregorianCalendar::from_ymd(y, m, d).unwrap());
+        assert_eq!(Day::checked_from_date(date), None);
+        assert_eq!(Day::try_from(date), Err(YearOutOfRangeError { year }));
+    }
+    assert_eq!(Day::checked_from_date(Date::from_jdn(i32::MAX)), None);
+    assert_eq!(
+        Day::try_from(date + 3_000_000),
+        Err(YearOutOfRangeError { year: 10237 })
+    );
+    assert_eq!(Calendar::from_ymd(2024, 1, 22).unwrap().pinyin(), "Niàn'èr");
+    assert_eq!(Calendar::from_ymd(2024, 1, 22).unwrap().english(), "Day 22");
+
+    // The Beginning of Spring of 2024 fell on February 4, six days before the
+    // Chinese New Year.
+    let stem_branch_years = |y, m, d| {
+        let day = Day::try_from(Date::from(GregorianCalendar::from_ymd(y, m, d).unwrap())).unwrap();
+        (
+            day.stem_branch_year(Boundary::SpringFestival).to_string(),
+            day.stem_branch_year(Boundary::Lichun).to_string(),
+        )
+    };
+    assert_eq!(
+        stem_branch_years(2024, 2, 3),
+        ("GuiMao".into(), "GuiMao".into())
+    );
+    assert_eq!(
+        stem_branch_years(2024, 2, 4),
+        ("GuiMao".into(), "JiaChen".into())
+    );
+    assert_eq!(
+        stem_branch_years(2024, 2, 10),
+        ("JiaChen".into(), "JiaChen".into())
+    );
+    assert_eq!(
+        stem_branch_years(2023, 1, 10),
+        ("RenYin".into(), "RenYin".into())
+    );
+    assert_eq!(
+        stem_branch_years(2023, 12, 31),
+        ("GuiMao".into(), "GuiMao".into())
+    );
+}
+
+/// A double-hour (时辰) of a Chinese day, named by its earthly branch.
+///
+/// The Zi (子) double-hour runs from 23:00 to 01:00, and is counted as the
+/// beginning of the following day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shichen {
+    day: Day,
+    branch: Branch,
+}
+
+impl Shichen {
+    pub fn new(day: Day, branch: Branch) -> Self {
+        Self { day, branch }
+    }
+
+    /// The double-hour containing the given hour (0 to 23) of the day.
+    pub fn from_hour(day: Day, hour: u8) -> Option<Self> {
+        match hour {
+            23 => Some(Self::new(day.succ(), Branch::Zi)),
+            0..=22 => Some(Self::new(
+                day,
+                Branch::from_repr((hour as usize).div_ceil(2)).unwrap(),
+            )),
+            _ => None,
+        }
+    }
+
+    pub fn day(&self) -> Day {
+        self.day
+    }
+
+    pub fn branch(&self) -> Branch {
+        self.branch
+    }
+
+    /// The hour (0 to 23) at which the double-hour begins.
+    pub fn first_hour(&self) -> u8 {
+        (self.branch as u8 * 2 + 23) % 24
+    }
+
+    pub fn stem_branch(&self) -> StemBranch {
+        first_shichen_stem_branch(self.day.stem_branch().stem()).add(self.branch as i32)
+    }
+}
+
+impl std::fmt::Display for Shichen {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}时", self.branch.chinese())
+    }
+}
+
+#[test]
+fn test_shichen() {
+    let day = Calendar::from_ymd(2024, 1, 1).unwrap();
+    assert_eq!(Shichen::from_hour(day, 0).unwrap().branch(), Branch::Zi);
+    assert_eq!(Shichen::from_hour(day, 1).unwrap().branch(), Branch::Chou);
+    assert_eq!(Shichen::from_hour(day, 12).unwrap().branch(), Branch::Wu);
+    assert_eq!(Shichen::from_hour(day, 22).unwrap().branch(), Branch::Hai);
+    assert_eq!(
+        Shichen::from_hour(day, 23),
+        Some(Shichen::new(day.succ(), Branch::Zi))
+    );
+    assert_eq!(Shichen::from_hour(day, 24), None);
+    assert_eq!(Shichen::new(day, Branch::Zi).first_hour(), 23);
+    assert_eq!(Shichen::new(day, Branch::Wu).first_hour(), 11);
+
+    // The day is Jia-Chen (甲辰), so the Wu (午) double-hour is Geng-Wu (庚午).
+    assert_eq!(
+        day.stem_branch(),
+        StemBranch::from_stem_branch(Stem::Jia, Branch::Chen).unwrap()
+    );
+    assert_eq!(
+        Shichen::new(day, Branch::Wu).stem_branch(),
+        StemBranch::from_stem_branch(Stem::Geng, Branch::Wu).unwrap()
+    );
+    assert_eq!(Shichen::new(day, Branch::Wu).to_string(), "午时");
+}
+
+/// A moment in the Chinese calendar: a day and a time of the day.
+///
+/// The day is the civil one, which changes at midnight, while the double-hour
+/// of 23:00 already belongs to the following day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateTime {
+    day: Day,
+    hour: u8,
+    minute: u8,
+    second: u8,
+}
+
+impl DateTime {
+    pub fn new(day: Day, hour: u8, minute: u8, second: u8) -> Option<Self> {
+        if hour < 24 && minute < 60 && second < 60 {
+            Some(Self {
+                day,
+                hour,
+                minute,
+                second,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// The moment at the given Julian date, in the given timezone.
+    pub fn from_jd(jd: f64, tz: f64) -> Self {
+        let local_jd = jd + 0.5 + tz / 24.0;
+        let date = Date::from_jdn(local_jd.floor() as i32);
+        let seconds = (local_jd.fract() * 86400.0).floor() as u32;
+        Self::new(
+            Day::from_date_with_tz(date, BEIJING_TZ),
+            (seconds / 3600) as u8,
+            (seconds / 60 % 60) as u8,
+            (seconds % 60) as u8,
+        )
+        .unwrap()
+    }
+
+    /// The moment at the given Unix time, in the given timezone.
+    ///
+    /// As in POSIX, leap seconds are ignored, see [`timescale`].
+    pub fn from_unix_time(unix_time: u64, tz: f64) -> Self {
+        Self::from_jd(timescale::unix_time_to_jd(unix_time), tz)
+    }
+
+    /// The moment at the given Julian date in Terrestrial Time (TT), e.g. of
+    /// an astronomical event, in the given timezone.
+    pub fn from_tt_jd(jd: f64, tz: f64, table: Option<&timescale::LeapSecondTable>) -> Self {
+        Self::from_jd(timescale::tt_jd_to_utc_jd(jd, table), tz)
+    }
+
+    pub fn day(&self) -> Day {
+        self.day
+    }
+
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+
+    pub fn shichen(&self) -> Shichen {
+        Shichen::from_hour(self.day, self.hour).unwrap()
+    }
+}
+
+impl std::fmt::Display for DateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let shichen = self.shichen();
+        if f.alternate() {
+            write!(f, "{:#}{}", shichen.day(), shichen)
+        } else {
+            write!(f, "{}{}", shichen.day(), shichen)
+        }
+    }
+}
+
+#[test]
+fn test_date_time() {
+    // 2024-02-10 12:34:56 in Beijing.
+    let date_time = DateTime::from_unix_time(1707539696, 8.0);
+    assert_eq!(date_time.day(), Calendar::from_ymd(2024, 1, 1).unwrap());
+    assert_eq!(
+        (date_time.hour(), date_time.minute(), date_time.second()),
+        (12, 34, 56)
+    );
+    assert_eq!(date_time.shichen().branch(), Branch::Wu);
+    assert_eq!(
+        date_time.shichen().stem_branch(),
+        StemBranch::from_stem_branch(Stem::Geng, Branch::Wu).unwrap()
+    );
+    assert_eq!(date_time.to_string(), "甲辰年正月初一午时");
+
+    // 2024-02-10 23:30 in Beijing is in the Zi (子) double-hour of the next day.
+    let date_time = DateTime::from_unix_time(1707579000, 8.0);
+    assert_eq!(date_time.day(), Calendar::from_ymd(2024, 1, 1).unwrap());
+    assert_eq!(date_time.to_string(), "甲辰年正月初二子时");
+
+    // 69.184 seconds of TT before 2024-02-10 12:00 UTC.
+    let table = timescale::LeapSecondTable::builtin();
+    let date_time = DateTime::from_tt_jd(2460351.0, 0.0, Some(&table));
+    assert_eq!(
+        (date_time.hour(), date_time.minute(), date_time.second()),
+        (11, 58, 50)
+    );
+
+    assert_eq!(DateTime::new(date_time.day(), 24, 0, 0), None);
+}
+
+/// The Four Pillars (四柱) of a moment: the stem-branches of its year, month,
+/// day, and double-hour.
+///
+/// The year and month pillars change at the exact moments of the twelve
+/// "sectional" solar terms (节), with the year beginning at 立春.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FourPillars {
+    pub year: StemBranch,
+    pub month: StemBranch,
+    pub day: StemBranch,
+    pub hour: StemBranch,
+}
+
+impl FourPillars {
+    /// The Four Pillars of the moment at the given Julian date, in the given
+    /// timezone.
+    pub fn from_jd(jd: f64, tz: f64) -> Self {
+        let date_time = DateTime::from_jd(jd, tz);
+        let date = Date::from(date_time.day());
+        let shichen = date_time.shichen();
+
+        // The months since the beginning of spring, with 0 being the Yin month.
+        let month = ((astronomy::get_sun_ecl_long(jd) - 315.0).rem_euclid(360.0) / 30.0) as i32;
+        let gregorian_day = GregorianDay::from(date);
+        let year = if month >= 10 && gregorian_day.the_month().ord() <= 2 {
+            gregorian_day.the_year().ord() - 1
+        } else {
+            gregorian_day.the_year().ord()
+        };
+        let year = StemBranch::from_year(year);
+
+        Self {
+            year,
+            month: first_month_stem_branch(year.stem()).add(month),
+            day: shichen.day().stem_branch(),
+            hour: shichen.stem_branch(),
+        }
+    }
+
+    /// The Four Pillars of the moment at the given Unix time, in the given
+    /// timezone.
+    ///
+    /// As in POSIX, leap seconds are ignored, see [`timescale`].
+    pub fn from_unix_time(unix_time: u64, tz: f64) -> Self {
+        Self::from_jd(timescale::unix_time_to_jd(unix_time), tz)
+    }
+}
+
+impl std::fmt::Display for FourPillars {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            write!(
+                f,
+                "{:#}年 {:#}月 {:#}日 {:#}时",
+                self.year, self.month, self.day, self.hour
+            )
+        } else {
+            write!(f, "{} {} {} {}", self.year, self.month, self.day, self.hour)
+        }
+    }
+}
+
+#[test]
+fn test_four_pillars() {
+    // 2024-02-10 12:00 in Beijing.
+    let pillars = FourPillars::from_unix_time(1707537600, 8.0);
+    assert_eq!(format!("{:#}", pillars), "甲辰年 丙寅月 甲辰日 庚午时");
+
+    // 2024-02-03 12:00 in Beijing, before the beginning of spring.
+    let pillars = FourPillars::from_unix_time(1706932800, 8.0);
+    assert_eq!(format!("{:#}", pillars), "癸卯年 乙丑月 丁酉日 丙午时");
+
+    // 2024-01-01 23:30 in Beijing belongs to the next day.
+    let pillars = FourPillars::from_unix_time(1704123000, 8.0);
+    assert_eq!(format!("{:#}", pillars), "癸卯年 甲子月 乙丑日 丙子时");
+
+    // Reference values from published almanacs (万年历).
+    // 2000-01-01 12:00 in Beijing.
+    let pillars = FourPillars::from_unix_time(946699200, 8.0);
+    assert_eq!(format!("{:#}", pillars), "己卯年 丙子月 戊午日 戊午时");
+    // 1949-10-01 15:00 in Beijing.
+    let pillars = FourPillars::from_jd(2433190.5 + 7.0 / 24.0, 8.0);
+    assert_eq!(format!("{:#}", pillars), "己丑年 癸酉月 甲子日 壬申时");
 }
 
 impl std::fmt::Display for Year {
@@ -668,24 +2109,10 @@ impl std::fmt::Display for Year {
 
 impl std::fmt::Display for Month {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let l = self.year.leap_month < 13 && self.month == self.year.leap_month;
-        let m = if self.month < self.year.leap_month {
-            self.month
-        } else {
-            self.month - 1
-        };
         if f.alternate() {
-            write!(
-                f,
-                "{:#}{}{}",
-                self.year, LEAP_NAMES[l as usize], MONTH_NAMES[m as usize]
-            )
+            write!(f, "{:#}{}", self.year, self.name())
         } else {
-            write!(
-                f,
-                "{}{}{}",
-                self.year, LEAP_NAMES[l as usize], MONTH_NAMES[m as usize]
-            )
+            write!(f, "{}{}", self.year, self.name())
         }
     }
 }
@@ -693,9 +2120,83 @@ impl std::fmt::Display for Month {
 impl std::fmt::Display for Day {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if f.alternate() {
-            write!(f, "{:#}{}", self.month, DAY_NAMES[self.day as usize])
+            write!(f, "{:#}{}", self.month, self.name())
         } else {
-            write!(f, "{}{}", self.month, DAY_NAMES[self.day as usize])
+            write!(f, "{}{}", self.month, self.name())
         }
     }
 }
+
+#[cfg(feature = "bincode")]
+#[test]
+fn test_bincode() {
+    let config = bincode::config::standard();
+    let days: Vec<Day> = Calendar::from_y(2023).unwrap().days().collect();
+    let bytes = bincode::encode_to_vec(&days, config).unwrap();
+    let (decoded, _): (Vec<Day>, _) = bincode::decode_from_slice(&bytes, config).unwrap();
+    assert_eq!(decoded, days);
+    let year = decoded[0].the_year();
+    assert_eq!(year.new_year_day(), days[0].the_year().new_year_day());
+    assert_eq!(year.leap_month_number(), Some(2));
+
+    let date = Date::from(days[0]);
+    let bytes = bincode::encode_to_vec(date, config).unwrap();
+    assert_eq!(
+        bincode::decode_from_slice(&bytes, config).unwrap(),
+        (date, 5)
+    );
+}
+
+#[cfg(feature = "rkyv")]
+#[test]
+fn test_rkyv() {
+    let days: Vec<Day> = Calendar::from_y(2023).unwrap().days().collect();
+    let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&days).unwrap();
+    let archived = rkyv::access::<rkyv::Archived<Vec<Day>>, rkyv::rancor::Error>(&bytes).unwrap();
+    assert_eq!(archived.len(), 384);
+    let day: Day = rkyv::deserialize::<_, rkyv::rancor::Error>(&archived[1]).unwrap();
+    assert_eq!(day, days[1]);
+    assert_eq!(day.the_year().leap_month_number(), Some(2));
+    assert_eq!(day.to_string(), "癸卯年正月初二");
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn test_tracing() {
+    use std::sync::Arc;
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata};
+
+    /// Collects the names of the spans.
+    struct Spans(Arc<Mutex<Vec<&'static str>>>);
+
+    impl tracing::Subscriber for Spans {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let mut names = self.0.lock().unwrap();
+            names.push(span.metadata().name());
+            Id::from_u64(names.len() as u64)
+        }
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+        fn event(&self, _: &Event<'_>) {}
+        fn enter(&self, _: &Id) {}
+        fn exit(&self, _: &Id) {}
+    }
+
+    let names = Arc::new(Mutex::new(Vec::new()));
+    tracing::subscriber::with_default(Spans(names.clone()), || {
+        calc_chinese_year_data(1234, Mode::MeanMoonMeanTerms)
+    });
+    let names = names.lock().unwrap();
+    for name in [
+        "calc_chinese_year_data",
+        "calc_sui_data",
+        "get_mean_lunar_phases",
+        "get_mean_solar_terms",
+    ] {
+        assert!(names.contains(&name), "{}", name);
+    }
+}
@@ -0,0 +1,162 @@
+//! Yearly recurrences of a day of the Chinese calendar, such as birthdays (生日)
+//! and death anniversaries (忌日).
+
+use super::{Calendar, Day};
+use crate::calendar::{Day as _, Month as _};
+use crate::*;
+
+/// How an anniversary is observed in a year having a leap month with the same
+/// number as its month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LeapPolicy {
+    /// In the regular month only.
+    #[default]
+    Regular,
+    /// In the leap month if the year has one, otherwise in the regular month.
+    Leap,
+    /// In both the regular month and the leap month.
+    Both,
+}
+
+/// How an anniversary is observed in a month that does not have its day,
+/// i.e. on the 30th of a short month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MissingDayPolicy {
+    /// On the last day of the month.
+    #[default]
+    LastDay,
+    /// On the first day of the next month.
+    NextDay,
+    /// Not at all.
+    Skip,
+}
+
+/// A day of the Chinese calendar recurring every year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Anniversary {
+    pub month: u8,
+    pub day: u8,
+    pub leap: LeapPolicy,
+    pub missing_day: MissingDayPolicy,
+}
+
+impl Anniversary {
+    /// The anniversary of the given month and day, with the default policies.
+    pub fn new(month: u8, day: u8) -> Self {
+        Self {
+            month,
+            day,
+            leap: LeapPolicy::default(),
+            missing_day: MissingDayPolicy::default(),
+        }
+    }
+
+    /// The anniversary of the given day, observed in the leap month if the day
+    /// is in one.
+    pub fn from_day(day: Day) -> Self {
+        let month = day.the_month();
+        Self {
+            leap: if month.is_leap() {
+                LeapPolicy::Leap
+            } else {
+                LeapPolicy::Regular
+            },
+            ..Self::new(month.ord_no_leap(), day.ord())
+        }
+    }
+
+    fn date_in_month(&self, year: i32, leap: bool) -> Option<Date> {
+        let month = Calendar::from_ylm(year, leap, self.month)?;
+        match month.day(self.day) {
+            Some(day) => Some(day.into()),
+            None if !(1..=30).contains(&self.day) => None,
+            None => match self.missing_day {
+                MissingDayPolicy::LastDay => Some(month.last_day().into()),
+                MissingDayPolicy::NextDay => Some(month.succ().first_day().into()),
+                MissingDayPolicy::Skip => None,
+            },
+        }
+    }
+
+    /// The days of the anniversary in the given Chinese year, in order; there
+    /// may be none, one or two of them.
+    pub fn dates(&self, year: i32) -> Vec<Date> {
+        let regular = self.date_in_month(year, false);
+        let leap = self.date_in_month(year, true);
+        let has_leap = Calendar::from_ylm(year, true, self.month).is_some();
+        match self.leap {
+            LeapPolicy::Regular => regular.into_iter().collect(),
+            LeapPolicy::Leap if has_leap => leap.into_iter().collect(),
+            LeapPolicy::Leap => regular.into_iter().collect(),
+            LeapPolicy::Both => regular.into_iter().chain(leap).collect(),
+        }
+    }
+
+    /// The days of the anniversary in the Chinese years from `start`
+    /// (inclusive) to `end` (exclusive).
+    pub fn dates_in_range(&self, start: i32, end: i32) -> impl Iterator<Item = Date> + '_ {
+        (start..end).flat_map(|year| self.dates(year))
+    }
+}
+
+#[test]
+fn test_anniversary() {
+    use crate::calendar::Calendar as _;
+
+    let ylmd = |y, l, m, d| Date::from(Calendar::from_ylmd(y, l, m, d).unwrap());
+
+    // 2023 has a leap second month.
+    let anniversary = Anniversary::new(2, 15);
+    assert_eq!(anniversary.dates(2023), [ylmd(2023, false, 2, 15)]);
+    assert_eq!(anniversary.dates(2024), [ylmd(2024, false, 2, 15)]);
+    let anniversary = Anniversary {
+        leap: LeapPolicy::Both,
+        ..anniversary
+    };
+    assert_eq!(
+        anniversary.dates(2023),
+        [ylmd(2023, false, 2, 15), ylmd(2023, true, 2, 15)]
+    );
+    assert_eq!(anniversary.dates(2024), [ylmd(2024, false, 2, 15)]);
+
+    let anniversary = Anniversary::from_day(Calendar::from_ylmd(2023, true, 2, 15).unwrap());
+    assert_eq!(
+        anniversary,
+        Anniversary {
+            leap: LeapPolicy::Leap,
+            ..Anniversary::new(2, 15)
+        }
+    );
+    assert_eq!(anniversary.dates(2023), [ylmd(2023, true, 2, 15)]);
+    assert_eq!(anniversary.dates(2024), [ylmd(2024, false, 2, 15)]);
+
+    // The twelfth month of 2024 has 29 days, that of 2022 has 30.
+    let anniversary = Anniversary::new(12, 30);
+    assert_eq!(Calendar::from_ym(2024, 12).unwrap().num_days(), 29);
+    assert_eq!(anniversary.dates(2024), [ylmd(2024, false, 12, 29)]);
+    assert_eq!(anniversary.dates(2022), [ylmd(2022, false, 12, 30)]);
+    let anniversary = Anniversary {
+        missing_day: MissingDayPolicy::NextDay,
+        ..anniversary
+    };
+    assert_eq!(anniversary.dates(2024), [ylmd(2025, false, 1, 1)]);
+    let anniversary = Anniversary {
+        missing_day: MissingDayPolicy::Skip,
+        ..anniversary
+    };
+    assert_eq!(anniversary.dates(2024), []);
+    assert_eq!(anniversary.dates_in_range(2022, 2025).count(), 2);
+
+    // The leap second month of 2023 has 29 days.
+    let anniversary = Anniversary {
+        leap: LeapPolicy::Both,
+        ..Anniversary::new(2, 30)
+    };
+    assert_eq!(
+        anniversary.dates(2023),
+        [ylmd(2023, false, 2, 30), ylmd(2023, true, 2, 29)]
+    );
+
+    assert_eq!(Anniversary::new(13, 1).dates(2024), []);
+    assert_eq!(Anniversary::new(1, 31).dates(2024), []);
+}
@@ -0,0 +1,216 @@
+//! Other day and year cycles of East Asian almanacs: the nine stars (九星)
+//! and the Japanese rokuyō (六曜).
+
+use strum::{Display, EnumCount, EnumProperty, EnumString, FromRepr, VariantArray};
+
+use super::{Day, BEIJING_TZ};
+use crate::astronomy::find_solar_terms;
+use crate::calendar::{Calendar as _, Day as _, Year as _};
+use crate::*;
+
+/// The nine stars (九星) of the flying-star cycle.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    EnumCount,
+    VariantArray,
+    Display,
+    EnumString,
+    FromRepr,
+    EnumProperty,
+)]
+#[repr(u8)]
+pub enum NineStar {
+    #[strum(props(zh = "一白"))]
+    OneWhite = 1,
+    #[strum(props(zh = "二黑"))]
+    TwoBlack,
+    #[strum(props(zh = "三碧"))]
+    ThreeJade,
+    #[strum(props(zh = "四绿"))]
+    FourGreen,
+    #[strum(props(zh = "五黄"))]
+    FiveYellow,
+    #[strum(props(zh = "六白"))]
+    SixWhite,
+    #[strum(props(zh = "七赤"))]
+    SevenRed,
+    #[strum(props(zh = "八白"))]
+    EightWhite,
+    #[strum(props(zh = "九紫"))]
+    NinePurple,
+}
+
+/// The day of the Jia-Zi (甲子) day nearest to the given date.
+fn nearest_jia_zi(date: Date) -> Date {
+    let offset = StemBranch::from_date(date).ord() as i32 - 1;
+    if offset < 30 {
+        date + -offset
+    } else {
+        date + (60 - offset)
+    }
+}
+
+fn summer_solstice(year: i32) -> Date {
+    let begin = Date::from(GregorianCalendar::from_ymd(year, 6, 15).unwrap());
+    find_solar_terms(begin, begin + 15, BEIJING_TZ)
+        .into_iter()
+        .find(|&(_, st)| st == SummerSolstice)
+        .unwrap()
+        .0
+}
+
+impl NineStar {
+    pub fn ord(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn from_ord(ord: u8) -> Option<Self> {
+        Self::from_repr(ord)
+    }
+
+    /// The star `n` steps after this one, wrapping around from nine to one.
+    pub fn add(&self, n: i32) -> Self {
+        Self::from_ord((self.ord() as i32 - 1 + n).rem_euclid(9) as u8 + 1).unwrap()
+    }
+
+    /// The star of the given year, which begins at the Beginning of Spring
+    /// (立春); the stars descend year by year, with 1984 being Seven Red.
+    pub fn from_year(year: i32) -> Self {
+        Self::SevenRed.add(1984 - year)
+    }
+
+    /// The star of the given date.
+    ///
+    /// The stars ascend from One White from the Jia-Zi (甲子) day nearest to
+    /// the winter solstice (阳遁), and descend from Nine Purple from the Jia-Zi
+    /// day nearest to the summer solstice (阴遁).
+    pub fn from_date(date: Date) -> Self {
+        let year = GregorianDay::from(date).the_year().ord();
+        let (begin, ascending) = [
+            (nearest_jia_zi(summer_solstice(year - 1)), false),
+            (
+                nearest_jia_zi(lunisolar::winter_solstice(
+                    year - 1,
+                    &lunisolar::Rules::CHINESE,
+                )),
+                true,
+            ),
+            (nearest_jia_zi(summer_solstice(year)), false),
+            (
+                nearest_jia_zi(lunisolar::winter_solstice(year, &lunisolar::Rules::CHINESE)),
+                true,
+            ),
+        ]
+        .into_iter()
+        .rev()
+        .find(|&(begin, _)| begin <= date)
+        .unwrap();
+        if ascending {
+            Self::OneWhite.add(date - begin)
+        } else {
+            Self::NinePurple.add(begin - date)
+        }
+    }
+
+    pub fn chinese(&self) -> &str {
+        self.get_str("zh").unwrap()
+    }
+}
+
+#[test]
+fn test_nine_star() {
+    assert_eq!(NineStar::from_year(1984), NineStar::SevenRed);
+    assert_eq!(NineStar::from_year(2024), NineStar::ThreeJade);
+    assert_eq!(NineStar::from_year(2025), NineStar::TwoBlack);
+    assert_eq!(NineStar::from_year(2026), NineStar::OneWhite);
+    assert_eq!(NineStar::from_year(2027), NineStar::NinePurple);
+    assert_eq!(NineStar::NinePurple.chinese(), "九紫");
+
+    let ymd = |y, m, d| Date::from(GregorianCalendar::from_ymd(y, m, d).unwrap());
+    // The winter solstice of 2023 fell on December 22, and the nearest Jia-Zi
+    // day was January 1, 2024; the summer solstice of 2024 fell on June 21,
+    // and the nearest Jia-Zi day was June 29.
+    assert_eq!(NineStar::from_date(ymd(2024, 1, 1)), NineStar::OneWhite);
+    assert_eq!(NineStar::from_date(ymd(2024, 1, 2)), NineStar::TwoBlack);
+    // The descending stars end with One White, followed by One White again.
+    assert_eq!(NineStar::from_date(ymd(2023, 12, 31)), NineStar::OneWhite);
+    assert_eq!(NineStar::from_date(ymd(2024, 6, 29)), NineStar::NinePurple);
+    assert_eq!(NineStar::from_date(ymd(2024, 6, 30)), NineStar::EightWhite);
+    assert_eq!(NineStar::from_date(ymd(2024, 6, 28)), NineStar::NinePurple);
+}
+
+/// The six days (六曜) of the Japanese calendar.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    EnumCount,
+    VariantArray,
+    Display,
+    EnumString,
+    FromRepr,
+    EnumProperty,
+)]
+pub enum Rokuyo {
+    #[strum(props(zh = "先胜", ja = "先勝"))]
+    Sensho,
+    #[strum(props(zh = "友引", ja = "友引"))]
+    Tomobiki,
+    #[strum(props(zh = "先负", ja = "先負"))]
+    Senbu,
+    #[strum(props(zh = "佛灭", ja = "仏滅"))]
+    Butsumetsu,
+    #[strum(props(zh = "大安", ja = "大安"))]
+    Taian,
+    #[strum(props(zh = "赤口", ja = "赤口"))]
+    Shakko,
+}
+
+impl Rokuyo {
+    /// The rokuyō of the given day of the given month, with the first day of
+    /// the first month being Sensho (先胜).
+    pub fn from_month_day(month: u8, day: u8) -> Self {
+        Self::from_repr((month as usize + day as usize - 2) % Self::COUNT).unwrap()
+    }
+
+    pub fn chinese(&self) -> &str {
+        self.get_str("zh").unwrap()
+    }
+
+    pub fn japanese(&self) -> &str {
+        self.get_str("ja").unwrap()
+    }
+}
+
+impl Day {
+    /// The star (九星) of the day.
+    pub fn nine_star(&self) -> NineStar {
+        NineStar::from_date(Date::from(*self))
+    }
+
+    /// The rokuyō (六曜) of the day; a leap month counts as the month it
+    /// follows.
+    pub fn rokuyo(&self) -> Rokuyo {
+        Rokuyo::from_month_day(self.the_month().ord_no_leap(), self.ord())
+    }
+}
+
+#[test]
+fn test_rokuyo() {
+    assert_eq!(Rokuyo::from_month_day(1, 1), Rokuyo::Sensho);
+    assert_eq!(Rokuyo::from_month_day(6, 1), Rokuyo::Shakko);
+    assert_eq!(Rokuyo::from_month_day(12, 30), Rokuyo::Taian);
+
+    // The Mid-Autumn Festival is always Butsumetsu (仏滅).
+    let day = super::Calendar::from_ymd(2024, 8, 15).unwrap();
+    assert_eq!(day.rokuyo(), Rokuyo::Butsumetsu);
+    assert_eq!(day.rokuyo().japanese(), "仏滅");
+    assert_eq!(day.rokuyo().chinese(), "佛灭");
+    assert_eq!(day.nine_star(), NineStar::from_date(Date::from(day)));
+}
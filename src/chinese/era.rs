@@ -0,0 +1,112 @@
+//! Era names (年号) of Chinese years.
+//!
+//! The eras are those of China and the Minguo calendar in [`regnal`], which
+//! cover the Ming and Qing dynasties and the Republic of China. An era is
+//! identified with the Chinese years from its first year (元年) to its last, so
+//! the year in which an era changes belongs to both.
+//!
+//! [`regnal`]: crate::regnal
+
+use super::{Calendar, Year};
+use crate::calendar::Year as _;
+use crate::regnal::{self, Country};
+pub use crate::regnal::{Era, EraYear, ParseEraYearError};
+
+/// The countries whose eras count Chinese years.
+const COUNTRIES: [Country; 2] = [Country::China, Country::Taiwan];
+
+/// Finds an era of Chinese years by its name.
+fn era_from_name(name: &str) -> Option<&'static Era> {
+    Era::from_name(name).filter(|era| COUNTRIES.contains(&era.country))
+}
+
+/// All eras containing the given Chinese year, in chronological order.
+pub fn era_years(year: i32) -> Vec<EraYear> {
+    COUNTRIES
+        .iter()
+        .flat_map(|&country| regnal::era_years(country, year))
+        .collect()
+}
+
+impl Calendar {
+    /// The Chinese year of the given year of the era named `era_name`.
+    pub fn from_era_year(era_name: &str, era_year: u32) -> Option<Year> {
+        use crate::calendar::Calendar as _;
+        Self::from_y(era_from_name(era_name)?.year(era_year)?)
+    }
+}
+
+impl Year {
+    /// The year of the latest era beginning no later than the year, if known.
+    pub fn era(&self) -> Option<EraYear> {
+        era_years(self.ord()).pop()
+    }
+}
+
+#[test]
+fn test_era() {
+    use crate::calendar::Calendar as _;
+
+    let kangxi = Era::from_name("康熙").unwrap();
+    assert_eq!(kangxi.year(1), Some(1662));
+    assert_eq!(kangxi.year(61), Some(1722));
+    assert_eq!(kangxi.year(62), None);
+    assert_eq!(kangxi.year(0), None);
+    assert_eq!(Era::from_name("不存在"), None);
+
+    let year = Calendar::from_era_year("康熙", 61).unwrap();
+    assert_eq!(year.ord(), 1722);
+    assert_eq!(year.era().unwrap().to_string(), "康熙六十一年");
+    assert_eq!(year.era().unwrap().era.dynasty, Some("清"));
+    assert_eq!(
+        Calendar::from_y(1662).unwrap().era().unwrap().to_string(),
+        "康熙元年"
+    );
+    assert_eq!(Calendar::from_y(1300).unwrap().era(), None);
+    assert_eq!(Calendar::from_era_year("令和", 7), None);
+
+    assert_eq!(
+        "康熙三十年".parse::<EraYear>().unwrap().era.year(30),
+        Some(1691)
+    );
+    assert_eq!(
+        "清康熙元年".parse(),
+        Ok(EraYear {
+            era: kangxi,
+            year: 1
+        })
+    );
+    assert_eq!(
+        "康熙61年".parse(),
+        Ok(EraYear {
+            era: kangxi,
+            year: 61
+        })
+    );
+    assert_eq!("康熙六十二年".parse::<EraYear>(), Err(ParseEraYearError));
+    assert_eq!("康熙".parse::<EraYear>(), Err(ParseEraYearError));
+
+    let era_years = era_years(1644);
+    assert_eq!(era_years.len(), 2);
+    assert_eq!(era_years[0].to_string(), "崇祯十七年");
+    assert_eq!(era_years[1].to_string(), "顺治元年");
+}
+
+#[test]
+fn test_minguo() {
+    use crate::calendar::Calendar as _;
+
+    // The Chinese calendar and the Minguo calendar of Taiwan agree on the
+    // years of 民国.
+    for year in [1912, 1949, 2024] {
+        let era_year = Calendar::from_y(year).unwrap().era().unwrap();
+        assert_eq!(era_year, regnal::era_years(Country::Taiwan, year)[0]);
+        assert_eq!(era_year.year, (year - 1911) as u32);
+    }
+    assert_eq!(
+        Calendar::from_y(2024).unwrap().era().unwrap().to_string(),
+        "民国113年"
+    );
+    assert_eq!(Calendar::from_era_year("民国", 1).unwrap().ord(), 1912);
+    assert_eq!(era_years(1911)[0].to_string(), "宣统三年");
+}
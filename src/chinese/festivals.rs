@@ -0,0 +1,153 @@
+//! Traditional Chinese festivals.
+
+use strum::{Display, EnumCount, EnumProperty, EnumString, FromRepr, VariantArray};
+
+use super::{Calendar, Day, BEIJING_TZ};
+use crate::calendar::{Calendar as _, Day as _, Month as _, Year as _};
+use crate::*;
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    EnumCount,
+    VariantArray,
+    Display,
+    EnumString,
+    FromRepr,
+    EnumProperty,
+)]
+pub enum Festival {
+    #[strum(props(zh = "春节"))]
+    SpringFestival,
+    #[strum(props(zh = "元宵节"))]
+    LanternFestival,
+    #[strum(props(zh = "清明节"))]
+    QingmingFestival,
+    #[strum(props(zh = "端午节"))]
+    DragonBoatFestival,
+    #[strum(props(zh = "七夕节"))]
+    QixiFestival,
+    #[strum(props(zh = "中秋节"))]
+    MidAutumnFestival,
+    #[strum(props(zh = "重阳节"))]
+    DoubleNinthFestival,
+    #[strum(props(zh = "腊八节"))]
+    LabaFestival,
+    #[strum(props(zh = "除夕"))]
+    NewYearsEve,
+}
+
+impl Festival {
+    /// The (month, day) of the festival in the Chinese calendar, if it is
+    /// fixed to one.
+    pub fn month_day(&self) -> Option<(u8, u8)> {
+        match self {
+            Festival::SpringFestival => Some((1, 1)),
+            Festival::LanternFestival => Some((1, 15)),
+            Festival::DragonBoatFestival => Some((5, 5)),
+            Festival::QixiFestival => Some((7, 7)),
+            Festival::MidAutumnFestival => Some((8, 15)),
+            Festival::DoubleNinthFestival => Some((9, 9)),
+            Festival::LabaFestival => Some((12, 8)),
+            Festival::QingmingFestival | Festival::NewYearsEve => None,
+        }
+    }
+
+    /// The date of the festival in the given Chinese year.
+    pub fn date(&self, year: i32) -> Option<Date> {
+        match self {
+            Festival::QingmingFestival => {
+                let begin: Date = GregorianCalendar::from_ymd(year, 4, 1)?.into();
+                astronomy::find_solar_terms(begin, begin + 10, BEIJING_TZ)
+                    .into_iter()
+                    .find(|(_, st)| *st == PureBrightness)
+                    .map(|(date, _)| date)
+            }
+            Festival::NewYearsEve => Some(Calendar::from_y(year)?.last_day().into()),
+            _ => {
+                let (month, day) = self.month_day()?;
+                Some(Calendar::from_ylmd(year, false, month, day)?.into())
+            }
+        }
+    }
+
+    pub fn chinese(&self) -> &str {
+        self.get_str("zh").unwrap()
+    }
+}
+
+/// All festivals of the given Chinese year, in chronological order.
+pub fn festivals(year: i32) -> Vec<(Festival, Date)> {
+    let mut festivals: Vec<_> = Festival::VARIANTS
+        .iter()
+        .filter_map(|festival| Some((*festival, festival.date(year)?)))
+        .collect();
+    festivals.sort_by_key(|(_, date)| *date);
+    festivals
+}
+
+impl Day {
+    /// The festival falling on the day, if any.
+    pub fn festival(&self) -> Option<Festival> {
+        if *self == self.the_year().last_day() {
+            return Some(Festival::NewYearsEve);
+        }
+        if !self.the_month().is_leap() {
+            let month_day = (self.the_month().ord_no_leap(), self.ord());
+            if let Some(festival) = Festival::VARIANTS
+                .iter()
+                .find(|festival| festival.month_day() == Some(month_day))
+            {
+                return Some(*festival);
+            }
+        }
+        if Date::from(*self).solar_term(BEIJING_TZ) == Some(PureBrightness) {
+            return Some(Festival::QingmingFestival);
+        }
+        None
+    }
+}
+
+#[test]
+fn test_festivals() {
+    let gregorian = |y, m, d| Date::from(GregorianCalendar::from_ymd(y, m, d).unwrap());
+    assert_eq!(
+        festivals(2024),
+        vec![
+            (Festival::SpringFestival, gregorian(2024, 2, 10)),
+            (Festival::LanternFestival, gregorian(2024, 2, 24)),
+            (Festival::QingmingFestival, gregorian(2024, 4, 4)),
+            (Festival::DragonBoatFestival, gregorian(2024, 6, 10)),
+            (Festival::QixiFestival, gregorian(2024, 8, 10)),
+            (Festival::MidAutumnFestival, gregorian(2024, 9, 17)),
+            (Festival::DoubleNinthFestival, gregorian(2024, 10, 11)),
+            (Festival::LabaFestival, gregorian(2025, 1, 7)),
+            (Festival::NewYearsEve, gregorian(2025, 1, 28)),
+        ]
+    );
+
+    // The Dragon Boat Festival is in the regular 5th month, not the leap one.
+    assert_eq!(
+        Festival::DragonBoatFestival.date(2009),
+        Some(gregorian(2009, 5, 28))
+    );
+
+    let day = Day::try_from(gregorian(2024, 9, 17)).unwrap();
+    assert_eq!(day.festival(), Some(Festival::MidAutumnFestival));
+    assert_eq!(day.festival().unwrap().chinese(), "中秋节");
+    assert_eq!(
+        Day::try_from(gregorian(2024, 4, 4)).unwrap().festival(),
+        Some(Festival::QingmingFestival)
+    );
+    assert_eq!(
+        Day::try_from(gregorian(2025, 1, 28)).unwrap().festival(),
+        Some(Festival::NewYearsEve)
+    );
+    assert_eq!(
+        Day::try_from(gregorian(2024, 9, 18)).unwrap().festival(),
+        None
+    );
+}
@@ -0,0 +1,358 @@
+//! Day attributes of the traditional almanac (黄历).
+
+use strum::{Display, EnumCount, EnumProperty, EnumString, FromRepr, VariantArray};
+
+use super::{Branch, Day, Nayin, Shichen, StemBranch, Year, BEIJING_TZ};
+use crate::*;
+
+/// The twelve day officers (建除十二神).
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    EnumCount,
+    VariantArray,
+    Display,
+    EnumString,
+    FromRepr,
+    EnumProperty,
+)]
+pub enum DayOfficer {
+    #[strum(props(zh = "建"))]
+    Establish,
+    #[strum(props(zh = "除"))]
+    Remove,
+    #[strum(props(zh = "满"))]
+    Full,
+    #[strum(props(zh = "平"))]
+    Balance,
+    #[strum(props(zh = "定"))]
+    Stable,
+    #[strum(props(zh = "执"))]
+    Initiate,
+    #[strum(props(zh = "破"))]
+    Destruction,
+    #[strum(props(zh = "危"))]
+    Danger,
+    #[strum(props(zh = "成"))]
+    Success,
+    #[strum(props(zh = "收"))]
+    Receive,
+    #[strum(props(zh = "开"))]
+    Open,
+    #[strum(props(zh = "闭"))]
+    Close,
+}
+
+impl DayOfficer {
+    /// The officer of a day with the given branch in a month with the given
+    /// branch; the Establish (建) day has the same branch as its month.
+    pub fn from_branches(month_branch: Branch, day_branch: Branch) -> Self {
+        let ord = (day_branch as usize + Branch::COUNT - month_branch as usize) % Branch::COUNT;
+        Self::from_repr(ord).unwrap()
+    }
+
+    pub fn chinese(&self) -> &str {
+        self.get_str("zh").unwrap()
+    }
+}
+
+/// The twelve spirits on duty for the double-hours of a day; six of them mark
+/// auspicious hours (黄道) and the other six inauspicious ones (黑道).
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    EnumCount,
+    VariantArray,
+    Display,
+    EnumString,
+    FromRepr,
+    EnumProperty,
+)]
+pub enum HourSpirit {
+    #[strum(props(zh = "青龙"))]
+    AzureDragon,
+    #[strum(props(zh = "明堂"))]
+    BrightHall,
+    #[strum(props(zh = "天刑"))]
+    HeavenlyPunishment,
+    #[strum(props(zh = "朱雀"))]
+    VermilionBird,
+    #[strum(props(zh = "金匮"))]
+    GoldenCoffer,
+    #[strum(props(zh = "天德"))]
+    HeavenlyVirtue,
+    #[strum(props(zh = "白虎"))]
+    WhiteTiger,
+    #[strum(props(zh = "玉堂"))]
+    JadeHall,
+    #[strum(props(zh = "天牢"))]
+    HeavenlyPrison,
+    #[strum(props(zh = "玄武"))]
+    BlackTortoise,
+    #[strum(props(zh = "司命"))]
+    LifeGovernor,
+    #[strum(props(zh = "勾陈"))]
+    CurvedArray,
+}
+
+impl HourSpirit {
+    /// Whether the spirit marks an auspicious (黄道) hour.
+    pub fn is_auspicious(&self) -> bool {
+        matches!(
+            self,
+            HourSpirit::AzureDragon
+                | HourSpirit::BrightHall
+                | HourSpirit::GoldenCoffer
+                | HourSpirit::HeavenlyVirtue
+                | HourSpirit::JadeHall
+                | HourSpirit::LifeGovernor
+        )
+    }
+
+    /// The spirit on duty for the double-hour with the given branch, on a day
+    /// with the given branch.
+    pub fn from_branches(day_branch: Branch, hour_branch: Branch) -> Self {
+        let azure_dragon = (day_branch as usize % 6 * 2 + 8) % Branch::COUNT;
+        let ord = (hour_branch as usize + Branch::COUNT - azure_dragon) % Branch::COUNT;
+        Self::from_repr(ord).unwrap()
+    }
+
+    pub fn chinese(&self) -> &str {
+        self.get_str("zh").unwrap()
+    }
+}
+
+impl Day {
+    /// The day officer (建除十二神) of the day.
+    pub fn day_officer(&self) -> DayOfficer {
+        DayOfficer::from_branches(
+            Date::from(*self).solar_month(BEIJING_TZ).branch(),
+            self.stem_branch().branch(),
+        )
+    }
+
+    /// The "received sound" (纳音) of the day's stem-branch.
+    pub fn nayin(&self) -> Nayin {
+        self.stem_branch().nayin()
+    }
+
+    /// The branch the day clashes with (冲), whose animal is considered
+    /// unlucky on the day.
+    pub fn clash(&self) -> Branch {
+        self.stem_branch().branch().clash()
+    }
+
+    /// The direction of the baleful influence (煞) of the day.
+    pub fn sha_direction(&self) -> &'static str {
+        ["南", "东", "北", "西"][self.stem_branch().branch() as usize % 4]
+    }
+
+    /// The spirits on duty for the twelve double-hours of the day, starting
+    /// from the Zi (子) double-hour.
+    pub fn hour_spirits(&self) -> [HourSpirit; 12] {
+        let day_branch = self.stem_branch().branch();
+        std::array::from_fn(|i| {
+            HourSpirit::from_branches(day_branch, Branch::from_repr(i).unwrap())
+        })
+    }
+}
+
+impl Shichen {
+    /// The spirit on duty for the double-hour.
+    pub fn spirit(&self) -> HourSpirit {
+        HourSpirit::from_branches(self.day().stem_branch().branch(), self.branch())
+    }
+}
+
+/// The ways a birth year can offend the Grand Duke Jupiter (犯太岁).
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    EnumCount,
+    VariantArray,
+    Display,
+    EnumString,
+    FromRepr,
+    EnumProperty,
+)]
+pub enum TaisuiConflict {
+    /// The birth year has the same branch as the year (值太岁).
+    #[strum(props(zh = "值太岁"))]
+    Duty,
+    #[strum(props(zh = "冲太岁"))]
+    Clash,
+    #[strum(props(zh = "刑太岁"))]
+    Punishment,
+    #[strum(props(zh = "害太岁"))]
+    Harm,
+    #[strum(props(zh = "破太岁"))]
+    Destruction,
+}
+
+impl TaisuiConflict {
+    pub fn chinese(&self) -> &str {
+        self.get_str("zh").unwrap()
+    }
+}
+
+/// The Grand Duke Jupiter (太岁) of a year, which stays at the position of the
+/// year's branch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Taisui {
+    pub stem_branch: StemBranch,
+}
+
+impl Taisui {
+    pub fn branch(&self) -> Branch {
+        self.stem_branch.branch()
+    }
+
+    /// The azimuth of the position in degrees, clockwise from the north.
+    pub fn azimuth(&self) -> u16 {
+        self.branch() as u16 * 30
+    }
+
+    /// The direction of the position, e.g. "东南偏东" for Chen (辰).
+    pub fn direction(&self) -> &'static str {
+        [
+            "正北",
+            "东北偏北",
+            "东北偏东",
+            "正东",
+            "东南偏东",
+            "东南偏南",
+            "正南",
+            "西南偏南",
+            "西南偏西",
+            "正西",
+            "西北偏西",
+            "西北偏北",
+        ][self.branch() as usize]
+    }
+
+    /// The ways in which a birth year with the given branch offends the
+    /// Taisui, which may be none or more than one.
+    pub fn conflicts(&self, birth_branch: Branch) -> Vec<TaisuiConflict> {
+        let branch = self.branch();
+        [
+            (TaisuiConflict::Duty, birth_branch == branch),
+            (TaisuiConflict::Clash, birth_branch.clash() == branch),
+            (
+                TaisuiConflict::Punishment,
+                birth_branch.punishment() == branch || branch.punishment() == birth_branch,
+            ),
+            (TaisuiConflict::Harm, birth_branch.harm() == branch),
+            (
+                TaisuiConflict::Destruction,
+                birth_branch.destruction() == branch,
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(conflict, applies)| applies.then_some(conflict))
+        .collect()
+    }
+
+    /// Whether a birth year with the given branch offends the Taisui.
+    pub fn is_offended_by(&self, birth_branch: Branch) -> bool {
+        !self.conflicts(birth_branch).is_empty()
+    }
+}
+
+impl Year {
+    /// The Taisui (太岁) of the year.
+    pub fn taisui(&self) -> Taisui {
+        Taisui {
+            stem_branch: self.stem_branch(),
+        }
+    }
+}
+
+#[test]
+fn test_taisui() {
+    use crate::calendar::Calendar as _;
+
+    let taisui = super::Calendar::from_y(2024).unwrap().taisui();
+    assert_eq!(taisui.branch(), Branch::Chen);
+    assert_eq!(taisui.azimuth(), 120);
+    assert_eq!(taisui.direction(), "东南偏东");
+    assert_eq!(
+        taisui.conflicts(Branch::Chen),
+        [TaisuiConflict::Duty, TaisuiConflict::Punishment]
+    );
+    assert_eq!(taisui.conflicts(Branch::Xu), [TaisuiConflict::Clash]);
+    assert_eq!(taisui.conflicts(Branch::Mao), [TaisuiConflict::Harm]);
+    assert_eq!(
+        taisui.conflicts(Branch::Chou),
+        [TaisuiConflict::Destruction]
+    );
+    assert!(!taisui.is_offended_by(Branch::Zi));
+    assert_eq!(TaisuiConflict::Clash.chinese(), "冲太岁");
+
+    // 2025 is a Yi-Si (乙巳) year; Yin (寅) and Shen (申) both punish and
+    // harm or destroy Si (巳).
+    let taisui = super::Calendar::from_y(2025).unwrap().taisui();
+    assert_eq!(
+        taisui.conflicts(Branch::Yin),
+        [TaisuiConflict::Punishment, TaisuiConflict::Harm]
+    );
+    assert_eq!(
+        taisui.conflicts(Branch::Shen),
+        [TaisuiConflict::Punishment, TaisuiConflict::Destruction]
+    );
+}
+
+#[test]
+fn test_huangli() {
+    use crate::calendar::{Calendar as _, Day as _};
+
+    // 2024-02-10, a Jia-Chen (甲辰) day in the Yin (寅) month.
+    let day = super::Calendar::from_ymd(2024, 1, 1).unwrap();
+    assert_eq!(day.day_officer(), DayOfficer::Full);
+    assert_eq!(day.day_officer().chinese(), "满");
+    assert_eq!(day.nayin().chinese(), "覆灯火");
+    assert_eq!(day.clash(), Branch::Xu);
+    assert_eq!(day.clash().animal(), "狗");
+    assert_eq!(day.sha_direction(), "南");
+
+    let spirits = day.hour_spirits();
+    assert_eq!(spirits[Branch::Chen as usize], HourSpirit::AzureDragon);
+    assert_eq!(spirits[Branch::Zi as usize], HourSpirit::HeavenlyPrison);
+    assert_eq!(spirits.iter().filter(|s| s.is_auspicious()).count(), 6);
+    assert_eq!(
+        Shichen::new(day, Branch::Mao).spirit(),
+        HourSpirit::CurvedArray
+    );
+
+    // 2000-01-01, a Wu-Wu (戊午) day in the Zi (子) month, as in published
+    // almanacs (万年历).
+    let day = Day::try_from(Date::from(GregorianCalendar::from_ymd(2000, 1, 1).unwrap())).unwrap();
+    assert_eq!(day.day_officer(), DayOfficer::Destruction);
+    assert_eq!(day.nayin().chinese(), "天上火");
+    assert_eq!(day.clash(), Branch::Zi);
+
+    // The day of a sectional term repeats the officer of the day before.
+    let beginning_of_spring =
+        Day::try_from(Date::from(GregorianCalendar::from_ymd(2024, 2, 4).unwrap())).unwrap();
+    assert_eq!(
+        beginning_of_spring.day_officer(),
+        beginning_of_spring.pred().day_officer()
+    );
+
+    assert_eq!(
+        DayOfficer::from_branches(Branch::Yin, Branch::Yin),
+        DayOfficer::Establish
+    );
+    assert_eq!(
+        DayOfficer::from_branches(Branch::Yin, Branch::Chou),
+        DayOfficer::Close
+    );
+}
@@ -0,0 +1,170 @@
+//! Parsing of Chinese dates written in Chinese, e.g. "二〇二四年正月初一",
+//! "2024年正月初一" or "甲辰年八月十五".
+
+use std::str::FromStr;
+
+use strum::VariantArray;
+
+use super::{Branch, Calendar, Day, Stem, StemBranch, Year, BEIJING_TZ};
+use crate::calendar::{Day as _, Year as _};
+use crate::numeral::parse_numeral;
+use crate::*;
+
+/// The error returned when a string is not a valid Chinese date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseDayError;
+
+impl std::fmt::Display for ParseDayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid Chinese date")
+    }
+}
+
+impl std::error::Error for ParseDayError {}
+
+fn parse_stem_branch(s: &str) -> Option<StemBranch> {
+    let mut chars = s.chars();
+    let (s, b) = (chars.next()?, chars.next()?);
+    if chars.next().is_some() {
+        return None;
+    }
+    let stem = Stem::VARIANTS
+        .iter()
+        .find(|stem| stem.chinese().starts_with(s))?;
+    let branch = Branch::VARIANTS
+        .iter()
+        .find(|branch| branch.chinese().starts_with(b))?;
+    StemBranch::from_stem_branch(*stem, *branch)
+}
+
+fn parse_year(s: &str, pivot: i32) -> Option<i32> {
+    match parse_stem_branch(s) {
+        Some(stem_branch) => Some(Year::from_stem_branch_near(stem_branch, pivot)?.ord()),
+        None => parse_numeral(s)?.try_into().ok(),
+    }
+}
+
+fn parse_month(s: &str) -> Option<u8> {
+    let m = match s {
+        "正" | "端" => 1,
+        "冬" => 11,
+        "腊" => 12,
+        _ => parse_numeral(s)?,
+    };
+    (1..=12).contains(&m).then_some(m as u8)
+}
+
+fn parse_day(s: &str) -> Option<u8> {
+    let s = s.strip_suffix('日').unwrap_or(s);
+    let s = s.strip_prefix('初').unwrap_or(s);
+    let d = parse_numeral(s)?;
+    (1..=30).contains(&d).then_some(d as u8)
+}
+
+/// Parses a Chinese month, leap or not, and day without a year, e.g.
+/// "八月十五" or "闰四月初一", into whether the month is leap, the month and
+/// the day.
+pub fn parse_month_day(s: &str) -> Result<(bool, u8, u8), ParseDayError> {
+    let s = s.trim();
+    let (leap, s) = match s.strip_prefix('闰') {
+        Some(s) => (true, s),
+        None => (false, s),
+    };
+    let (month, day) = s.split_once('月').ok_or(ParseDayError)?;
+    let month = parse_month(month).ok_or(ParseDayError)?;
+    let day = parse_day(day).ok_or(ParseDayError)?;
+    Ok((leap, month, day))
+}
+
+impl Day {
+    /// Parses a Chinese date, resolving a stem-branch year to the one nearest
+    /// to the given pivot year.
+    ///
+    /// Both the plain and the alternate forms of [`Day`]'s `Display` output
+    /// are accepted.
+    pub fn parse_with_pivot(s: &str, pivot: i32) -> Result<Self, ParseDayError> {
+        let s = s.trim();
+        let (year, rest) = match s.strip_prefix("公元") {
+            Some(s) => {
+                let (year, rest) = s.split_once('年').ok_or(ParseDayError)?;
+                let rest = rest.strip_prefix("农历").unwrap_or(rest);
+                // Skip the redundant stem-branch year, if any.
+                let rest = match rest.split_once('年') {
+                    Some((sb, rest)) if parse_stem_branch(sb).is_some() => rest,
+                    _ => rest,
+                };
+                (year, rest)
+            }
+            None => s
+                .split_once('年')
+                .or_else(|| {
+                    // A year in Arabic numerals may be followed by the month
+                    // directly, e.g. "2023闰2月5日".
+                    let len = s.find(|c: char| !c.is_ascii_digit())?;
+                    (len > 0).then(|| s.split_at(len))
+                })
+                .ok_or(ParseDayError)?,
+        };
+        let year = parse_year(year, pivot).ok_or(ParseDayError)?;
+        let (leap, month, day) = parse_month_day(rest)?;
+        Calendar::from_ylmd(year, leap, month, day).ok_or(ParseDayError)
+    }
+}
+
+impl FromStr for Day {
+    type Err = ParseDayError;
+
+    /// Parses a Chinese date, resolving a stem-branch year to the one nearest
+    /// to the current year.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let today = GregorianDay::from(Date::from_unix_time_with_tz(unix_time_now(), BEIJING_TZ));
+        Self::parse_with_pivot(s, today.the_year().ord())
+    }
+}
+
+#[test]
+fn test_parse() {
+    use crate::calendar::Calendar as _;
+
+    let new_year = Calendar::from_ymd(2024, 1, 1).unwrap();
+    assert_eq!("2024年正月初一".parse(), Ok(new_year));
+    assert_eq!("二〇二四年正月初一".parse(), Ok(new_year));
+    assert_eq!("二〇二四年一月一日".parse(), Ok(new_year));
+    assert_eq!(new_year.to_string().parse(), Ok(new_year));
+    assert_eq!(format!("{:#}", new_year).parse(), Ok(new_year));
+
+    assert_eq!(
+        Day::parse_with_pivot("二〇二三年闰二月初五", 2000),
+        Ok(Calendar::from_ylmd(2023, true, 2, 5).unwrap())
+    );
+    assert_eq!(
+        Day::parse_with_pivot("二〇二四年闰二月初五", 2000),
+        Err(ParseDayError)
+    );
+    assert_eq!(
+        Day::parse_with_pivot("甲辰年八月十五", 1990),
+        Ok(Calendar::from_ymd(1964, 8, 15).unwrap())
+    );
+    assert_eq!(
+        Day::parse_with_pivot("甲辰年八月十五", 2010),
+        Ok(Calendar::from_ymd(2024, 8, 15).unwrap())
+    );
+    assert_eq!(
+        Day::parse_with_pivot("甲辰年腊月廿九", 2024),
+        Ok(Calendar::from_ymd(2024, 12, 29).unwrap())
+    );
+    assert_eq!(
+        Day::parse_with_pivot("甲子年十三月初一", 2024),
+        Err(ParseDayError)
+    );
+    assert_eq!(
+        Day::parse_with_pivot("2023闰2月5日", 2000),
+        Ok(Calendar::from_ylmd(2023, true, 2, 5).unwrap())
+    );
+    assert_eq!(Day::parse_with_pivot("正月初一", 2024), Err(ParseDayError));
+
+    assert_eq!(parse_month_day("八月十五"), Ok((false, 8, 15)));
+    assert_eq!(parse_month_day("闰四月初一"), Ok((true, 4, 1)));
+    assert_eq!(parse_month_day("腊月初八日"), Ok((false, 12, 8)));
+    assert_eq!(parse_month_day("十三月初一"), Err(ParseDayError));
+}
@@ -0,0 +1,189 @@
+//! Seasonal periods counted from solar terms: Shujiu (数九) and Sanfu (三伏).
+
+use strum::{Display, EnumCount, EnumProperty, EnumString, FromRepr, VariantArray};
+
+use super::{Stem, BEIJING_TZ};
+use crate::astronomy::find_solar_terms;
+use crate::*;
+
+/// A labeled range of days, both ends inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Period<T> {
+    pub label: T,
+    pub first_day: Date,
+    pub last_day: Date,
+}
+
+impl<T> Period<T> {
+    pub fn num_days(&self) -> u8 {
+        (self.last_day - self.first_day + 1) as u8
+    }
+
+    pub fn contains(&self, date: Date) -> bool {
+        self.first_day <= date && date <= self.last_day
+    }
+}
+
+/// The nine nines (数九) of winter.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    EnumCount,
+    VariantArray,
+    Display,
+    EnumString,
+    FromRepr,
+    EnumProperty,
+)]
+#[repr(u8)]
+pub enum Jiu {
+    #[strum(props(zh = "一九"))]
+    First = 1,
+    #[strum(props(zh = "二九"))]
+    Second,
+    #[strum(props(zh = "三九"))]
+    Third,
+    #[strum(props(zh = "四九"))]
+    Fourth,
+    #[strum(props(zh = "五九"))]
+    Fifth,
+    #[strum(props(zh = "六九"))]
+    Sixth,
+    #[strum(props(zh = "七九"))]
+    Seventh,
+    #[strum(props(zh = "八九"))]
+    Eighth,
+    #[strum(props(zh = "九九"))]
+    Ninth,
+}
+
+impl Jiu {
+    pub fn ord(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn from_ord(ord: u8) -> Option<Self> {
+        Self::from_repr(ord)
+    }
+
+    pub fn chinese(&self) -> &'static str {
+        self.get_str("zh").unwrap()
+    }
+}
+
+/// The three fu (三伏) of summer.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    EnumCount,
+    VariantArray,
+    Display,
+    EnumString,
+    FromRepr,
+    EnumProperty,
+)]
+#[repr(u8)]
+pub enum Fu {
+    #[strum(props(zh = "初伏"))]
+    Initial = 1,
+    #[strum(props(zh = "中伏"))]
+    Middle,
+    #[strum(props(zh = "末伏"))]
+    Last,
+}
+
+impl Fu {
+    pub fn ord(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn from_ord(ord: u8) -> Option<Self> {
+        Self::from_repr(ord)
+    }
+
+    pub fn chinese(&self) -> &'static str {
+        self.get_str("zh").unwrap()
+    }
+}
+
+/// The nine nines starting from the winter solstice of the given Gregorian
+/// year, each lasting nine days.
+pub fn shujiu(year: i32) -> [Period<Jiu>; 9] {
+    let ws = lunisolar::winter_solstice(year, &lunisolar::Rules::CHINESE);
+    std::array::from_fn(|i| {
+        let first_day = ws + i as i32 * 9;
+        Period {
+            label: Jiu::VARIANTS[i],
+            first_day,
+            last_day: first_day + 8,
+        }
+    })
+}
+
+/// The three fu of the summer of the given Gregorian year.
+///
+/// The initial fu starts on the third Geng (庚) day counting from the summer
+/// solstice, the middle fu on the fourth, and the last fu on the first Geng
+/// day from the beginning of autumn.  The initial and last fu last ten days,
+/// and the middle fu lasts until the last one begins.
+pub fn sanfu(year: i32) -> [Period<Fu>; 3] {
+    let begin = Date::from(GregorianCalendar::from_ymd(year, 6, 1).unwrap());
+    let end = Date::from(GregorianCalendar::from_ymd(year, 9, 1).unwrap());
+    let terms = find_solar_terms(begin, end, BEIJING_TZ);
+    let term_day = |term| terms.iter().find(|&&(_, st)| st == term).unwrap().0;
+    let next_geng_day = |date: Date| {
+        let offset = Stem::Geng.ord() as i32 - StemBranch::from_date(date).stem().ord() as i32;
+        date + offset.rem_euclid(10)
+    };
+    let initial = next_geng_day(term_day(SummerSolstice)) + 20;
+    let middle = initial + 10;
+    let last = next_geng_day(term_day(BeginningOfAutumn));
+    [
+        Period {
+            label: Fu::Initial,
+            first_day: initial,
+            last_day: middle.pred(),
+        },
+        Period {
+            label: Fu::Middle,
+            first_day: middle,
+            last_day: last.pred(),
+        },
+        Period {
+            label: Fu::Last,
+            first_day: last,
+            last_day: last + 9,
+        },
+    ]
+}
+
+#[test]
+fn test_periods() {
+    let ymd = |y, m, d| Date::from(GregorianCalendar::from_ymd(y, m, d).unwrap());
+
+    let jiu = shujiu(2023);
+    assert_eq!(jiu[0].label.chinese(), "一九");
+    assert_eq!(jiu[0].first_day, ymd(2023, 12, 22));
+    assert_eq!(jiu[2].first_day, ymd(2024, 1, 9));
+    assert_eq!(jiu[8].last_day, ymd(2024, 3, 11));
+    assert!(jiu.iter().all(|p| p.num_days() == 9));
+
+    let fu = sanfu(2024);
+    assert_eq!(fu[0].first_day, ymd(2024, 7, 15));
+    assert_eq!(fu[1].first_day, ymd(2024, 7, 25));
+    assert_eq!(fu[1].num_days(), 20);
+    assert_eq!(fu[2].label.chinese(), "末伏");
+    assert_eq!(fu[2].first_day, ymd(2024, 8, 14));
+    assert_eq!(fu[2].last_day, ymd(2024, 8, 23));
+    assert!(fu[2].contains(ymd(2024, 8, 20)));
+
+    let fu = sanfu(2023);
+    assert_eq!(fu[0].first_day, ymd(2023, 7, 11));
+    assert_eq!(fu[1].num_days(), 20);
+}
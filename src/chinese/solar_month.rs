@@ -0,0 +1,174 @@
+//! Solar-term months (节气月), which run from one sectional term (节) to the
+//! next, as used in the Four Pillars (八字) and in agriculture.
+
+use strum::EnumCount;
+
+use super::{first_month_stem_branch, Branch, Stem, StemBranch};
+use crate::calendar::{Calendar as _, Day as _, Month as _, Year as _};
+use crate::*;
+
+/// A month from the day of a sectional term to the day before the next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SolarMonth {
+    year: i32,
+    branch: Branch,
+    first_day: Date,
+    next_first_day: Date,
+}
+
+/// The mean motion of the sun in ecliptic longitude, in degrees per day.
+const MEAN_SUN_MOTION: f64 = 360.0 / 365.2422;
+
+/// The day of the given solar term, searching from a day near it.
+fn term_day(term: SolarTerm, near: Date, tz: f64) -> Date {
+    // Whether the sun has passed the term by the end of the day.
+    let passed = |date: Date| {
+        let offset = (astronomy::get_sun_ecl_long(date.succ().midnight_jd(tz)) - term.degrees())
+            .rem_euclid(360.0);
+        offset > 0.0 && offset < 180.0
+    };
+    let mut date = near;
+    while passed(date.pred()) {
+        date = date.pred();
+    }
+    while !passed(date) {
+        date = date.succ();
+    }
+    date
+}
+
+impl SolarMonth {
+    /// The solar month with the given branch in the year beginning with the
+    /// Beginning of Spring (立春) of the given Gregorian year.
+    pub fn from_year_branch(year: i32, branch: Branch, tz: f64) -> Self {
+        let months = (branch as i32 - Branch::Yin as i32).rem_euclid(12);
+        // Around the middle of the month.
+        let date = Date::from(GregorianCalendar::from_ymd(year, 2, 19).unwrap())
+            + (months as f64 * 30.44).round() as i32;
+        Self::from_date(date, tz)
+    }
+
+    /// The solar month containing the given date.
+    ///
+    /// The month follows from the longitude of the sun at the end of the day,
+    /// and only the days around its sectional terms are searched for.
+    pub fn from_date(date: Date, tz: f64) -> Self {
+        let sun_ecl_long = astronomy::get_sun_ecl_long(date.succ().midnight_jd(tz));
+        let degrees = (sun_ecl_long - BeginningOfSpring.degrees()).rem_euclid(360.0);
+        let months = (-(-degrees).div_euclid(30.0) as usize + 11) % 12;
+        let term =
+            SolarTerm::from_repr((BeginningOfSpring as usize + months * 2) % SolarTerm::COUNT)
+                .unwrap();
+        let days = (sun_ecl_long - term.degrees()).rem_euclid(360.0) / MEAN_SUN_MOTION;
+        let first_day = term_day(term, date + -(days as i32), tz);
+        let next_first_day = term_day(term.succ().succ(), first_day + 30, tz);
+        let gregorian_day = GregorianDay::from(first_day);
+        let year = if months >= 10 && gregorian_day.the_month().ord() <= 2 {
+            gregorian_day.the_year().ord() - 1
+        } else {
+            gregorian_day.the_year().ord()
+        };
+        Self {
+            year,
+            branch: Branch::from_repr((months + Branch::Yin as usize) % Branch::COUNT).unwrap(),
+            first_day,
+            next_first_day,
+        }
+    }
+
+    /// The Gregorian year in which the year of the month begins with the
+    /// Beginning of Spring.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// The branch of the month, with the month beginning with the Beginning of
+    /// Spring being a Yin (寅) month.
+    pub fn branch(&self) -> Branch {
+        self.branch
+    }
+
+    /// The stem-branch of the month, i.e. the month pillar of Four Pillars.
+    pub fn stem_branch(&self) -> StemBranch {
+        let months = (self.branch as i32 - Branch::Yin as i32).rem_euclid(12);
+        first_month_stem_branch(Stem::from_year(self.year)).add(months)
+    }
+
+    /// The sectional term beginning the month.
+    pub fn sectional_term(&self) -> SolarTerm {
+        let months = (self.branch as usize + Branch::COUNT - Branch::Yin as usize) % Branch::COUNT;
+        SolarTerm::from_repr((BeginningOfSpring as usize + months * 2) % SolarTerm::COUNT).unwrap()
+    }
+
+    /// The day of the sectional term beginning the month.
+    pub fn first_day(&self) -> Date {
+        self.first_day
+    }
+
+    /// The day before the sectional term ending the month.
+    pub fn last_day(&self) -> Date {
+        self.next_first_day.pred()
+    }
+
+    pub fn num_days(&self) -> usize {
+        (self.next_first_day - self.first_day) as usize
+    }
+
+    pub fn contains(&self, date: Date) -> bool {
+        (self.first_day..self.next_first_day).contains(&date)
+    }
+}
+
+#[test]
+fn test_solar_month() {
+    use strum::VariantArray;
+
+    let ymd = |y, m, d| Date::from(GregorianCalendar::from_ymd(y, m, d).unwrap());
+
+    // The Beginning of Spring of 2024 fell on February 4.
+    let month = SolarMonth::from_date(ymd(2024, 2, 10), 8.0);
+    assert_eq!(month.year(), 2024);
+    assert_eq!(month.branch(), Branch::Yin);
+    assert_eq!(
+        month.stem_branch(),
+        StemBranch::from_stem_branch(Stem::Bing, Branch::Yin).unwrap()
+    );
+    assert_eq!(month.sectional_term(), BeginningOfSpring);
+    assert_eq!(month.first_day(), ymd(2024, 2, 4));
+    assert_eq!(month.last_day(), ymd(2024, 3, 4));
+    assert_eq!(month.num_days(), 30);
+    assert!(month.contains(ymd(2024, 3, 4)));
+    assert!(!month.contains(ymd(2024, 3, 5)));
+    assert_eq!(SolarMonth::from_year_branch(2024, Branch::Yin, 8.0), month);
+
+    let month = SolarMonth::from_date(ymd(2024, 2, 3), 8.0);
+    assert_eq!(month.year(), 2023);
+    assert_eq!(month.branch(), Branch::Chou);
+    assert_eq!(month.sectional_term(), MinorCold);
+    assert_eq!(SolarMonth::from_year_branch(2023, Branch::Chou, 8.0), month);
+
+    for branch in Branch::VARIANTS {
+        let month = SolarMonth::from_year_branch(2000, *branch, 8.0);
+        assert_eq!(month.year(), 2000);
+        assert_eq!(month.branch(), *branch);
+        assert_eq!(
+            month.first_day().solar_term(8.0),
+            Some(month.sectional_term())
+        );
+    }
+
+    let mut date = ymd(2023, 12, 1);
+    while date < ymd(2025, 2, 1) {
+        let month = SolarMonth::from_date(date, 8.0);
+        assert!(month.contains(date), "{:?}", date);
+        assert_eq!(
+            month.first_day().solar_term(8.0),
+            Some(month.sectional_term())
+        );
+        assert_eq!(
+            month.last_day().succ().solar_term(8.0),
+            Some(month.sectional_term().succ().succ())
+        );
+        date = date.succ();
+    }
+}
@@ -0,0 +1,33 @@
+//! Precomputed Chinese calendar data for 1900–2100, read from
+//! [`tables::CHINESE_YEARS`], which the build script generates for the
+//! `gen-tables` feature.
+//!
+//! The table is computed in [`Mode::Deterministic`](super::Mode::Deterministic),
+//! so it serves only that mode, and is checked against it in tests.
+
+use super::ChineseYearData;
+use crate::*;
+
+/// Looks up the data of a Chinese year, if it is in the table.
+pub fn lookup(year: i32) -> Option<ChineseYearData> {
+    let data = tables::chinese_year(year)?;
+    let mut num_days_of_months = [0; 13];
+    for (i, num_days) in num_days_of_months.iter_mut().enumerate() {
+        *num_days = data.num_days_of_month(i).unwrap_or(0);
+    }
+    Some((data.first_day, num_days_of_months, data.leap_month))
+}
+
+#[test]
+fn test_lookup() {
+    use super::Mode;
+
+    assert_eq!(lookup(tables::FIRST_YEAR - 1), None);
+    assert_eq!(lookup(tables::LAST_YEAR + 1), None);
+    for year in tables::FIRST_YEAR..=tables::LAST_YEAR {
+        assert_eq!(
+            lookup(year),
+            Some(super::calc_chinese_year_data(year, Mode::Deterministic))
+        );
+    }
+}
@@ -0,0 +1,185 @@
+//! Compact string codes of days, uniform across calendars, e.g.
+//! "G:2024-02-10" for a Gregorian day or "C:2023-02L-05" for a Chinese day in
+//! a leap month.
+//!
+//! The codes of the days of a calendar sort as strings in the order of the
+//! days, so they can be used as keys of databases and caches. A code starts
+//! with the letter of its calendar and a colon, followed by the year, the
+//! month and the day. Years from 0 to 9999 are written with four digits;
+//! years after are written as "~" and seven digits, and years before as "!"
+//! and seven digits of the year plus 10,000,000, e.g. "!9999956" for the year
+//! -44, so that they sort before and after the four-digit years.
+
+use crate::calendar::{Calendar as _, Day as _, Month as _, Year as _};
+use crate::*;
+
+/// The error returned when a string is not a valid code of a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseCodeError;
+
+impl std::fmt::Display for ParseCodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid code of a day")
+    }
+}
+
+impl std::error::Error for ParseCodeError {}
+
+/// The width of the digits of a year outside 0..=9999.
+const WIDE_YEAR_DIGITS: usize = 7;
+const WIDE_YEAR_OFFSET: i32 = 10_000_000;
+
+fn format_year(year: i32) -> String {
+    match year {
+        0..=9999 => format!("{:04}", year),
+        10000.. => format!("~{:07}", year),
+        _ => format!("!{:07}", year + WIDE_YEAR_OFFSET),
+    }
+}
+
+fn digits(s: &str, len: usize) -> Option<u32> {
+    if s.len() == len && s.bytes().all(|b| b.is_ascii_digit()) {
+        s.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Splits the year off the rest of the string, which begins with '-'.
+fn parse_year(s: &str) -> Option<(i32, &str)> {
+    if let Some(s) = s.strip_prefix('~') {
+        let (year, rest) = s.split_at_checked(WIDE_YEAR_DIGITS)?;
+        let year = digits(year, WIDE_YEAR_DIGITS)? as i32;
+        return (year >= 10000).then_some((year, rest));
+    }
+    if let Some(s) = s.strip_prefix('!') {
+        let (year, rest) = s.split_at_checked(WIDE_YEAR_DIGITS)?;
+        let year = digits(year, WIDE_YEAR_DIGITS)? as i32 - WIDE_YEAR_OFFSET;
+        return (year < 0).then_some((year, rest));
+    }
+    let (year, rest) = s.split_at_checked(4)?;
+    Some((digits(year, 4)? as i32, rest))
+}
+
+impl GregorianDay {
+    /// The code of the day, e.g. "G:2024-02-10".
+    pub fn to_code(&self) -> String {
+        format!(
+            "G:{}-{:02}-{:02}",
+            format_year(self.the_year().ord()),
+            self.the_month().ord(),
+            self.ord()
+        )
+    }
+
+    pub fn from_code(s: &str) -> Result<Self, ParseCodeError> {
+        let parse = || {
+            let (year, rest) = parse_year(s.strip_prefix("G:")?)?;
+            let (month, day) = rest.strip_prefix('-')?.split_once('-')?;
+            GregorianCalendar::from_ymd(year, digits(month, 2)? as u8, digits(day, 2)? as u8)
+        };
+        parse().ok_or(ParseCodeError)
+    }
+}
+
+#[cfg(feature = "astronomy")]
+impl ChineseDay {
+    /// The code of the day, e.g. "C:2024-01-01", or "C:2023-02L-05" for a day
+    /// in the leap month after the second month.
+    pub fn to_code(&self) -> String {
+        let month = self.the_month();
+        format!(
+            "C:{}-{:02}{}-{:02}",
+            format_year(self.the_year().ord()),
+            month.ord_no_leap(),
+            if month.is_leap() { "L" } else { "" },
+            self.ord()
+        )
+    }
+
+    pub fn from_code(s: &str) -> Result<Self, ParseCodeError> {
+        let parse = || {
+            let (year, rest) = parse_year(s.strip_prefix("C:")?)?;
+            let (month, day) = rest.strip_prefix('-')?.split_once('-')?;
+            let (month, leap) = match month.strip_suffix('L') {
+                Some(month) => (month, true),
+                None => (month, false),
+            };
+            ChineseCalendar::try_from_y(year).ok()?;
+            let (month, day) = (digits(month, 2)? as u8, digits(day, 2)? as u8);
+            ChineseCalendar::from_ylmd(year, leap, month, day)
+        };
+        parse().ok_or(ParseCodeError)
+    }
+}
+
+/// Parses the code of a day of any calendar.
+pub fn parse(s: &str) -> Result<Date, ParseCodeError> {
+    match s.split_once(':') {
+        Some(("G", _)) => GregorianDay::from_code(s).map(Date::from),
+        #[cfg(feature = "astronomy")]
+        Some(("C", _)) => ChineseDay::from_code(s).map(Date::from),
+        _ => Err(ParseCodeError),
+    }
+}
+
+#[test]
+fn test_code() {
+    let day = GregorianCalendar::from_ymd(2024, 2, 10).unwrap();
+    assert_eq!(day.to_code(), "G:2024-02-10");
+    assert_eq!(GregorianDay::from_code("G:2024-02-10"), Ok(day));
+    for s in [
+        "G:2024-2-10",
+        "G:2024-02-30",
+        "G:~0009999-01-01",
+        "G:!0000000-01-01",
+        "X:2024-02-10",
+        "2024-02-10",
+    ] {
+        assert_eq!(parse(s), Err(ParseCodeError), "{}", s);
+    }
+
+    for (year, code) in [
+        (-5_000_000, "G:!5000000-01-01"),
+        (-44, "G:!9999956-01-01"),
+        (-1, "G:!9999999-01-01"),
+        (0, "G:0000-01-01"),
+        (9999, "G:9999-01-01"),
+        (10000, "G:~0010000-01-01"),
+    ] {
+        let day = GregorianCalendar::from_ymd(year, 1, 1).unwrap();
+        assert_eq!(day.to_code(), code);
+        assert_eq!(GregorianDay::from_code(code), Ok(day));
+    }
+
+    let mut dates: Vec<_> = testing::Dates::new(1, -20000..=20000).take(1000).collect();
+    let mut codes: Vec<_> = dates
+        .iter()
+        .map(|&date| GregorianDay::from(date).to_code())
+        .collect();
+    dates.sort();
+    codes.sort();
+    assert_eq!(
+        codes,
+        dates
+            .iter()
+            .map(|&date| GregorianDay::from(date).to_code())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[cfg(feature = "astronomy")]
+#[test]
+fn test_chinese_code() {
+    let day = ChineseCalendar::from_ylmd(2023, true, 2, 5).unwrap();
+    assert_eq!(day.to_code(), "C:2023-02L-05");
+    assert_eq!(ChineseDay::from_code("C:2023-02L-05"), Ok(day));
+    assert_eq!(parse("C:2023-02L-05"), Ok(day.into()));
+    for s in ["C:2024-02L-05", "C:9999-01-01"] {
+        assert_eq!(parse(s), Err(ParseCodeError), "{}", s);
+    }
+
+    let year = ChineseCalendar::from_y(2023).unwrap();
+    let codes: Vec<_> = year.days().map(|day| day.to_code()).collect();
+    assert!(codes.is_sorted());
+}
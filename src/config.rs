@@ -0,0 +1,320 @@
+//! The configuration file of the CLI, `~/.config/omnical/config.toml`, holding
+//! the defaults of its options, e.g.
+//!
+//! ```toml
+//! tz = "Asia/Shanghai"
+//! locale = "zh-Hant"
+//! first_weekday = "sun"
+//! columns = ["date", "chinese", "weekday", "solar-term"]
+//! region = "cn"
+//! ```
+//!
+//! Only the subset of TOML needed for that and the events file is supported:
+//! one `key = value` or `[[table]]` per line, with strings, numbers and arrays
+//! of strings as values.
+
+use std::path::PathBuf;
+
+use clap::ValueEnum;
+
+use omnical::*;
+
+use super::{parse_tz, Cli, Column, Commands, FirstWeekday, OptionArgs};
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Config {
+    tz: Option<f64>,
+    locale: Option<Locale>,
+    first_weekday: Option<FirstWeekday>,
+    columns: Vec<Column>,
+    region: Option<holiday::Region>,
+}
+
+#[derive(Debug, PartialEq)]
+pub(super) enum Value {
+    String(String),
+    Number(f64),
+    Array(Vec<String>),
+}
+
+/// A line of TOML other than a blank line or a comment.
+pub(super) enum Line<'a> {
+    /// The header of a table in an array of tables, e.g. `[[event]]`.
+    Table(&'a str),
+    Pair(&'a str, Value),
+}
+
+/// The path of the configuration file, under `$XDG_CONFIG_HOME` or
+/// `~/.config`.
+fn path() -> Option<PathBuf> {
+    let dir = match std::env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(std::env::var_os("HOME")?).join(".config"),
+    };
+    Some(dir.join("omnical").join("config.toml"))
+}
+
+fn parse_string(s: &str) -> Option<(String, &str)> {
+    let mut chars = s.strip_prefix('"')?.char_indices();
+    let mut string = String::new();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((string, &s[i + 2..])),
+            '\\' => string.push(match chars.next()?.1 {
+                'n' => '\n',
+                't' => '\t',
+                c @ ('"' | '\\') => c,
+                _ => return None,
+            }),
+            c => string.push(c),
+        }
+    }
+    None
+}
+
+/// Parses a value, returning it with the rest of the line.
+fn parse_value(s: &str) -> Result<(Value, &str), String> {
+    if s.starts_with('"') {
+        let (string, rest) = parse_string(s).ok_or("invalid string")?;
+        return Ok((Value::String(string), rest));
+    }
+    if let Some(mut rest) = s.strip_prefix('[') {
+        let mut strings = Vec::new();
+        loop {
+            rest = rest.trim_start();
+            if let Some(rest) = rest.strip_prefix(']') {
+                return Ok((Value::Array(strings), rest));
+            }
+            let (string, after) = parse_string(rest).ok_or("expected a string in the array")?;
+            strings.push(string);
+            rest = after.trim_start();
+            rest = match rest.strip_prefix(',') {
+                Some(rest) => rest,
+                None if rest.starts_with(']') => rest,
+                None => return Err("expected ',' or ']' in the array".to_string()),
+            };
+        }
+    }
+    let end = s
+        .find(|c: char| c.is_whitespace() || c == '#')
+        .unwrap_or(s.len());
+    let number = s[..end].replace('_', "");
+    match number.parse() {
+        Ok(number) => Ok((Value::Number(number), &s[end..])),
+        Err(_) => Err(format!("invalid value: {}", &s[..end])),
+    }
+}
+
+/// Parses TOML, calling `f` with the number and the content of each line but
+/// the blank lines and comments, and reporting an error as "line: message".
+pub(super) fn parse_lines(
+    text: &str,
+    mut f: impl FnMut(usize, Line) -> Result<(), String>,
+) -> Result<(), String> {
+    for (i, line) in text.lines().enumerate() {
+        let error = |e: String| format!("{}: {}", i + 1, e);
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some(table) = line
+            .strip_prefix("[[")
+            .and_then(|line| line.strip_suffix("]]"))
+        {
+            f(i + 1, Line::Table(table.trim())).map_err(error)?;
+            continue;
+        }
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| error("expected `key = value`".to_string()))?;
+        let (value, rest) = parse_value(value.trim()).map_err(error)?;
+        let rest = rest.trim();
+        if !(rest.is_empty() || rest.starts_with('#')) {
+            return Err(error(format!("unexpected text: {}", rest)));
+        }
+        f(i + 1, Line::Pair(key.trim(), value)).map_err(error)?;
+    }
+    Ok(())
+}
+
+fn parse_enum<T: ValueEnum>(key: &str, value: &str) -> Result<T, String> {
+    T::from_str(value, true).map_err(|_| format!("invalid {}: {}", key, value))
+}
+
+impl Config {
+    /// Loads the configuration file, or the default configuration if there is
+    /// none.
+    pub fn load() -> Result<Self, String> {
+        let Some(path) = path() else {
+            return Ok(Self::default());
+        };
+        match std::fs::read_to_string(&path) {
+            Ok(text) => Self::parse(&text).map_err(|e| format!("{}:{}", path.display(), e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {}", path.display(), e)),
+        }
+    }
+
+    /// Parses a configuration, reporting an error as "line: message".
+    fn parse(text: &str) -> Result<Self, String> {
+        let mut config = Self::default();
+        parse_lines(text, |_, line| match line {
+            Line::Table(table) => Err(format!("unknown table: {}", table)),
+            Line::Pair(key, value) => config.set(key, value),
+        })?;
+        Ok(config)
+    }
+
+    fn set(&mut self, key: &str, value: Value) -> Result<(), String> {
+        match (key, value) {
+            ("tz", Value::Number(offset)) => self.tz = Some(parse_tz(&offset.to_string())?),
+            ("tz", Value::String(tz)) => self.tz = Some(parse_tz(&tz)?),
+            ("locale", Value::String(locale)) => {
+                self.locale = Some(
+                    locale
+                        .parse()
+                        .ok()
+                        .filter(|locale| {
+                            matches!(
+                                locale,
+                                Locale::English
+                                    | Locale::SimplifiedChinese
+                                    | Locale::TraditionalChinese
+                            )
+                        })
+                        .ok_or_else(|| format!("unsupported locale: {}", locale))?,
+                )
+            }
+            ("first_weekday", Value::String(weekday)) => {
+                self.first_weekday = Some(parse_enum(key, &weekday)?)
+            }
+            ("columns", Value::Array(columns)) => {
+                self.columns = columns
+                    .iter()
+                    .map(|column| parse_enum(key, column))
+                    .collect::<Result<_, _>>()?
+            }
+            ("region", Value::String(region)) => {
+                self.region = Some(
+                    region
+                        .parse()
+                        .map_err(|_| format!("invalid region: {}", region))?,
+                )
+            }
+            ("tz" | "locale" | "first_weekday" | "columns" | "region", _) => {
+                return Err(format!("invalid type of {}", key))
+            }
+            _ => return Err(format!("unknown key: {}", key)),
+        }
+        Ok(())
+    }
+
+    fn apply_locale(&self, option: &mut OptionArgs) {
+        if option.english || option.traditional {
+            return;
+        }
+        match self.locale {
+            Some(Locale::English) => option.english = true,
+            Some(Locale::TraditionalChinese) => option.traditional = true,
+            _ => {}
+        }
+    }
+
+    /// Fills in the options not given on the command line.
+    pub fn apply(&self, cli: &mut Cli) {
+        cli.tz = cli.tz.or(self.tz);
+        match &mut cli.command {
+            Some(Commands::Print(args)) => {
+                args.first_weekday = args.first_weekday.or(self.first_weekday)
+            }
+            Some(Commands::List(args)) => {
+                let option = &args.option;
+                if args.columns.is_empty()
+                    && !(option.chinese
+                        || option.weekday
+                        || option.lunar_phase
+                        || option.lunar_phase_emoji
+                        || option.solar_term)
+                {
+                    args.columns = self.columns.clone();
+                }
+                self.apply_locale(&mut args.option);
+            }
+            Some(Commands::Query(args)) => self.apply_locale(&mut args.option),
+            Some(Commands::Holidays(args)) => args.region = args.region.or(self.region),
+            #[cfg(feature = "render")]
+            Some(Commands::Render(args)) => {
+                args.first_weekday = args.first_weekday.or(self.first_weekday)
+            }
+            None => cli.args.first_weekday = cli.args.first_weekday.or(self.first_weekday),
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test_config() {
+    let config = Config::parse(
+        r#"
+        # Defaults of omnical.
+        tz = "-05:30"
+        locale = "zh-Hant"
+        first_weekday = "Sun"  # Weeks start on Sunday.
+        columns = ["date", "chinese", "solar-term",]
+        region = "us"
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        config,
+        Config {
+            tz: Some(-5.5),
+            locale: Some(Locale::TraditionalChinese),
+            first_weekday: Some(FirstWeekday::Sun),
+            columns: vec![Column::Date, Column::Chinese, Column::SolarTerm],
+            region: Some(holiday::Region::UnitedStates),
+        }
+    );
+    assert_eq!(Config::parse("tz = 8").unwrap().tz, Some(8.0));
+    assert_eq!(Config::parse(""), Ok(Config::default()));
+    assert_eq!(
+        Config::parse("\ncolor = \"never\""),
+        Err("2: unknown key: color".to_string())
+    );
+    assert_eq!(
+        Config::parse("region = [\"cn\"]"),
+        Err("1: invalid type of region".to_string())
+    );
+    assert_eq!(
+        Config::parse("locale = \"ja\""),
+        Err("1: unsupported locale: ja".to_string())
+    );
+    assert!(Config::parse("first_weekday = \"sun").is_err());
+    assert!(Config::parse("columns = [\"date\" \"chinese\"]").is_err());
+    assert!(Config::parse("[list]").is_err());
+    assert_eq!(
+        Config::parse("[[event]]"),
+        Err("1: unknown table: event".to_string())
+    );
+
+    use clap::Parser as _;
+    let mut cli = Cli::parse_from(["omnical", "list", "-w"]);
+    config.apply(&mut cli);
+    assert_eq!(cli.tz, Some(-5.5));
+    let Some(Commands::List(args)) = &cli.command else {
+        unreachable!()
+    };
+    assert!(args.columns.is_empty());
+    assert!(args.option.traditional);
+    let mut cli = Cli::parse_from(["omnical", "--tz", "+8", "list", "-E"]);
+    config.apply(&mut cli);
+    assert_eq!(cli.tz, Some(8.0));
+    let Some(Commands::List(args)) = &cli.command else {
+        unreachable!()
+    };
+    assert_eq!(args.columns, config.columns);
+    assert!(!args.option.traditional);
+    let mut cli = Cli::parse_from(["omnical", "--first-weekday", "sat"]);
+    config.apply(&mut cli);
+    assert_eq!(cli.args.first_weekday, Some(FirstWeekday::Sat));
+}
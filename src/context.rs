@@ -0,0 +1,210 @@
+//! The settings that many functions of this crate take as arguments, kept
+//! together so that they are passed once.
+//!
+//! A [`Context`] can be passed explicitly, or made the default of the
+//! process with [`Context::set_default`] or of a closure on the current
+//! thread with [`Context::scope`], and then got back anywhere with
+//! [`Context::current`]:
+//!
+//! ```
+//! use omnical::{Context, Locale};
+//!
+//! let context = Context {
+//!     tz: 9.0,
+//!     locale: Locale::Japanese,
+//!     ..Context::default()
+//! };
+//! context.scope(|| {
+//!     assert_eq!(Context::current().tz, 9.0);
+//!     assert_eq!(Context::current().month_name(omnical::January), "1月");
+//! });
+//! assert_eq!(Context::current().tz, 0.0);
+//! ```
+
+use std::cell::Cell;
+use std::sync::Mutex;
+
+#[cfg(feature = "astronomy")]
+use crate::chinese::Mode;
+use crate::holiday::{Holiday, HolidayProvider, Region};
+use crate::script::Scripted;
+use crate::*;
+
+/// The timezone, language and other settings to compute and display dates
+/// with.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Context {
+    /// The timezone in hours east of UTC, e.g. 8.0 for Beijing.
+    pub tz: f64,
+    pub locale: Locale,
+    /// The script of Chinese text.
+    pub script: Script,
+    /// How new moons and solar terms are computed, e.g.
+    /// [`Mode::Deterministic`] for the same results on every platform.
+    #[cfg(feature = "astronomy")]
+    pub mode: Mode,
+    /// The region whose public holidays are observed, if any.
+    pub region: Option<Region>,
+}
+
+impl Context {
+    /// UTC, English, simplified Chinese script, the default [`Mode`] and no
+    /// holidays.
+    pub const DEFAULT: Self = Self {
+        tz: 0.0,
+        locale: Locale::English,
+        script: Script::Simplified,
+        #[cfg(feature = "astronomy")]
+        mode: Mode::TrueMoonTrueTerms,
+        region: None,
+    };
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+static DEFAULT: Mutex<Context> = Mutex::new(Context::DEFAULT);
+
+thread_local! {
+    static SCOPED: Cell<Option<Context>> = const { Cell::new(None) };
+}
+
+/// Restores the context of the enclosing scope, even if the scope panics.
+struct ScopeGuard(Option<Context>);
+
+impl Drop for ScopeGuard {
+    fn drop(&mut self) {
+        SCOPED.set(self.0);
+    }
+}
+
+impl Context {
+    /// The context of the innermost [`scope`](Self::scope) on the current
+    /// thread, or else the default of the process.
+    pub fn current() -> Self {
+        SCOPED
+            .get()
+            .unwrap_or_else(|| *DEFAULT.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    /// Sets the default context of the process, used by all threads outside
+    /// a [`scope`](Self::scope).
+    pub fn set_default(context: Self) {
+        *DEFAULT.lock().unwrap_or_else(|e| e.into_inner()) = context;
+    }
+
+    /// Runs the closure with this context as [`current`](Self::current) on
+    /// the current thread.
+    pub fn scope<R>(&self, f: impl FnOnce() -> R) -> R {
+        let _guard = ScopeGuard(SCOPED.replace(Some(*self)));
+        f()
+    }
+
+    /// The date of today in the timezone.
+    pub fn today(&self) -> Date {
+        Date::from_unix_time_with_tz(unix_time_now(), self.tz)
+    }
+
+    pub fn month_name(&self, month: MonthName) -> &'static str {
+        self.locale.month_name(month)
+    }
+
+    pub fn weekday_name(&self, weekday: Weekday) -> &'static str {
+        self.locale.weekday_name(weekday)
+    }
+
+    /// Wraps a value so that its Chinese text is displayed in the script.
+    pub fn display<T: std::fmt::Display>(&self, value: T) -> Scripted<T> {
+        self.script.display(value)
+    }
+
+    /// The solar term on the date in the timezone, computed by the mode.
+    #[cfg(feature = "astronomy")]
+    pub fn solar_term(&self, date: impl DateLike) -> Option<SolarTerm> {
+        let date = date.to_date();
+        self.mode.solar_terms(date, date.succ(), self.tz)[0]
+    }
+
+    /// The lunar phase on the date in the timezone, computed by the mode.
+    #[cfg(feature = "astronomy")]
+    pub fn lunar_phase(&self, date: impl DateLike) -> LunarPhase {
+        let date = date.to_date();
+        self.mode.lunar_phases(date, date.succ(), self.tz)[0]
+    }
+
+    /// The Chinese day of the date, computed by the mode.
+    ///
+    /// The Chinese calendar is always reckoned in Beijing time, whatever the
+    /// timezone.
+    #[cfg(feature = "astronomy")]
+    pub fn chinese_day(&self, date: impl DateLike) -> ChineseDay {
+        ChineseDay::from_date_with_mode(date.to_date(), self.mode)
+    }
+}
+
+/// The holidays of the region, none without one.
+impl HolidayProvider for Context {
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        self.region
+            .map_or_else(Vec::new, |region| region.holidays(year))
+    }
+}
+
+#[test]
+fn test_context() {
+    let context = Context {
+        tz: 8.0,
+        script: Script::Traditional,
+        region: Some(Region::UnitedStates),
+        ..Context::default()
+    };
+    let outer = Context::current();
+    let inner = Context {
+        locale: Locale::German,
+        ..context
+    };
+    context.scope(|| {
+        assert_eq!(Context::current(), context);
+        inner.scope(|| assert_eq!(Context::current().month_name(March), "März"));
+        assert_eq!(Context::current(), context);
+        // Other threads are outside the scope.
+        let other = std::thread::spawn(Context::current).join().unwrap();
+        assert_eq!(other, outer);
+    });
+    assert_eq!(Context::current(), outer);
+    let result = std::panic::catch_unwind(|| context.scope(|| panic!()));
+    assert!(result.is_err());
+    assert_eq!(Context::current(), outer);
+
+    let date: Date = GregorianCalendar::from_ymd(2024, 7, 4).unwrap().into();
+    assert_eq!(context.holidays_on(date)[0].name, "Independence Day");
+    assert!(Context::DEFAULT.holidays(2024).is_empty());
+    assert_eq!(context.weekday_name(Thursday), "Thursday");
+}
+
+#[cfg(feature = "astronomy")]
+#[test]
+fn test_context_astronomy() {
+    let context = Context {
+        tz: 8.0,
+        script: Script::Traditional,
+        mode: Mode::Deterministic,
+        ..Context::default()
+    };
+    let date: Date = GregorianCalendar::from_ymd(2024, 2, 10).unwrap().into();
+    assert_eq!(context.lunar_phase(date), NewMoon);
+    assert_eq!(
+        context.solar_term(GregorianCalendar::from_ymd(2024, 2, 4).unwrap()),
+        Some(BeginningOfSpring)
+    );
+    assert_eq!(context.solar_term(date), None);
+    let day = context.chinese_day(date);
+    assert_eq!(
+        format!("{:#}", context.display(day)),
+        "公元2024年農曆甲辰年正月初一"
+    );
+    assert_eq!(day.to_date(), date);
+}
@@ -152,6 +152,16 @@ fn test_weekday() {
 
 /// A generic date type using Julian day number (JDN) as its internal representation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Date)
+)]
 pub struct Date {
     jdn: i32,
 }
@@ -193,6 +203,8 @@ impl Date {
         self.midnight_jd(0.0)
     }
 
+    /// The date at the given Unix time in the given timezone; as in POSIX,
+    /// leap seconds are ignored, see [`timescale`].
     pub fn from_unix_time_with_tz(unix_time: u64, tz: f64) -> Self {
         Self::from_jd_with_tz(unix_time as f64 / 86400.0 + 2440587.5, tz)
     }
@@ -201,6 +213,27 @@ impl Date {
         Self::from_unix_time_with_tz(unix_time, 0.0)
     }
 
+    /// The date at the given time in the given timezone, which may be before
+    /// the Unix epoch.
+    pub fn from_system_time_with_tz(time: std::time::SystemTime, tz: f64) -> Self {
+        let seconds = match time.duration_since(std::time::UNIX_EPOCH) {
+            Ok(duration) => duration.as_secs_f64(),
+            Err(e) => -e.duration().as_secs_f64(),
+        };
+        Self::from_jd_with_tz(seconds / 86400.0 + 2440587.5, tz)
+    }
+
+    /// The midnight at the start of the date in the given timezone.
+    pub fn midnight_system_time(&self, tz: f64) -> std::time::SystemTime {
+        let seconds = (self.jdn as i64 - 2440588) * 86400 - (tz * 3600.0).round() as i64;
+        let duration = std::time::Duration::from_secs(seconds.unsigned_abs());
+        if seconds < 0 {
+            std::time::UNIX_EPOCH - duration
+        } else {
+            std::time::UNIX_EPOCH + duration
+        }
+    }
+
     pub const fn succ(&self) -> Self {
         Self::new(self.jdn + 1)
     }
@@ -213,27 +246,84 @@ impl Date {
         *ignore_none(&Weekday::from_repr(self.jdn.rem_euclid(7) as usize))
     }
 
+    /// The day of the Gregorian year of the date, starting from 1.
+    pub fn ordinal_in_gregorian_year(&self) -> u16 {
+        GregorianDay::from(*self).day_of_year()
+    }
+}
+
+#[cfg(feature = "astronomy")]
+impl Date {
     pub fn solar_term(&self, tz: f64) -> Option<SolarTerm> {
-        let curr_sun_ecl_long = astronomy::get_sun_ecl_long(self.midnight_jd(tz));
-        let next_sun_ecl_long = astronomy::get_sun_ecl_long(self.succ().midnight_jd(tz));
-        let curr_sun_ecl_long = if next_sun_ecl_long < curr_sun_ecl_long {
-            curr_sun_ecl_long - 360.0
-        } else {
-            curr_sun_ecl_long
-        };
-        SolarTerm::from_degree_range(curr_sun_ecl_long, next_sun_ecl_long)
+        astronomy::solar_term_between(
+            astronomy::get_sun_ecl_long(self.midnight_jd(tz)),
+            astronomy::get_sun_ecl_long(self.succ().midnight_jd(tz)),
+        )
     }
 
     pub fn lunar_phase(&self, tz: f64) -> LunarPhase {
-        let curr_moon_ecl_long_to_sun = astronomy::get_moon_ecl_long_to_sun(self.midnight_jd(tz));
-        let next_moon_ecl_long_to_sun =
-            astronomy::get_moon_ecl_long_to_sun(self.succ().midnight_jd(tz));
-        let curr_moon_ecl_long_to_sun = if next_moon_ecl_long_to_sun < curr_moon_ecl_long_to_sun {
-            curr_moon_ecl_long_to_sun - 360.0
-        } else {
-            curr_moon_ecl_long_to_sun
+        astronomy::lunar_phase_between(
+            astronomy::get_moon_ecl_long_to_sun(self.midnight_jd(tz)),
+            astronomy::get_moon_ecl_long_to_sun(self.succ().midnight_jd(tz)),
+        )
+    }
+
+    /// The lunation (synodic month) containing the date.
+    pub fn lunation(&self, tz: f64) -> Lunation {
+        Lunation::from_date(*self, tz)
+    }
+
+    /// The Brown lunation number of the lunation containing the date.
+    pub fn lunation_number(&self, tz: f64) -> i32 {
+        self.lunation(tz).number()
+    }
+
+    /// The season of the date in the given hemisphere by the given convention.
+    ///
+    /// For conventions bounded by solar terms, the day on which a term falls
+    /// already belongs to the new season.
+    pub fn season(&self, hemisphere: Hemisphere, convention: SeasonConvention, tz: f64) -> Season {
+        let season = match convention {
+            SeasonConvention::Astronomical => {
+                Season::from_degrees(astronomy::get_sun_ecl_long(self.succ().midnight_jd(tz)))
+            }
+            SeasonConvention::Chinese => Season::from_degrees(
+                astronomy::get_sun_ecl_long(self.succ().midnight_jd(tz)) + 45.0,
+            ),
+            SeasonConvention::Meteorological => {
+                Season::from_month(GregorianDay::from(*self).the_month().ord()).unwrap()
+            }
         };
-        LunarPhase::from_degree_range(curr_moon_ecl_long_to_sun, next_moon_ecl_long_to_sun)
+        match hemisphere {
+            Hemisphere::Northern => season,
+            Hemisphere::Southern => season.opposite(),
+        }
+    }
+
+    /// The lunar mansion (二十八宿) on duty for the date.
+    pub fn lunar_mansion(&self) -> LunarMansion {
+        LunarMansion::from_jdn(self.jdn)
+    }
+
+    /// The solar-term month (节气月) containing the date.
+    pub fn solar_month(&self, tz: f64) -> SolarMonth {
+        SolarMonth::from_date(*self, tz)
+    }
+
+    /// The zodiac sign of the date, determined by the given method.
+    ///
+    /// With [`ZodiacMethod::Astronomical`], the sign is where the sun is at
+    /// local noon; with [`ZodiacMethod::DateRange`], the timezone is ignored.
+    pub fn zodiac_sign(&self, method: ZodiacMethod, tz: f64) -> ZodiacSign {
+        match method {
+            ZodiacMethod::Astronomical => {
+                ZodiacSign::from_degrees(astronomy::get_sun_ecl_long(self.noon_jd(tz)))
+            }
+            ZodiacMethod::DateRange => {
+                let day = GregorianDay::from(*self);
+                ZodiacSign::from_month_day(day.the_month().ord(), day.ord()).unwrap()
+            }
+        }
     }
 }
 
@@ -271,6 +361,46 @@ impl std::ops::Sub for Date {
     }
 }
 
+/// The date of the time in UTC.
+impl From<std::time::SystemTime> for Date {
+    fn from(time: std::time::SystemTime) -> Self {
+        Self::from_system_time_with_tz(time, 0.0)
+    }
+}
+
+/// The midnight at the start of the date in UTC.
+impl From<Date> for std::time::SystemTime {
+    fn from(date: Date) -> Self {
+        date.midnight_system_time(0.0)
+    }
+}
+
+#[test]
+fn test_system_time() {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    let date = Date::from_jdn(2460351); // 2024-02-10
+    let time = UNIX_EPOCH + Duration::from_secs(1707539696); // 2024-02-10 04:34:56 UTC
+    assert_eq!(Date::from(time), date);
+    assert_eq!(Date::from_system_time_with_tz(time, -5.0), date.pred());
+    assert_eq!(
+        SystemTime::from(date),
+        UNIX_EPOCH + Duration::from_secs(1707523200)
+    );
+    assert_eq!(
+        date.midnight_system_time(8.0),
+        UNIX_EPOCH + Duration::from_secs(1707494400)
+    );
+    assert_eq!(
+        Date::from(UNIX_EPOCH - Duration::from_secs(1)),
+        Date::from_jdn(2440587)
+    );
+    assert_eq!(
+        SystemTime::from(Date::from_jdn(2440587)),
+        UNIX_EPOCH - Duration::from_secs(86400)
+    );
+}
+
 #[test]
 fn test_date() {
     let d1 = Date::from_jd(2299159.5);
@@ -285,14 +415,67 @@ fn test_date() {
     assert_eq!(Date::from_jdn(2446324).weekday(), Sunday);
     assert_eq!(Date::from_jdn(2460351).weekday(), Saturday);
 
+    assert_eq!(d2 - d1, 1);
+
+    let j2000: Date = GregorianCalendar::from_ymd(2000, 1, 1).unwrap().into();
+    assert_eq!(j2000.jdn(), 2451545);
+}
+
+#[cfg(feature = "astronomy")]
+#[test]
+fn test_date_astronomy() {
     assert_eq!(
         Date::from_jdn(2460301).solar_term(8.0),
         Some(WinterSolstice)
     );
     assert_eq!(Date::from_jdn(2460292).lunar_phase(8.0), NewMoon);
+    assert_eq!(Date::from_jdn(2460301).lunation_number(8.0), 1249);
 
-    assert_eq!(d2 - d1, 1);
+    let winter_solstice = Date::from_jdn(2460301);
+    assert_eq!(
+        winter_solstice.season(Hemisphere::Northern, SeasonConvention::Astronomical, 8.0),
+        Winter
+    );
+    assert_eq!(
+        winter_solstice
+            .pred()
+            .season(Hemisphere::Northern, SeasonConvention::Astronomical, 8.0),
+        Autumn
+    );
+    assert_eq!(
+        winter_solstice.season(Hemisphere::Southern, SeasonConvention::Astronomical, 8.0),
+        Summer
+    );
+    assert_eq!(
+        winter_solstice.season(Hemisphere::Northern, SeasonConvention::Meteorological, 8.0),
+        Winter
+    );
+    assert_eq!(
+        winter_solstice.season(Hemisphere::Northern, SeasonConvention::Chinese, 8.0),
+        Winter
+    );
+    let beginning_of_spring: Date = GregorianCalendar::from_ymd(2024, 2, 4).unwrap().into();
+    assert_eq!(
+        beginning_of_spring.season(Hemisphere::Northern, SeasonConvention::Chinese, 8.0),
+        Spring
+    );
+    assert_eq!(
+        beginning_of_spring
+            .pred()
+            .season(Hemisphere::Northern, SeasonConvention::Chinese, 8.0),
+        Winter
+    );
 
-    let j2000: Date = GregorianCalendar::from_ymd(2000, 1, 1).unwrap().into();
-    assert_eq!(j2000.jdn(), 2451545);
+    assert_eq!(
+        Date::from_jdn(2460301).zodiac_sign(ZodiacMethod::Astronomical, 8.0),
+        Capricorn
+    );
+    assert_eq!(
+        Date::from_jdn(2460300).zodiac_sign(ZodiacMethod::Astronomical, 8.0),
+        Sagittarius
+    );
+    assert_eq!(
+        Date::from_jdn(2460300).zodiac_sign(ZodiacMethod::DateRange, 8.0),
+        Sagittarius
+    );
 }
@@ -0,0 +1,418 @@
+//! The events file of the CLI, given by `--events`, holding the user's events
+//! to highlight and annotate, e.g.
+//!
+//! ```toml
+//! [[event]]
+//! name = "Dentist"
+//! date = "2025-03-14"
+//!
+//! [[event]]
+//! name = "Wedding anniversary"
+//! date = "06-18"  # Every year.
+//!
+//! [[event]]
+//! name = "Standup"
+//! start = "2025-01-06"
+//! rule = "FREQ=WEEKLY;BYDAY=MO,WE,FR"
+//!
+//! [[event]]
+//! name = "Grandma's birthday"
+//! chinese = "八月十五"
+//! ```
+//!
+//! The same subset of TOML as the configuration file is supported, with an
+//! `[[event]]` line starting each event.
+
+use std::path::Path;
+
+use omnical::*;
+
+use super::config::{parse_lines, Line, Value};
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Events(Vec<Event>);
+
+#[derive(Debug, PartialEq)]
+struct Event {
+    name: String,
+    occurrence: Occurrence,
+}
+
+/// When an event occurs.
+#[derive(Debug, PartialEq)]
+enum Occurrence {
+    Once(Date),
+    /// Every year on a Gregorian month and day.
+    Yearly(u8, u8),
+    /// Every year on a Chinese month, leap or not, and day.
+    Chinese(bool, u8, u8),
+    Rule(Date, Rule),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// A recurrence rule, a subset of that of iCalendar (RFC 5545): `FREQ`,
+/// `INTERVAL`, `UNTIL`, `BYMONTH`, `BYMONTHDAY` and `BYDAY`, where the
+/// ordinal of a weekday, e.g. `-1FR`, counts within the month.
+#[derive(Debug, PartialEq)]
+struct Rule {
+    frequency: Frequency,
+    interval: i32,
+    until: Option<Date>,
+    months: Vec<u8>,
+    month_days: Vec<i8>,
+    weekdays: Vec<(Option<i8>, Weekday)>,
+}
+
+fn parse_date(s: &str) -> Result<Date, String> {
+    GregorianDay::from_iso8601(s)
+        .map(Date::from)
+        .map_err(|_| format!("invalid date: {}", s))
+}
+
+/// Parses a month and day like "06-18", allowing February 29.
+fn parse_month_day(s: &str) -> Option<(u8, u8)> {
+    let (m, d) = s.split_once('-')?;
+    let (m, d) = (m.parse().ok()?, d.parse().ok()?);
+    GregorianCalendar::from_ymd(2000, m, d).map(|_| (m, d))
+}
+
+fn parse_weekday(s: &str) -> Option<Weekday> {
+    let index = ["MO", "TU", "WE", "TH", "FR", "SA", "SU"]
+        .iter()
+        .position(|name| *name == s)?;
+    Weekday::from_repr(index)
+}
+
+fn parse_list<T>(value: &str, parse: impl Fn(&str) -> Option<T>) -> Option<Vec<T>> {
+    value.split(',').map(|item| parse(item.trim())).collect()
+}
+
+impl std::str::FromStr for Rule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rule = Rule {
+            frequency: Frequency::Daily,
+            interval: 1,
+            until: None,
+            months: Vec::new(),
+            month_days: Vec::new(),
+            weekdays: Vec::new(),
+        };
+        let mut frequency = None;
+        for part in s.trim().trim_start_matches("RRULE:").split(';') {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| format!("invalid rule part: {}", part))?;
+            let invalid = || format!("invalid {}: {}", key, value);
+            match key.trim().to_uppercase().as_str() {
+                "FREQ" => {
+                    frequency = Some(match value.to_uppercase().as_str() {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        _ => return Err(invalid()),
+                    })
+                }
+                "INTERVAL" => {
+                    rule.interval = value.parse().ok().filter(|&n| n > 0).ok_or_else(invalid)?
+                }
+                "UNTIL" => {
+                    let date = value.get(..8).ok_or_else(invalid)?;
+                    rule.until = Some(
+                        GregorianCalendar::from_ymd(
+                            date[..4].parse().map_err(|_| invalid())?,
+                            date[4..6].parse().map_err(|_| invalid())?,
+                            date[6..].parse().map_err(|_| invalid())?,
+                        )
+                        .ok_or_else(invalid)?
+                        .into(),
+                    )
+                }
+                "BYMONTH" => {
+                    rule.months =
+                        parse_list(value, |m| m.parse().ok().filter(|m| (1..=12).contains(m)))
+                            .ok_or_else(invalid)?
+                }
+                "BYMONTHDAY" => {
+                    rule.month_days = parse_list(value, |d| {
+                        d.parse().ok().filter(|d: &i8| (1..=31).contains(&d.abs()))
+                    })
+                    .ok_or_else(invalid)?
+                }
+                "BYDAY" => {
+                    rule.weekdays = parse_list(value, |day| {
+                        let split = day.len().checked_sub(2)?;
+                        let weekday = parse_weekday(&day.get(split..)?.to_uppercase())?;
+                        let ordinal = match &day[..split] {
+                            "" => None,
+                            n => Some(n.parse().ok().filter(|n: &i8| (1..=5).contains(&n.abs()))?),
+                        };
+                        Some((ordinal, weekday))
+                    })
+                    .ok_or_else(invalid)?
+                }
+                _ => return Err(format!("unsupported rule part: {}", key)),
+            }
+        }
+        rule.frequency = frequency.ok_or("missing FREQ in the rule")?;
+        Ok(rule)
+    }
+}
+
+impl Rule {
+    /// Whether the rule starting on `start` recurs on `date`.
+    fn matches(&self, start: Date, date: Date) -> bool {
+        if date < start || self.until.is_some_and(|until| date > until) {
+            return false;
+        }
+        let (day, first) = (GregorianDay::from(date), GregorianDay::from(start));
+        let month_index =
+            |day: GregorianDay| day.the_year().ord() * 12 + day.the_month().ord() as i32;
+        let week_start = |date: Date| date + -(date.weekday() as i32 - Monday as i32).rem_euclid(7);
+        let periods = match self.frequency {
+            Frequency::Daily => date - start,
+            Frequency::Weekly => (week_start(date) - week_start(start)) / 7,
+            Frequency::Monthly => month_index(day) - month_index(first),
+            Frequency::Yearly => day.the_year().ord() - first.the_year().ord(),
+        };
+        if periods % self.interval != 0 {
+            return false;
+        }
+
+        let months = if self.months.is_empty() && self.frequency == Frequency::Yearly {
+            vec![first.the_month().ord()]
+        } else {
+            self.months.clone()
+        };
+        if !months.is_empty() && !months.contains(&day.the_month().ord()) {
+            return false;
+        }
+        let num_days = day.the_month().num_days() as i8;
+        let ord = day.ord() as i8;
+        if !self.month_days.is_empty()
+            && !self
+                .month_days
+                .iter()
+                .any(|&d| d == ord || d == ord - num_days - 1)
+        {
+            return false;
+        }
+        if !self.weekdays.is_empty() {
+            return self.weekdays.iter().any(|&(ordinal, weekday)| {
+                weekday == date.weekday()
+                    && match ordinal {
+                        None => true,
+                        Some(n) if n > 0 => (ord - 1) / 7 + 1 == n,
+                        Some(n) => (num_days - ord) / 7 + 1 == -n,
+                    }
+            });
+        }
+        // Without a weekday or a day of the month, the rule recurs on the
+        // weekday or the day of the month of its start.
+        match self.frequency {
+            _ if !self.month_days.is_empty() => true,
+            Frequency::Daily => true,
+            Frequency::Weekly => date.weekday() == start.weekday(),
+            Frequency::Monthly | Frequency::Yearly => day.ord() == first.ord(),
+        }
+    }
+}
+
+impl Occurrence {
+    fn matches(&self, date: Date) -> bool {
+        match self {
+            Occurrence::Once(once) => date == *once,
+            Occurrence::Yearly(month, day) => {
+                let date = GregorianDay::from(date);
+                date.the_month().ord() == *month && date.ord() == *day
+            }
+            Occurrence::Chinese(leap, month, day) => ChineseDay::try_from(date).is_ok_and(|date| {
+                let the_month = date.the_month();
+                the_month.is_leap() == *leap
+                    && the_month.ord_no_leap() == *month
+                    && date.ord() == *day
+            }),
+            Occurrence::Rule(start, rule) => rule.matches(*start, date),
+        }
+    }
+}
+
+/// The fields of an event, as read from the file.
+#[derive(Default)]
+struct Entry {
+    name: Option<String>,
+    date: Option<String>,
+    chinese: Option<String>,
+    start: Option<String>,
+    rule: Option<String>,
+}
+
+impl Entry {
+    fn set(&mut self, key: &str, value: Value) -> Result<(), String> {
+        let field = match key {
+            "name" => &mut self.name,
+            "date" => &mut self.date,
+            "chinese" => &mut self.chinese,
+            "start" => &mut self.start,
+            "rule" => &mut self.rule,
+            _ => return Err(format!("unknown key: {}", key)),
+        };
+        match value {
+            Value::String(value) => *field = Some(value),
+            _ => return Err(format!("invalid type of {}", key)),
+        }
+        Ok(())
+    }
+
+    fn into_event(self) -> Result<Event, String> {
+        let name = self.name.ok_or("missing name of the event")?;
+        let occurrence = match (self.date, self.chinese, self.rule) {
+            (Some(date), None, None) if self.start.is_none() => match parse_month_day(&date) {
+                Some((month, day)) => Occurrence::Yearly(month, day),
+                None => Occurrence::Once(parse_date(&date)?),
+            },
+            (None, Some(chinese), None) if self.start.is_none() => {
+                let (leap, month, day) = chinese::parse_month_day(&chinese)
+                    .map_err(|_| format!("invalid Chinese date: {}", chinese))?;
+                Occurrence::Chinese(leap, month, day)
+            }
+            (None, None, Some(rule)) => {
+                let start = self.start.ok_or("missing start of the rule")?;
+                Occurrence::Rule(parse_date(&start)?, rule.parse()?)
+            }
+            _ => return Err("expected one of date, chinese, or start and rule".to_string()),
+        };
+        Ok(Event { name, occurrence })
+    }
+}
+
+impl Events {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text =
+            std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        Self::parse(&text).map_err(|e| format!("{}:{}", path.display(), e))
+    }
+
+    /// Parses events, reporting an error as "line: message".
+    fn parse(text: &str) -> Result<Self, String> {
+        // The entries, with the lines they start on.
+        let mut entries: Vec<(usize, Entry)> = Vec::new();
+        parse_lines(text, |i, line| match line {
+            Line::Table("event") => {
+                entries.push((i, Entry::default()));
+                Ok(())
+            }
+            Line::Table(table) => Err(format!("unknown table: {}", table)),
+            Line::Pair(key, value) => match entries.last_mut() {
+                Some((_, entry)) => entry.set(key, value),
+                None => Err("expected `[[event]]` first".to_string()),
+            },
+        })?;
+        entries
+            .into_iter()
+            .map(|(line, entry)| entry.into_event().map_err(|e| format!("{}: {}", line, e)))
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+
+    /// The names of the events on a date.
+    pub fn names(&self, date: Date) -> Vec<&str> {
+        self.0
+            .iter()
+            .filter(|event| event.occurrence.matches(date))
+            .map(|event| event.name.as_str())
+            .collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[test]
+fn test_events() {
+    let events = Events::parse(
+        r#"
+        # Events of omnical.
+        [[event]]
+        name = "Dentist"
+        date = "2025-03-14"
+
+        [[event]]
+        name = "Anniversary"
+        date = "02-29"
+
+        [[event]]
+        name = "Standup"
+        start = "2025-01-06"
+        rule = "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,FR;UNTIL=20250331"
+
+        [[event]]
+        name = "Payday"
+        start = "2025-01-01"
+        rule = "FREQ=MONTHLY;BYMONTHDAY=-1"
+
+        [[event]]
+        name = "Thanksgiving"
+        start = "2000-01-01"
+        rule = "FREQ=YEARLY;BYMONTH=11;BYDAY=4TH"
+
+        [[event]]
+        name = "Birthday"
+        chinese = "腊月初八"
+        "#,
+    )
+    .unwrap();
+    let date = |y, m, d| Date::from(GregorianCalendar::from_ymd(y, m, d).unwrap());
+    assert_eq!(events.names(date(2025, 3, 14)), ["Dentist"]);
+    assert!(events.names(date(2026, 3, 14)).is_empty());
+    assert_eq!(events.names(date(2028, 2, 29)), ["Anniversary", "Payday"]);
+    assert_eq!(events.names(date(2025, 1, 6)), ["Standup"]);
+    assert_eq!(events.names(date(2025, 1, 10)), ["Standup"]);
+    assert!(events.names(date(2025, 1, 13)).is_empty());
+    assert_eq!(events.names(date(2025, 1, 20)), ["Standup"]);
+    assert!(events.names(date(2025, 4, 14)).is_empty());
+    assert_eq!(events.names(date(2025, 2, 28)), ["Payday"]);
+    assert!(events.names(date(2024, 12, 31)).is_empty());
+    assert_eq!(events.names(date(2025, 11, 27)), ["Thanksgiving"]);
+    assert!(events.names(date(2025, 11, 20)).is_empty());
+    // 腊八 of 甲辰年 and 乙巳年.
+    assert_eq!(events.names(date(2025, 1, 7)), ["Birthday"]);
+    assert_eq!(events.names(date(2026, 1, 26)), ["Birthday"]);
+
+    assert_eq!(Events::parse(""), Ok(Events::default()));
+    assert_eq!(
+        Events::parse("name = \"Dentist\""),
+        Err("1: expected `[[event]]` first".to_string())
+    );
+    assert_eq!(
+        Events::parse("[[event]]\nname = \"Dentist\""),
+        Err("1: expected one of date, chinese, or start and rule".to_string())
+    );
+    assert_eq!(
+        Events::parse("[[event]]\nname = \"Dentist\"\ndate = \"2025-02-30\""),
+        Err("1: invalid date: 2025-02-30".to_string())
+    );
+    assert!(Events::parse("[[event]]\nname = \"A\"\nchinese = \"十三月初一\"").is_err());
+    assert_eq!(
+        Events::parse("[[events]]"),
+        Err("1: unknown table: events".to_string())
+    );
+    assert!(
+        Events::parse("[[event]]\nname = \"A\"\nstart = \"2025-01-01\"\nrule = \"BYDAY=MO\"")
+            .is_err()
+    );
+    assert!(Events::parse(
+        "[[event]]\nname = \"A\"\nstart = \"2025-01-01\"\nrule = \"FREQ=WEEKLY;COUNT=3\""
+    )
+    .is_err());
+}
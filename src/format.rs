@@ -0,0 +1,120 @@
+//! Combined Gregorian (公历) and Chinese (农历) date formatting.
+
+use crate::calendar::Day as _;
+use crate::chinese::BEIJING_TZ;
+use crate::*;
+
+/// The components of a date in the combined form, e.g.
+/// "2024年2月10日（正月初一，星期六）".
+///
+/// The Gregorian date always comes first, followed by the enabled components
+/// in parentheses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CombinedFormat {
+    /// The Chinese date, e.g. "正月初一".
+    pub chinese_date: bool,
+    /// The stem-branch year before the Chinese date, e.g. "甲辰年正月初一".
+    pub chinese_year: bool,
+    /// The weekday, e.g. "星期六".
+    pub weekday: bool,
+    /// The solar term, if any.
+    pub solar_term: bool,
+    /// The traditional festival, if any.
+    pub festival: bool,
+    /// The script of the Chinese text.
+    pub script: Script,
+}
+
+impl Default for CombinedFormat {
+    fn default() -> Self {
+        Self {
+            chinese_date: true,
+            chinese_year: false,
+            weekday: true,
+            solar_term: false,
+            festival: false,
+            script: Script::default(),
+        }
+    }
+}
+
+impl CombinedFormat {
+    /// Formats a date, leaving out the Chinese date and festival of a date
+    /// outside [`chinese::SUPPORTED_YEARS`].
+    pub fn format(&self, date: Date) -> String {
+        let chinese_day = ChineseDay::try_from(date).ok();
+        let mut components = Vec::new();
+        if let Some(chinese_day) = chinese_day.filter(|_| self.chinese_date) {
+            if self.chinese_year {
+                components.push(chinese_day.to_string());
+            } else {
+                components.push(format!(
+                    "{}{}",
+                    chinese_day.the_month().name(),
+                    chinese_day.name()
+                ));
+            }
+        }
+        if self.weekday {
+            components.push(format!("{:#}", date.weekday()));
+        }
+        if self.solar_term {
+            if let Some(st) = date.solar_term(BEIJING_TZ) {
+                components.push(st.chinese().to_string());
+            }
+        }
+        if self.festival {
+            if let Some(festival) = chinese_day.and_then(|day| day.festival()) {
+                components.push(festival.chinese().to_string());
+            }
+        }
+        let gregorian_day = GregorianDay::from(date);
+        let s = if components.is_empty() {
+            format!("{:#}", gregorian_day)
+        } else {
+            format!("{:#}（{}）", gregorian_day, components.join("，"))
+        };
+        self.script.convert(&s)
+    }
+}
+
+#[test]
+fn test_combined_format() {
+    use crate::calendar::Calendar as _;
+
+    let date = Date::from(GregorianCalendar::from_ymd(2024, 2, 10).unwrap());
+    assert_eq!(
+        CombinedFormat::default().format(date),
+        "2024年2月10日（正月初一，星期六）"
+    );
+    let format = CombinedFormat {
+        chinese_year: true,
+        weekday: false,
+        festival: true,
+        script: Script::Traditional,
+        ..Default::default()
+    };
+    assert_eq!(format.format(date), "2024年2月10日（甲辰年正月初一，春節）");
+    let format = CombinedFormat {
+        chinese_date: false,
+        weekday: false,
+        ..Default::default()
+    };
+    assert_eq!(format.format(date), "2024年2月10日");
+
+    let date = Date::from(GregorianCalendar::from_ymd(2024, 2, 4).unwrap());
+    let format = CombinedFormat {
+        solar_term: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        format.format(date),
+        "2024年2月4日（十二月廿五，星期日，立春）"
+    );
+
+    let date = Date::from(GregorianCalendar::from_ymd(7000, 1, 1).unwrap());
+    assert_eq!(
+        CombinedFormat::default().format(date),
+        "7000年1月1日（星期三）"
+    );
+}
@@ -1,9 +1,13 @@
 /// Proleptic Gregorian calendar
-use strum::{AsRefStr, Display, EnumCount, EnumString, FromRepr, VariantArray};
+use strum::{AsRefStr, Display, EnumCount, EnumProperty, EnumString, FromRepr, VariantArray};
 
 use crate::calendar::{Calendar as _, Day as _, Month as _, Year as _};
 use crate::*;
 
+mod iso8601;
+
+pub use iso8601::ParseIso8601Error;
+
 /// Converts a proleptic Gregorian date to a Julian day.
 pub fn proleptic_gregorian_to_julian_day(y: i32, m: u8, d: f64) -> f64 {
     let (y, m) = if m > 2 { (y, m) } else { (y - 1, m + 12) };
@@ -59,14 +63,19 @@ fn test_proleptic_gregorian_and_julian_day() {
 
 pub struct Calendar;
 
+/// The years supported by the Gregorian calendar.
+pub const SUPPORTED_YEARS: std::ops::RangeInclusive<i32> = -5_000_000..=5_000_000;
+
 impl calendar::Calendar for Calendar {
     type Year = Year;
     type Month = Month;
     type Day = Day;
+    type MonthOrd = u8;
+    type DayOrd = u8;
 
     fn from_y(year: i32) -> Option<Year> {
         // TODO: More precise validation
-        if (-5_000_000..=5_000_000).contains(&year) {
+        if SUPPORTED_YEARS.contains(&year) {
             Some(Year::new(year))
         } else {
             None
@@ -74,6 +83,20 @@ impl calendar::Calendar for Calendar {
     }
 }
 
+impl calendar::CalendarInfo for Calendar {
+    fn display_name() -> &'static str {
+        "Gregorian"
+    }
+
+    fn supported_years() -> std::ops::RangeInclusive<i32> {
+        SUPPORTED_YEARS
+    }
+
+    fn months_per_year() -> std::ops::RangeInclusive<usize> {
+        12..=12
+    }
+}
+
 impl Calendar {
     pub fn from_yn(year: i32, month: MonthName) -> Option<Month> {
         Some(Self::from_y(year)?.month_by_name(month))
@@ -82,9 +105,48 @@ impl Calendar {
     pub fn from_ynd(year: i32, month: MonthName, day: u8) -> Option<Day> {
         Self::from_yn(year, month)?.day(day)
     }
+
+    /// The year with the given number in the given era, e.g. 45 BCE, which is
+    /// the astronomical year -44.
+    pub fn from_era_year(era: Era, year: u32) -> Option<Year> {
+        let year = i32::try_from(year).ok().filter(|&y| y > 0)?;
+        match era {
+            Era::Ce => Self::from_y(year),
+            Era::Bce => Self::from_y(1 - year),
+        }
+    }
+}
+
+/// The eras of the Gregorian calendar, which has no year zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, EnumString, EnumProperty)]
+pub enum Era {
+    /// Before the Common Era (公元前), also known as BC.
+    #[strum(to_string = "BCE", serialize = "BC", props(zh = "公元前"))]
+    Bce,
+    /// The Common Era (公元), also known as AD.
+    #[strum(to_string = "CE", serialize = "AD", props(zh = "公元"))]
+    Ce,
+}
+
+impl Era {
+    pub fn chinese(&self) -> &str {
+        self.get_str("zh").unwrap()
+    }
 }
 
+/// The number of days before each month, and in the whole year, of common
+/// and leap years.
+const DAYS_BEFORE_MONTH: [[u16; 13]; 2] = [
+    [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334, 365],
+    [0, 31, 60, 91, 121, 152, 182, 213, 244, 274, 305, 335, 366],
+];
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub struct Year {
     year: i32,
 }
@@ -97,6 +159,35 @@ impl Year {
     pub fn month_by_name(&self, month_name: MonthName) -> Month {
         Month::new(*self, month_name)
     }
+
+    /// The Easter Sunday of the year, by the Gregorian computus.
+    pub fn easter(&self) -> Day {
+        // The anonymous Gregorian algorithm (Meeus/Jones/Butcher).
+        let y = self.year;
+        let a = y.rem_euclid(19);
+        let (b, c) = (y.div_euclid(100), y.rem_euclid(100));
+        let (d, e) = (b.div_euclid(4), b.rem_euclid(4));
+        let f = (b + 8).div_euclid(25);
+        let g = (b - f + 1).div_euclid(3);
+        let h = (19 * a + b - d - g + 15).rem_euclid(30);
+        let (i, k) = (c / 4, c % 4);
+        let l = (32 + 2 * e + 2 * i - h - k).rem_euclid(7);
+        let m = (a + 11 * h + 22 * l) / 451;
+        let n = h + l - 7 * m + 114;
+        self.month((n / 31) as u8)
+            .and_then(|month| month.day((n % 31 + 1) as u8))
+            .unwrap()
+    }
+
+    /// The era of the year and the number of the year in it, e.g. (BCE, 45)
+    /// for the astronomical year -44.
+    pub fn era(&self) -> (Era, u32) {
+        if self.year > 0 {
+            (Era::Ce, self.year as u32)
+        } else {
+            (Era::Bce, (1 - self.year) as u32)
+        }
+    }
 }
 
 impl calendar::Year<Calendar> for Year {
@@ -141,15 +232,21 @@ impl calendar::Year<Calendar> for Year {
     }
 
     fn day(&self, ord: u16) -> Option<Day> {
-        let mut ord = ord as usize;
-        for name in MonthName::VARIANTS.iter() {
-            let month = self.month_by_name(*name);
-            if ord <= month.num_days() {
-                return month.day(ord as u8);
-            }
-            ord -= month.num_days();
+        let days_before_month = &DAYS_BEFORE_MONTH[self.is_leap() as usize];
+        if ord < 1 || ord > days_before_month[12] {
+            return None;
         }
-        None
+        let month = days_before_month.partition_point(|&days| days < ord);
+        let month = self.month_by_name(MonthName::from_ord(month as u8).unwrap());
+        Some(Day::new(
+            month,
+            (ord - days_before_month[month.ord() as usize - 1] - 1) as u8,
+        ))
+    }
+
+    fn days(&self) -> impl Iterator<Item = Day> {
+        self.months()
+            .flat_map(|month| (0..month.num_days() as u8).map(move |day| Day::new(month, day)))
     }
 
     fn is_leap(&self) -> bool {
@@ -169,6 +266,36 @@ fn test_year() {
     assert!(Calendar::from_y(2000).unwrap().is_leap());
     assert!(!Calendar::from_y(1900).unwrap().is_leap());
 
+    for year in [1985, 2024] {
+        let year = Calendar::from_y(year).unwrap();
+        let days: Vec<_> = year.days().collect();
+        assert_eq!(days.len(), year.num_days());
+        assert_eq!(days[0], year.first_day());
+        for (i, day) in days.iter().enumerate() {
+            assert_eq!(day.ord_in_year(), i as u16 + 1);
+            assert_eq!(year.day(i as u16 + 1), Some(*day));
+            if i > 0 {
+                assert_eq!(days[i - 1].succ(), *day);
+            }
+        }
+        assert_eq!(year.day(0), None);
+        assert_eq!(year.day(year.num_days() as u16 + 1), None);
+    }
+    assert_eq!(Calendar::from_ymd(2024, 3, 1).unwrap().ord_in_year(), 61);
+
+    for (y, m, d) in [
+        (1985, 4, 7),
+        (2000, 4, 23),
+        (2024, 3, 31),
+        (2025, 4, 20),
+        (2038, 4, 25),
+    ] {
+        assert_eq!(
+            Calendar::from_y(y).unwrap().easter(),
+            Calendar::from_ymd(y, m, d).unwrap()
+        );
+    }
+
     assert_eq!(year.succ(), Calendar::from_y(1986).unwrap());
     assert_eq!(year.pred(), Calendar::from_y(1984).unwrap());
 
@@ -196,6 +323,11 @@ fn test_year() {
     EnumString,
     FromRepr,
 )]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub enum MonthName {
     January,
     February,
@@ -271,6 +403,11 @@ fn test_month_name() {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub struct Month {
     year: Year,
     month: MonthName,
@@ -284,6 +421,21 @@ impl Month {
     pub fn name(&self) -> MonthName {
         self.month
     }
+
+    /// The `n`-th given weekday of the month, counting from 1, e.g. the
+    /// second Sunday of May.
+    pub fn nth_weekday(&self, n: u8, weekday: Weekday) -> Option<Day> {
+        let first_weekday = self.first_day().weekday();
+        let offset = (weekday as u8 + 7 - first_weekday as u8) % 7;
+        self.day(n.checked_sub(1)?.checked_mul(7)?.checked_add(offset + 1)?)
+    }
+
+    /// The last given weekday of the month, e.g. the last Sunday of March.
+    pub fn last_weekday(&self, weekday: Weekday) -> Day {
+        let last_day = self.last_day();
+        let offset = (last_day.weekday() as u8 + 7 - weekday as u8) % 7;
+        self.day(last_day.ord() - offset).unwrap()
+    }
 }
 
 impl calendar::Month<Calendar> for Month {
@@ -375,6 +527,11 @@ fn test_month() {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub struct Day {
     month: Month,
     day: u8,
@@ -386,8 +543,78 @@ impl Day {
     }
 
     pub fn from_date_with_tz(date: Date, tz: f64) -> Self {
+        Self::checked_from_date_with_tz(date, tz).unwrap()
+    }
+
+    /// Like [`from_date_with_tz`](Self::from_date_with_tz), but returns `None`
+    /// instead of panicking for a date outside [`SUPPORTED_YEARS`] or a
+    /// timezone that is not finite.
+    pub fn checked_from_date_with_tz(date: Date, tz: f64) -> Option<Self> {
         let (y, m, d) = julian_day_to_proleptic_gregorian(date.midnight_jd(tz));
-        Calendar::from_ymd(y, m, d as u8).unwrap()
+        Calendar::from_ymd(y, m, d as u8)
+    }
+
+    /// The day of the year, starting from 1.
+    pub fn day_of_year(&self) -> u16 {
+        self.ord_in_year()
+    }
+
+    /// The number of days after this one in the year.
+    pub fn days_remaining_in_year(&self) -> u16 {
+        self.the_year().num_days() as u16 - self.ord_in_year()
+    }
+
+    /// The week of the month containing the day, counting from 1, where weeks
+    /// begin on the given weekday and the first week contains the first day of
+    /// the month.
+    pub fn week_of_month(&self, first_weekday: Weekday) -> u8 {
+        let offset = (self.month.first_day().weekday() as u8 + 7 - first_weekday as u8) % 7;
+        (self.day + offset) / 7 + 1
+    }
+
+    /// The occurrence of the day's weekday in the month, counting from 1,
+    /// e.g. 2 for the second Sunday.
+    pub fn weekday_occurrence(&self) -> u8 {
+        self.day / 7 + 1
+    }
+
+    /// The occurrence of the day's weekday in the month counting from the end,
+    /// e.g. 1 for the last Sunday.
+    pub fn weekday_occurrence_from_end(&self) -> u8 {
+        (self.month.num_days() as u8 - 1 - self.day) / 7 + 1
+    }
+
+    /// The day `n` months later, or earlier if `n` is negative, falling on the
+    /// last day of the month if the month is shorter.
+    pub fn add_months(&self, n: i32) -> Option<Self> {
+        let months = self.the_month().ord() as i32 - 1 + n;
+        let month = Calendar::from_ym(
+            self.the_year().ord() + months.div_euclid(12),
+            months.rem_euclid(12) as u8 + 1,
+        )?;
+        Some(month.day(self.ord()).unwrap_or(month.last_day()))
+    }
+
+    /// The whole years, months and days from this day to `end`, or `None` if
+    /// `end` is earlier.
+    pub fn years_months_days_until(&self, end: Self) -> Option<(i32, u8, u8)> {
+        if Date::from(end) < Date::from(*self) {
+            return None;
+        }
+        let mut months = (end.the_year().ord() - self.the_year().ord()) * 12
+            + end.the_month().ord() as i32
+            - self.the_month().ord() as i32;
+        if end.ord() < self.ord() {
+            months -= 1;
+        }
+        let mut anchor = self.add_months(months)?;
+        if Date::from(anchor) > Date::from(end) {
+            // The anchor may be clamped past the end, e.g. from Jan 31.
+            months -= 1;
+            anchor = self.add_months(months)?;
+        }
+        let days = Date::from(end) - Date::from(anchor);
+        Some((months / 12, (months % 12) as u8, days as u8))
     }
 }
 
@@ -396,6 +623,11 @@ impl calendar::Day<Calendar> for Day {
         self.day + 1
     }
 
+    fn ord_in_year(&self) -> u16 {
+        DAYS_BEFORE_MONTH[self.the_year().is_leap() as usize][self.month.month as usize]
+            + self.ord() as u16
+    }
+
     fn succ(&self) -> Self {
         if self.day == self.month.num_days() as u8 - 1 {
             self.month.succ().first_day()
@@ -437,6 +669,49 @@ impl From<Date> for Day {
     }
 }
 
+/// The error returned when a (year, month, day) is not a valid Gregorian day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidDayError {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl std::fmt::Display for InvalidDayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid Gregorian date: {}-{}-{}",
+            self.year, self.month, self.day
+        )
+    }
+}
+
+impl std::error::Error for InvalidDayError {}
+
+impl TryFrom<(i32, u8, u8)> for Day {
+    type Error = InvalidDayError;
+
+    fn try_from((year, month, day): (i32, u8, u8)) -> Result<Self, Self::Error> {
+        Calendar::from_ymd(year, month, day).ok_or(InvalidDayError { year, month, day })
+    }
+}
+
+impl From<Day> for (i32, u8, u8) {
+    fn from(day: Day) -> Self {
+        (day.the_year().ord(), day.the_month().ord(), day.ord())
+    }
+}
+
+#[test]
+fn test_tuple() {
+    let day = Day::try_from((2024, 2, 29)).unwrap();
+    assert_eq!(day, Calendar::from_ymd(2024, 2, 29).unwrap());
+    assert_eq!(<(i32, u8, u8)>::from(day), (2024, 2, 29));
+    let error = Day::try_from((2023, 2, 29)).unwrap_err();
+    assert_eq!(error.to_string(), "invalid Gregorian date: 2023-2-29");
+}
+
 #[test]
 fn test_day() {
     assert_eq!(Calendar::from_ymd(-4713, 11, 24).unwrap().jdn(), 0);
@@ -449,6 +724,66 @@ fn test_day() {
     assert_ne!(Calendar::from_ymd(2024, 2, 29), None);
     assert_eq!(Calendar::from_ymd(2022, 2, 29), None);
 
+    // 2024-05-01 is a Wednesday.
+    let mothers_day = Calendar::from_ymd(2024, 5, 12).unwrap();
+    assert_eq!(mothers_day.weekday(), Sunday);
+    assert_eq!(mothers_day.weekday_occurrence(), 2);
+    assert_eq!(mothers_day.weekday_occurrence_from_end(), 3);
+    assert_eq!(mothers_day.week_of_month(Sunday), 3);
+    assert_eq!(mothers_day.week_of_month(Monday), 2);
+    let may = mothers_day.the_month();
+    assert_eq!(may.nth_weekday(2, Sunday), Some(mothers_day));
+    assert_eq!(may.nth_weekday(1, Wednesday), may.day(1));
+    assert_eq!(may.nth_weekday(5, Friday), may.day(31));
+    assert_eq!(may.nth_weekday(5, Saturday), None);
+    assert_eq!(may.nth_weekday(0, Saturday), None);
+    assert_eq!(may.last_weekday(Friday), may.day(31).unwrap());
+    assert_eq!(may.last_weekday(Sunday), may.day(26).unwrap());
+    assert_eq!(may.day(1).unwrap().week_of_month(Sunday), 1);
+    assert_eq!(may.day(31).unwrap().week_of_month(Sunday), 5);
+    assert_eq!(may.day(31).unwrap().weekday_occurrence_from_end(), 1);
+
+    for (y, m, d, day_of_year, days_remaining) in [
+        (2023, 1, 1, 1, 364),
+        (2023, 3, 1, 60, 305),
+        (2023, 12, 31, 365, 0),
+        (2024, 2, 29, 60, 306),
+        (2024, 3, 1, 61, 305),
+        (2024, 12, 31, 366, 0),
+        (1900, 3, 1, 60, 305),
+        (2000, 3, 1, 61, 305),
+    ] {
+        let day = Calendar::from_ymd(y, m, d).unwrap();
+        assert_eq!(day.day_of_year(), day_of_year);
+        assert_eq!(day.days_remaining_in_year(), days_remaining);
+        assert_eq!(Date::from(day).ordinal_in_gregorian_year(), day_of_year);
+    }
+    // A date is already a civil day, so the midnight of a positive offset,
+    // which falls on the day before in UTC, does not move it.
+    for (m, d, day_of_year) in [(1, 1, 1), (3, 1, 61)] {
+        let date = Date::from(Calendar::from_ymd(2024, m, d).unwrap());
+        assert_eq!(date.ordinal_in_gregorian_year(), day_of_year);
+        assert_eq!(GregorianDay::from(date).day_of_year(), day_of_year);
+    }
+
+    let ymd = |y, m, d| Calendar::from_ymd(y, m, d).unwrap();
+    assert_eq!(ymd(2024, 1, 31).add_months(1), Some(ymd(2024, 2, 29)));
+    assert_eq!(ymd(2024, 3, 31).add_months(-13), Some(ymd(2023, 2, 28)));
+    assert_eq!(ymd(2024, 12, 15).add_months(2), Some(ymd(2025, 2, 15)));
+    for (start, end, expected) in [
+        (ymd(2024, 2, 10), ymd(2025, 1, 29), (0, 11, 19)),
+        (ymd(2024, 1, 31), ymd(2024, 3, 1), (0, 1, 1)),
+        (ymd(2024, 2, 29), ymd(2025, 2, 28), (0, 11, 30)),
+        (ymd(2024, 2, 29), ymd(2028, 2, 29), (4, 0, 0)),
+        (ymd(2023, 5, 15), ymd(2023, 5, 15), (0, 0, 0)),
+    ] {
+        assert_eq!(start.years_months_days_until(end), Some(expected));
+    }
+    assert_eq!(
+        ymd(2024, 1, 2).years_months_days_until(ymd(2024, 1, 1)),
+        None
+    );
+
     let last_day_of_2023 = Calendar::from_ymd(2023, 12, 31).unwrap();
     let first_day_of_2024 = Calendar::from_ymd(2024, 1, 1).unwrap();
     assert_eq!(last_day_of_2023.succ(), first_day_of_2024);
@@ -458,9 +793,15 @@ fn test_day() {
 impl std::fmt::Display for Year {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if f.alternate() {
-            write!(f, "{}年", self.ord())
+            match self.era() {
+                (Era::Ce, year) => write!(f, "{}年", year),
+                (era, year) => write!(f, "{}{}年", era.chinese(), year),
+            }
         } else if f.sign_minus() {
-            write!(f, "{}", self.ord())
+            match self.era() {
+                (Era::Ce, year) => write!(f, "{}", year),
+                (era, year) => write!(f, "{} {}", year, era),
+            }
         } else {
             write!(f, "{:04}", self.ord())
         }
@@ -490,3 +831,28 @@ impl std::fmt::Display for Day {
         }
     }
 }
+
+#[test]
+fn test_era() {
+    let year = Calendar::from_y(-44).unwrap();
+    assert_eq!(year.era(), (Era::Bce, 45));
+    assert_eq!(Calendar::from_era_year(Era::Bce, 45), Some(year));
+    assert_eq!(Calendar::from_y(0).unwrap().era(), (Era::Bce, 1));
+    assert_eq!(Calendar::from_y(1).unwrap().era(), (Era::Ce, 1));
+    assert_eq!(
+        Calendar::from_era_year(Era::Ce, 2024),
+        Calendar::from_y(2024)
+    );
+    assert_eq!(Calendar::from_era_year(Era::Ce, 0), None);
+    assert_eq!("BC".parse(), Ok(Era::Bce));
+
+    assert_eq!(format!("{:-}", year), "45 BCE");
+    assert_eq!(format!("{:#}", year), "公元前45年");
+    assert_eq!(year.to_string(), "-044");
+    let day = Calendar::from_ymd(-44, 3, 15).unwrap();
+    assert_eq!(format!("{:-}", day), "15 March 45 BCE");
+    assert_eq!(format!("{:#}", day), "公元前45年3月15日");
+    let day = Calendar::from_ymd(2024, 2, 10).unwrap();
+    assert_eq!(format!("{:-}", day), "10 February 2024");
+    assert_eq!(format!("{:#}", day), "2024年2月10日");
+}
@@ -0,0 +1,177 @@
+//! ISO 8601 representations of dates in the extended format: calendar dates
+//! ("2024-02-10"), ordinal dates ("2024-041") and week dates ("2024-W06-6").
+//!
+//! Years from 0 to 9999 are written with four digits; other years are written
+//! with a sign and at least five digits, e.g. "-00044-03-15".
+
+use super::{Calendar, Day};
+use crate::calendar::{Calendar as _, Day as _, Month as _, Year as _};
+use crate::*;
+
+/// The error returned when a string is not a valid ISO 8601 date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseIso8601Error;
+
+impl std::fmt::Display for ParseIso8601Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid ISO 8601 date")
+    }
+}
+
+impl std::error::Error for ParseIso8601Error {}
+
+fn format_year(year: i32) -> String {
+    if (0..=9999).contains(&year) {
+        format!("{:04}", year)
+    } else {
+        format!("{:+06}", year)
+    }
+}
+
+/// Splits the year off the rest of the string, which begins with '-'.
+fn parse_year(s: &str) -> Option<(i32, &str)> {
+    let (sign, unsigned) = match s.strip_prefix(['+', '-']) {
+        Some(rest) => (&s[..1], rest),
+        None => ("", s),
+    };
+    let len = unsigned.find(|c: char| !c.is_ascii_digit())?;
+    if sign.is_empty() && len != 4 || !sign.is_empty() && len < 5 {
+        return None;
+    }
+    let year = format!("{}{}", sign, &unsigned[..len]).parse().ok()?;
+    Some((year, &unsigned[len..]))
+}
+
+fn parse_number<T: std::str::FromStr>(s: &str, len: usize) -> Option<T> {
+    if s.len() == len && s.bytes().all(|b| b.is_ascii_digit()) {
+        s.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// The Monday of the first week of the given week-numbering year, i.e. the
+/// week containing January 4.
+fn first_monday(year: i32) -> Option<Date> {
+    let jan4 = Calendar::from_ymd(year, 1, 4)?;
+    Some(Date::from(jan4) + -(jan4.weekday().ord() as i32 - 1))
+}
+
+impl Day {
+    /// The ISO week-numbering year, the week of the year and the weekday.
+    pub fn iso_week(&self) -> (i32, u8, Weekday) {
+        let weekday = self.weekday();
+        // The week belongs to the year in which its Thursday falls.
+        let thursday = Day::from(Date::from(*self) + (4 - weekday.ord() as i32));
+        (
+            thursday.the_year().ord(),
+            ((thursday.ord_in_year() - 1) / 7 + 1) as u8,
+            weekday,
+        )
+    }
+
+    /// The calendar date, e.g. "2024-02-10".
+    pub fn to_iso8601(&self) -> String {
+        format!(
+            "{}-{:02}-{:02}",
+            format_year(self.the_year().ord()),
+            self.the_month().ord(),
+            self.ord()
+        )
+    }
+
+    /// The ordinal date, e.g. "2024-041".
+    pub fn to_iso8601_ordinal(&self) -> String {
+        format!(
+            "{}-{:03}",
+            format_year(self.the_year().ord()),
+            self.ord_in_year()
+        )
+    }
+
+    /// The week date, e.g. "2024-W06-6".
+    pub fn to_iso8601_week(&self) -> String {
+        let (year, week, weekday) = self.iso_week();
+        format!("{}-W{:02}-{}", format_year(year), week, weekday.ord())
+    }
+
+    /// Parses a calendar, ordinal or week date in the extended format.
+    pub fn from_iso8601(s: &str) -> Result<Self, ParseIso8601Error> {
+        Self::parse_iso8601(s).ok_or(ParseIso8601Error)
+    }
+
+    fn parse_iso8601(s: &str) -> Option<Self> {
+        let (year, rest) = parse_year(s)?;
+        let rest = rest.strip_prefix('-')?;
+        if let Some(rest) = rest.strip_prefix('W') {
+            let (week, weekday) = rest.split_once('-')?;
+            let week: i32 = parse_number(week, 2)?;
+            let weekday: i32 = parse_number(weekday, 1)?;
+            if !(1..=7).contains(&weekday) {
+                return None;
+            }
+            let date = first_monday(year)? + (week - 1) * 7 + (weekday - 1);
+            let day = Day::from(date);
+            (week >= 1 && day.iso_week().0 == year).then_some(day)
+        } else if let Some((month, day)) = rest.split_once('-') {
+            Calendar::from_ymd(year, parse_number(month, 2)?, parse_number(day, 2)?)
+        } else {
+            Calendar::from_y(year)?.day(parse_number(rest, 3)?)
+        }
+    }
+}
+
+impl std::str::FromStr for Day {
+    type Err = ParseIso8601Error;
+
+    /// Parses an ISO 8601 date, as [`Day::from_iso8601`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_iso8601(s)
+    }
+}
+
+#[test]
+fn test_iso8601() {
+    let day = Calendar::from_ymd(2024, 2, 10).unwrap();
+    assert_eq!(day.to_iso8601(), "2024-02-10");
+    assert_eq!(day.to_iso8601_ordinal(), "2024-041");
+    assert_eq!(day.to_iso8601_week(), "2024-W06-6");
+
+    // Week years differing from calendar years.
+    let day = Calendar::from_ymd(2008, 12, 29).unwrap();
+    assert_eq!(day.to_iso8601_week(), "2009-W01-1");
+    let day = Calendar::from_ymd(2010, 1, 3).unwrap();
+    assert_eq!(day.to_iso8601_week(), "2009-W53-7");
+    assert_eq!(Day::from_iso8601("2009-W53-7"), Ok(day));
+    assert_eq!(Day::from_iso8601("2010-W53-1"), Err(ParseIso8601Error));
+
+    let day = Calendar::from_ymd(-44, 3, 15).unwrap();
+    assert_eq!(day.to_iso8601(), "-00044-03-15");
+    assert_eq!(Day::from_iso8601("-00044-03-15"), Ok(day));
+    assert_eq!("-00044-03-15".parse(), Ok(day));
+    let day = Calendar::from_ymd(12345, 12, 31).unwrap();
+    assert_eq!(day.to_iso8601_ordinal(), "+12345-365");
+    assert_eq!(Day::from_iso8601("+12345-365"), Ok(day));
+
+    for s in [
+        "2024-2-10",
+        "24-02-10",
+        "2024-02-30",
+        "2023-366",
+        "2024-W00-1",
+        "2024-W01-8",
+        "-0044-03-15",
+        "2024/02/10",
+        "2024-02-10T00:00",
+    ] {
+        assert_eq!(Day::from_iso8601(s), Err(ParseIso8601Error), "{}", s);
+    }
+
+    let mut day = Calendar::from_ymd(1995, 1, 1).unwrap();
+    while day.the_year().ord() <= 2030 {
+        assert_eq!(Day::from_iso8601(&day.to_iso8601()), Ok(day));
+        assert_eq!(Day::from_iso8601(&day.to_iso8601_ordinal()), Ok(day));
+        assert_eq!(Day::from_iso8601(&day.to_iso8601_week()), Ok(day));
+        day = day.succ();
+    }
+}
@@ -0,0 +1,313 @@
+//! Public holidays of different regions.
+
+use strum::{Display, EnumString, VariantArray};
+
+use crate::calendar::{Calendar as _, Day as _};
+use crate::*;
+
+/// A holiday falling on a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Holiday {
+    pub name: &'static str,
+    /// The day of the holiday itself.
+    pub date: Date,
+    /// The day off in lieu of the holiday, which differs from `date` when
+    /// the holiday falls on a weekend in some regions.
+    pub observed: Date,
+}
+
+impl Holiday {
+    fn new(name: &'static str, date: Date) -> Self {
+        Self {
+            name,
+            date,
+            observed: date,
+        }
+    }
+
+    /// A holiday observed on the Friday before if it falls on a Saturday, and
+    /// on the Monday after if it falls on a Sunday.
+    fn nearest_weekday(name: &'static str, date: Date) -> Self {
+        let observed = match date.weekday() {
+            Saturday => date + -1,
+            Sunday => date + 1,
+            _ => date,
+        };
+        Self {
+            name,
+            date,
+            observed,
+        }
+    }
+}
+
+/// A source of the holidays of a year.
+pub trait HolidayProvider {
+    /// The holidays of the given Gregorian year, in order, or none for a
+    /// year the provider cannot compute, e.g. outside the Chinese calendar.
+    fn holidays(&self, year: i32) -> Vec<Holiday>;
+
+    /// The holidays falling on or observed on the given date.
+    fn holidays_on(&self, date: Date) -> Vec<Holiday> {
+        let year = GregorianDay::from(date).the_year().ord();
+        // A holiday may be observed in the year before or after.
+        let years = gregorian::SUPPORTED_YEARS;
+        ((year - 1).max(*years.start())..=(year + 1).min(*years.end()))
+            .flat_map(|year| self.holidays(year))
+            .filter(|holiday| holiday.date == date || holiday.observed == date)
+            .collect()
+    }
+
+    /// Whether the given date is a day off.
+    fn is_day_off(&self, date: Date) -> bool {
+        self.holidays_on(date)
+            .iter()
+            .any(|holiday| holiday.observed == date)
+    }
+}
+
+/// The regions whose public holidays are built in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VariantArray, Display, EnumString)]
+#[strum(ascii_case_insensitive)]
+pub enum Region {
+    /// The public holidays of mainland China, without the adjusted working
+    /// days (调休) announced every year.
+    #[cfg(feature = "astronomy")]
+    #[strum(to_string = "cn")]
+    China,
+    /// The federal holidays of the United States.
+    #[strum(to_string = "us")]
+    UnitedStates,
+    /// The nationwide public holidays of Germany.
+    #[strum(to_string = "de")]
+    Germany,
+    /// The public holidays of France.
+    #[strum(to_string = "fr")]
+    France,
+}
+
+fn ymd(year: i32, month: u8, day: u8) -> Date {
+    GregorianCalendar::from_ymd(year, month, day)
+        .unwrap()
+        .into()
+}
+
+fn nth_weekday(year: i32, month: u8, n: u8, weekday: Weekday) -> Date {
+    let month = GregorianCalendar::from_ym(year, month).unwrap();
+    month.nth_weekday(n, weekday).unwrap().into()
+}
+
+fn last_weekday(year: i32, month: u8, weekday: Weekday) -> Date {
+    let month = GregorianCalendar::from_ym(year, month).unwrap();
+    month.last_weekday(weekday).into()
+}
+
+fn easter(year: i32) -> Date {
+    GregorianCalendar::from_y(year).unwrap().easter().into()
+}
+
+/// The day of a Chinese festival of the Chinese year starting in the given
+/// Gregorian year.
+#[cfg(feature = "astronomy")]
+fn chinese_festival(year: i32, festival: ChineseFestival) -> Date {
+    festival.date(year).unwrap()
+}
+
+#[cfg(feature = "astronomy")]
+fn china_holidays(year: i32) -> Vec<Holiday> {
+    if !chinese::SUPPORTED_YEARS.contains(&year) {
+        return Vec::new();
+    }
+    let mut holidays = vec![Holiday::new("New Year's Day", ymd(year, 1, 1))];
+    let spring_festival = chinese_festival(year, ChineseFestival::SpringFestival);
+    // The New Year's Eve has been a holiday again since 2025.
+    let first_day = if year >= 2025 { -1 } else { 0 };
+    holidays.extend((first_day..3).map(|i| Holiday::new("Spring Festival", spring_festival + i)));
+    holidays.push(Holiday::new(
+        "Qingming Festival",
+        chinese_festival(year, ChineseFestival::QingmingFestival),
+    ));
+    // The Labour Day has had two days since 2025.
+    let labour_days = if year >= 2025 { 2 } else { 1 };
+    holidays.extend((0..labour_days).map(|i| Holiday::new("Labour Day", ymd(year, 5, 1) + i)));
+    holidays.extend([
+        Holiday::new(
+            "Dragon Boat Festival",
+            chinese_festival(year, ChineseFestival::DragonBoatFestival),
+        ),
+        Holiday::new(
+            "Mid-Autumn Festival",
+            chinese_festival(year, ChineseFestival::MidAutumnFestival),
+        ),
+    ]);
+    holidays.extend((0..3).map(|i| Holiday::new("National Day", ymd(year, 10, 1) + i)));
+    holidays.sort_by_key(|holiday| holiday.date);
+    holidays
+}
+
+fn us_holidays(year: i32) -> Vec<Holiday> {
+    let mut holidays = vec![Holiday::nearest_weekday("New Year's Day", ymd(year, 1, 1))];
+    if year >= 1986 {
+        holidays.push(Holiday::new(
+            "Martin Luther King Jr. Day",
+            nth_weekday(year, 1, 3, Monday),
+        ));
+    }
+    holidays.extend([
+        Holiday::new("Washington's Birthday", nth_weekday(year, 2, 3, Monday)),
+        Holiday::new("Memorial Day", last_weekday(year, 5, Monday)),
+    ]);
+    if year >= 2021 {
+        holidays.push(Holiday::nearest_weekday(
+            "Juneteenth National Independence Day",
+            ymd(year, 6, 19),
+        ));
+    }
+    holidays.extend([
+        Holiday::nearest_weekday("Independence Day", ymd(year, 7, 4)),
+        Holiday::new("Labor Day", nth_weekday(year, 9, 1, Monday)),
+        Holiday::new("Columbus Day", nth_weekday(year, 10, 2, Monday)),
+        Holiday::nearest_weekday("Veterans Day", ymd(year, 11, 11)),
+        Holiday::new("Thanksgiving Day", nth_weekday(year, 11, 4, Thursday)),
+        Holiday::nearest_weekday("Christmas Day", ymd(year, 12, 25)),
+    ]);
+    holidays
+}
+
+fn germany_holidays(year: i32) -> Vec<Holiday> {
+    let easter = easter(year);
+    let mut holidays = vec![
+        Holiday::new("New Year's Day", ymd(year, 1, 1)),
+        Holiday::new("Good Friday", easter + -2),
+        Holiday::new("Easter Monday", easter + 1),
+        Holiday::new("Labour Day", ymd(year, 5, 1)),
+        Holiday::new("Ascension Day", easter + 39),
+        Holiday::new("Whit Monday", easter + 50),
+    ];
+    if year >= 1990 {
+        holidays.push(Holiday::new("German Unity Day", ymd(year, 10, 3)));
+    }
+    holidays.extend([
+        Holiday::new("Christmas Day", ymd(year, 12, 25)),
+        Holiday::new("Boxing Day", ymd(year, 12, 26)),
+    ]);
+    holidays.sort_by_key(|holiday| holiday.date);
+    holidays
+}
+
+fn france_holidays(year: i32) -> Vec<Holiday> {
+    let easter = easter(year);
+    let mut holidays = vec![
+        Holiday::new("New Year's Day", ymd(year, 1, 1)),
+        Holiday::new("Easter Monday", easter + 1),
+        Holiday::new("Labour Day", ymd(year, 5, 1)),
+        Holiday::new("Victory in Europe Day", ymd(year, 5, 8)),
+        Holiday::new("Ascension Day", easter + 39),
+        Holiday::new("Whit Monday", easter + 50),
+        Holiday::new("Bastille Day", ymd(year, 7, 14)),
+        Holiday::new("Assumption Day", ymd(year, 8, 15)),
+        Holiday::new("All Saints' Day", ymd(year, 11, 1)),
+        Holiday::new("Armistice Day", ymd(year, 11, 11)),
+        Holiday::new("Christmas Day", ymd(year, 12, 25)),
+    ];
+    holidays.sort_by_key(|holiday| holiday.date);
+    holidays
+}
+
+impl HolidayProvider for Region {
+    fn holidays(&self, year: i32) -> Vec<Holiday> {
+        match self {
+            #[cfg(feature = "astronomy")]
+            Region::China => china_holidays(year),
+            Region::UnitedStates => us_holidays(year),
+            Region::Germany => germany_holidays(year),
+            Region::France => france_holidays(year),
+        }
+    }
+}
+
+#[test]
+fn test_holidays() {
+    let holidays = Region::UnitedStates.holidays(2024);
+    assert_eq!(holidays.len(), 11);
+    let find = |name| holidays.iter().find(|h| h.name == name).unwrap();
+    assert_eq!(find("Martin Luther King Jr. Day").date, ymd(2024, 1, 15));
+    assert_eq!(find("Memorial Day").date, ymd(2024, 5, 27));
+    assert_eq!(find("Thanksgiving Day").date, ymd(2024, 11, 28));
+    // Juneteenth of 2022 fell on a Sunday, and Christmas of 2021 on a
+    // Saturday.
+    let holiday = Region::UnitedStates.holidays(2022)[4];
+    assert_eq!(holiday.date, ymd(2022, 6, 19));
+    assert_eq!(holiday.observed, ymd(2022, 6, 20));
+    assert!(Region::UnitedStates.is_day_off(ymd(2021, 12, 24)));
+    assert!(!Region::UnitedStates.is_day_off(ymd(2021, 12, 25)));
+    // The New Year's Day of 2022 was observed in 2021.
+    assert_eq!(
+        Region::UnitedStates.holidays_on(ymd(2021, 12, 31))[0].name,
+        "New Year's Day"
+    );
+    assert_eq!(Region::UnitedStates.holidays(2020).len(), 10);
+
+    let holidays = Region::Germany.holidays(2024);
+    assert_eq!(holidays.len(), 9);
+    assert_eq!(holidays[1].name, "Good Friday");
+    assert_eq!(holidays[1].date, ymd(2024, 3, 29));
+    assert!(Region::Germany.is_day_off(ymd(2024, 10, 3)));
+    assert!(holidays.windows(2).all(|w| w[0].date < w[1].date));
+
+    let holidays = Region::France.holidays(2024);
+    assert_eq!(holidays.len(), 11);
+    // Ascension Day fell the day after Victory in Europe Day in 2024.
+    assert_eq!(Region::France.holidays_on(ymd(2024, 5, 9)).len(), 1);
+    assert_eq!(
+        Region::France.holidays_on(ymd(2024, 5, 20))[0].name,
+        "Whit Monday"
+    );
+
+    let last = *gregorian::SUPPORTED_YEARS.end();
+    assert_eq!(
+        Region::Germany.holidays_on(ymd(last, 12, 25))[0].name,
+        "Christmas Day"
+    );
+
+    assert_eq!("US".parse(), Ok(Region::UnitedStates));
+    assert_eq!(Region::France.to_string(), "fr");
+}
+
+#[cfg(feature = "astronomy")]
+#[test]
+fn test_china_holidays() {
+    let holidays = Region::China.holidays(2024);
+    assert_eq!(holidays.len(), 11);
+    assert_eq!(holidays[1].name, "Spring Festival");
+    assert_eq!(holidays[1].date, ymd(2024, 2, 10));
+    assert_eq!(holidays[4].name, "Qingming Festival");
+    assert_eq!(holidays[4].date, ymd(2024, 4, 4));
+    assert!(Region::China.is_day_off(ymd(2024, 6, 10)));
+    assert!(Region::China.is_day_off(ymd(2024, 9, 17)));
+    assert!(Region::China.is_day_off(ymd(2024, 10, 3)));
+    assert!(!Region::China.is_day_off(ymd(2024, 2, 9)));
+    // The New Year's Eve and the second day of the Labour Day since 2025.
+    let holidays = Region::China.holidays(2025);
+    assert_eq!(holidays.len(), 13);
+    assert!(Region::China.is_day_off(ymd(2025, 1, 28)));
+    assert!(Region::China.is_day_off(ymd(2025, 5, 2)));
+    assert!(holidays.windows(2).all(|w| w[0].date < w[1].date));
+    assert_eq!("cn".parse(), Ok(Region::China));
+
+    // The years next to the ends of the Chinese calendar have no holidays.
+    let (first, last) = (
+        *chinese::SUPPORTED_YEARS.start(),
+        *chinese::SUPPORTED_YEARS.end(),
+    );
+    assert!(Region::China.holidays(first - 1).is_empty());
+    assert!(Region::China.holidays(last + 1).is_empty());
+    assert_eq!(
+        Region::China.holidays_on(ymd(first, 1, 1))[0].name,
+        "New Year's Day"
+    );
+    assert_eq!(
+        Region::China.holidays_on(ymd(last, 10, 1))[0].name,
+        "National Day"
+    );
+}
@@ -8,31 +8,125 @@
 //!
 //! * [`GregorianCalendar`]: [(Proleptic) Gregorian calendar](https://en.wikipedia.org/wiki/Proleptic_Gregorian_calendar)
 //! * [`ChineseCalendar`]: [Chinese calendar](https://en.wikipedia.org/wiki/Chinese_calendar)
+//! * [`regnal`]: Era years of China, Japan, Taiwan and North Korea
+//!
+//! The calendars can also be selected by name at runtime with [`registry`],
+//! and their days written as sortable string codes with [`code`].
+//! The timezone, locale and other settings can be kept in a [`Context`],
+//! passed explicitly or set as a default.
+//!
+//! Optional features:
+//!
+//! * `astronomy` (default): The ephemeris of the Sun and the Moon, and what is
+//!   computed from it: [`ChineseCalendar`], [`astronomy`] and [`lunisolar`].
+//!   Without it, e.g. with `default-features = false`, the crate has only the
+//!   arithmetic calendars and depends on no astronomical library.
+//! * `chinese-table`: Look up the Chinese calendar of 1900–2100 in
+//!   [`Mode::Deterministic`](chinese::Mode::Deterministic) in the table of
+//!   `gen-tables`, falling back to the computation outside that range.
+//! * `gen-tables`: Const tables of the Gregorian calendar, and of the Chinese
+//!   calendar and the solar terms of 1900–2100 generated at build time, for
+//!   const contexts and consumers without floating-point arithmetic, see
+//!   [`tables`].
+//! * `chinese-era`: Map Chinese years to era names (年号), see [`chinese::era`].
+//! * `wasm`: JavaScript bindings for wasm32-unknown-unknown, see [`wasm`].
+//! * `proptest`, `arbitrary`: Random dates for property-based tests, see
+//!   [`testing`].
+//! * `python`: A Python module `omnical` with the classes `Date`,
+//!   `GregorianDay` and `ChineseDay` and functions of lunar phases and solar
+//!   terms.
+//! * `bincode`, `rkyv`: Binary encodings of [`Date`] and the days of the
+//!   Gregorian and Chinese calendars, including the computed data of Chinese
+//!   years, so they are decoded without the ephemeris. Decoded values are not
+//!   validated, so decode only data encoded by this crate.
+//! * `serde`, `schemars`: [`Date`] as its Julian day number, and the days of
+//!   the Gregorian and Chinese calendars as ISO 8601 dates and codes, in serde
+//!   and JSON Schema, see [`serialize`].
+//! * `sqlx`, `diesel`: [`Date`] as SQL `DATE` and [`ChineseDay`] as text in
+//!   PostgreSQL and SQLite, see [`sql`].
+//! * `rayon`: Chinese years, solar terms and lunar phases of ranges of years
+//!   computed in parallel, see [`bulk`].
+//! * `tracing`: Spans of [`tracing`](https://docs.rs/tracing) around the
+//!   computation of Chinese years and the searches of solar terms and lunar
+//!   phases, and events for the years found in the caches. The evaluations of
+//!   the ephemeris are counted with or without it, see
+//!   [`astronomy::ephemeris_counters`].
 
+#[cfg(feature = "astronomy")]
 pub mod astronomy;
+#[cfg(feature = "rayon")]
+pub mod bulk;
+#[cfg(feature = "astronomy")]
+mod cache;
 pub mod calendar;
+#[cfg(feature = "astronomy")]
 pub mod chinese;
+pub mod code;
+pub mod context;
 pub mod date;
+#[cfg(feature = "astronomy")]
+pub mod format;
 pub mod gregorian;
+pub mod holiday;
+pub mod locale;
+#[cfg(feature = "astronomy")]
+pub mod lunisolar;
+pub mod metadata;
+mod numeral;
+#[cfg(feature = "python")]
+mod python;
+pub mod registry;
+pub mod regnal;
+pub mod script;
+#[cfg(feature = "serde")]
+pub mod serialize;
+#[cfg(any(feature = "sqlx", feature = "diesel"))]
+pub mod sql;
+#[cfg(feature = "gen-tables")]
+pub mod tables;
+pub mod testing;
+pub mod timescale;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
-pub use astronomy::{LunarPhase, LunarPhase::*, SolarTerm, SolarTerm::*};
-pub use calendar::{Calendar, Day, Month, Year};
+#[cfg(feature = "astronomy")]
+pub use astronomy::{
+    Hemisphere, LunarPhase, LunarPhase::*, Lunation, Season, Season::*, SeasonConvention,
+    SolarTerm, SolarTerm::*, ZodiacMethod, ZodiacSign, ZodiacSign::*,
+};
+pub use calendar::{Calendar, CalendarInfo, DateLike, Day, Month, Year};
+#[cfg(feature = "astronomy")]
+pub use chinese::festivals::Festival as ChineseFestival;
+#[cfg(feature = "astronomy")]
 pub use chinese::{
-    Branch, Calendar as ChineseCalendar, Day as ChineseDay, Month as ChineseMonth, Stem,
-    StemBranch, Year as ChineseYear,
+    Branch, Calendar as ChineseCalendar, DateTime as ChineseDateTime, Day as ChineseDay,
+    LunarMansion, Month as ChineseMonth, SolarMonth, Stem, StemBranch, Year as ChineseYear,
 };
+pub use context::Context;
 pub use date::{Date, Weekday, Weekday::*};
+#[cfg(feature = "astronomy")]
+pub use format::CombinedFormat;
 pub use gregorian::{
-    Calendar as GregorianCalendar, Day as GregorianDay, Month as GregorianMonth, MonthName,
-    MonthName::*, Year as GregorianYear,
+    Calendar as GregorianCalendar, Day as GregorianDay, Era as GregorianEra,
+    Month as GregorianMonth, MonthName, MonthName::*, Year as GregorianYear,
 };
+pub use locale::Locale;
+pub use script::Script;
 
 /// Returns the current Unix time.
+#[cfg(not(all(target_arch = "wasm32", feature = "wasm")))]
 pub fn unix_time_now() -> u64 {
     let now = std::time::SystemTime::now();
     now.duration_since(std::time::UNIX_EPOCH).unwrap().as_secs()
 }
 
+/// Returns the current Unix time, from the clock of JavaScript for there is
+/// no `std::time` in wasm32-unknown-unknown.
+#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+pub fn unix_time_now() -> u64 {
+    (js_sys::Date::now() / 1000.0) as u64
+}
+
 /// Ignores the `None` variant of an `Option` and returns the inner value.
 ///
 /// It is a work-around for unstable feature of `Option::unwrap` in const fn.
@@ -0,0 +1,245 @@
+//! Names of months and weekdays in different languages.
+
+use strum::{Display, EnumCount, EnumString, VariantArray};
+
+use crate::*;
+
+/// A language, identified by its BCP 47 tag.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default, EnumCount, VariantArray, Display, EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+pub enum Locale {
+    #[default]
+    #[strum(to_string = "en")]
+    English,
+    #[strum(to_string = "zh-Hans", serialize = "zh-CN", serialize = "zh")]
+    SimplifiedChinese,
+    #[strum(to_string = "zh-Hant", serialize = "zh-TW", serialize = "zh-HK")]
+    TraditionalChinese,
+    #[strum(to_string = "ja")]
+    Japanese,
+    #[strum(to_string = "ko")]
+    Korean,
+    #[strum(to_string = "de")]
+    German,
+    #[strum(to_string = "fr")]
+    French,
+    #[strum(to_string = "es")]
+    Spanish,
+}
+
+const MONTH_NAMES: [[&str; 12]; Locale::COUNT] = [
+    [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ],
+    [
+        "一月",
+        "二月",
+        "三月",
+        "四月",
+        "五月",
+        "六月",
+        "七月",
+        "八月",
+        "九月",
+        "十月",
+        "十一月",
+        "十二月",
+    ],
+    [
+        "一月",
+        "二月",
+        "三月",
+        "四月",
+        "五月",
+        "六月",
+        "七月",
+        "八月",
+        "九月",
+        "十月",
+        "十一月",
+        "十二月",
+    ],
+    [
+        "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+    ],
+    [
+        "1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월",
+    ],
+    [
+        "Januar",
+        "Februar",
+        "März",
+        "April",
+        "Mai",
+        "Juni",
+        "Juli",
+        "August",
+        "September",
+        "Oktober",
+        "November",
+        "Dezember",
+    ],
+    [
+        "janvier",
+        "février",
+        "mars",
+        "avril",
+        "mai",
+        "juin",
+        "juillet",
+        "août",
+        "septembre",
+        "octobre",
+        "novembre",
+        "décembre",
+    ],
+    [
+        "enero",
+        "febrero",
+        "marzo",
+        "abril",
+        "mayo",
+        "junio",
+        "julio",
+        "agosto",
+        "septiembre",
+        "octubre",
+        "noviembre",
+        "diciembre",
+    ],
+];
+
+const WEEKDAY_NAMES: [[&str; 7]; Locale::COUNT] = [
+    [
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+        "Sunday",
+    ],
+    [
+        "星期一",
+        "星期二",
+        "星期三",
+        "星期四",
+        "星期五",
+        "星期六",
+        "星期日",
+    ],
+    [
+        "星期一",
+        "星期二",
+        "星期三",
+        "星期四",
+        "星期五",
+        "星期六",
+        "星期日",
+    ],
+    [
+        "月曜日",
+        "火曜日",
+        "水曜日",
+        "木曜日",
+        "金曜日",
+        "土曜日",
+        "日曜日",
+    ],
+    [
+        "월요일",
+        "화요일",
+        "수요일",
+        "목요일",
+        "금요일",
+        "토요일",
+        "일요일",
+    ],
+    [
+        "Montag",
+        "Dienstag",
+        "Mittwoch",
+        "Donnerstag",
+        "Freitag",
+        "Samstag",
+        "Sonntag",
+    ],
+    [
+        "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+    ],
+    [
+        "lunes",
+        "martes",
+        "miércoles",
+        "jueves",
+        "viernes",
+        "sábado",
+        "domingo",
+    ],
+];
+
+impl Locale {
+    /// The name of the given month in this language.
+    pub fn month_name(&self, month: MonthName) -> &'static str {
+        MONTH_NAMES[*self as usize][month as usize]
+    }
+
+    /// The name of the given weekday in this language.
+    pub fn weekday_name(&self, weekday: Weekday) -> &'static str {
+        WEEKDAY_NAMES[*self as usize][weekday as usize]
+    }
+}
+
+impl MonthName {
+    /// The name of the month in the given language.
+    pub fn localized(&self, locale: Locale) -> &'static str {
+        locale.month_name(*self)
+    }
+}
+
+impl Weekday {
+    /// The name of the weekday in the given language.
+    pub fn localized(&self, locale: Locale) -> &'static str {
+        locale.weekday_name(*self)
+    }
+}
+
+#[test]
+fn test_locale() {
+    assert_eq!(Locale::default(), Locale::English);
+    assert_eq!("zh-Hant".parse(), Ok(Locale::TraditionalChinese));
+    assert_eq!("zh-cn".parse(), Ok(Locale::SimplifiedChinese));
+    assert_eq!("FR".parse(), Ok(Locale::French));
+    assert!("xx".parse::<Locale>().is_err());
+    assert_eq!(Locale::SimplifiedChinese.to_string(), "zh-Hans");
+
+    for month in MonthName::VARIANTS {
+        assert_eq!(month.localized(Locale::English), month.as_ref());
+    }
+    for weekday in Weekday::VARIANTS {
+        assert_eq!(weekday.localized(Locale::English), weekday.as_ref());
+        assert_eq!(
+            weekday.localized(Locale::SimplifiedChinese),
+            weekday.chinese(3)
+        );
+    }
+    assert_eq!(March.localized(Locale::German), "März");
+    assert_eq!(December.localized(Locale::Korean), "12월");
+    assert_eq!(Sunday.localized(Locale::Japanese), "日曜日");
+    assert_eq!(Wednesday.localized(Locale::Spanish), "miércoles");
+    assert_eq!(Locale::French.month_name(August), "août");
+    assert_eq!(Locale::TraditionalChinese.weekday_name(Monday), "星期一");
+}
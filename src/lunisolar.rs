@@ -0,0 +1,210 @@
+//! A lunisolar calendar engine shared by the Chinese calendar and its
+//! variants.
+//!
+//! Months begin on the days of new moons, the eleventh month always contains
+//! the winter solstice, and when there are thirteen months from one eleventh
+//! month to the next, the first month without a mid-term (中气) is the leap
+//! month (无中置闰).  Where those days fall depends on the meridian they are
+//! reckoned at and on how the new moons and solar terms are computed.
+
+use std::sync::Mutex;
+
+use crate::cache::Cache;
+use crate::chinese::Mode;
+use crate::*;
+
+/// The parameters of a lunisolar calendar.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rules {
+    /// The timezone of the meridian the days are reckoned at, in hours.
+    pub tz: f64,
+    /// How new moons and solar terms are computed.
+    pub mode: Mode,
+}
+
+impl Rules {
+    /// The Chinese calendar, reckoned at 120°E.
+    pub const CHINESE: Self = Self {
+        tz: 8.0,
+        mode: Mode::TrueMoonTrueTerms,
+    };
+    /// The modern Korean calendar, reckoned at 135°E.
+    pub const KOREAN: Self = Self {
+        tz: 9.0,
+        mode: Mode::TrueMoonTrueTerms,
+    };
+    /// The modern Vietnamese calendar, reckoned at 105°E.
+    pub const VIETNAMESE: Self = Self {
+        tz: 7.0,
+        mode: Mode::TrueMoonTrueTerms,
+    };
+
+    fn key(&self) -> (u64, Mode) {
+        (self.tz.to_bits(), self.mode)
+    }
+}
+
+/// The months of a year, or of a suì (岁).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct YearData {
+    /// The first day of the first month.
+    pub first_day: Date,
+    /// The number of days of each month, including the leap month if any.
+    pub num_days_of_months: Vec<u8>,
+    /// The index of the leap month, counting from 0, if any.
+    pub leap_month: Option<usize>,
+}
+
+/// The day of the winter solstice of the given Gregorian year.
+pub fn winter_solstice(year: i32, rules: &Rules) -> Date {
+    let mut d: Date = GregorianCalendar::from_ymd(year, 12, 18).unwrap().into();
+    while rules.mode.solar_terms(d, d.succ(), rules.tz)[0] != Some(WinterSolstice) {
+        d = d.succ();
+    }
+    d
+}
+
+fn prev_new_moon(date: Date, rules: &Rules) -> Date {
+    let mut d = date;
+    while rules.mode.lunar_phases(d, d.succ(), rules.tz)[0] != NewMoon {
+        d = d.pred();
+    }
+    d
+}
+
+/// Computes the suì ending at the winter solstice of the given Gregorian year,
+/// i.e. the months from the eleventh month of the previous year to the one
+/// before the eleventh month of the year.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug"))]
+pub fn calc_sui_data(year: i32, rules: &Rules) -> YearData {
+    let mut data = Vec::new();
+    let last_ws = winter_solstice(year - 1, rules);
+    let next_ws_p1 = winter_solstice(year, rules).succ();
+    let nm_before_last_ws = prev_new_moon(last_ws, rules);
+    let lunar_phases = rules
+        .mode
+        .lunar_phases(nm_before_last_ws, next_ws_p1, rules.tz);
+    let solar_terms = rules
+        .mode
+        .solar_terms(nm_before_last_ws, next_ws_p1, rules.tz);
+    let mut last_nm = None;
+    let mut has_mt = false;
+    for (i, (lp, st)) in lunar_phases.into_iter().zip(solar_terms).enumerate() {
+        let d = nm_before_last_ws + i as i32;
+        if lp == NewMoon || st.is_some() {
+            if lp == NewMoon {
+                if let Some(last_nm) = last_nm {
+                    let num_days = d - last_nm;
+                    data.push((num_days as u8, has_mt));
+                }
+                last_nm = Some(d);
+                has_mt = false;
+            }
+            if let Some(st) = st {
+                if st.is_mid_term() {
+                    has_mt = true;
+                }
+            }
+        }
+    }
+    let is_leap_year = data.len() > 12;
+    let leap_month = if is_leap_year {
+        data.iter().position(|&(_, has_mt)| !has_mt)
+    } else {
+        None
+    };
+    YearData {
+        first_day: nm_before_last_ws,
+        num_days_of_months: data.into_iter().map(|(x, _)| x).collect(),
+        leap_month,
+    }
+}
+
+type SuiDataCacheKey = (i32, u64, Mode);
+
+/// The most suì kept by [`sui_data`], and the most years kept by the Chinese
+/// calendar, so that long-running processes do not grow without limit. The
+/// oldest ones are evicted first.
+pub const YEAR_CACHE_CAPACITY: usize = 1024;
+
+static SUI_DATA_CACHE: Mutex<Cache<SuiDataCacheKey, YearData>> = Mutex::new(Cache::new());
+
+/// Same as [`calc_sui_data`], but memoized across all threads, so that
+/// consecutive years, which share a suì, compute it only once.
+///
+/// At most [`YEAR_CACHE_CAPACITY`] suì are kept.
+pub fn sui_data(year: i32, rules: &Rules) -> YearData {
+    let (tz, mode) = rules.key();
+    if let Some(data) = SUI_DATA_CACHE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(&(year, tz, mode))
+    {
+        #[cfg(feature = "tracing")]
+        tracing::trace!(year, ?rules, "suì found in the cache");
+        return data;
+    }
+    let data = calc_sui_data(year, rules);
+    SUI_DATA_CACHE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert((year, tz, mode), data.clone(), YEAR_CACHE_CAPACITY);
+    data
+}
+
+/// Computes the year beginning with the first month in the given Gregorian
+/// year.
+pub fn calc_year_data(year: i32, rules: &Rules) -> YearData {
+    let sui1 = sui_data(year, rules);
+    let sui2 = sui_data(year + 1, rules);
+    // Skip the eleventh and twelfth months of the previous year, and the leap
+    // month among them if any.
+    let (off1, lm1) = match sui1.leap_month {
+        Some(lm) if lm <= 2 => (3, None),
+        Some(lm) => (2, Some(lm - 2)),
+        None => (2, None),
+    };
+    let (off2, lm2) = match sui2.leap_month {
+        Some(lm) if lm <= 2 => (3, Some(lm + 10)),
+        _ => (2, None),
+    };
+    YearData {
+        first_day: sui1.first_day + sui1.num_days_of_months[..off1].iter().sum::<u8>() as i32,
+        num_days_of_months: [
+            &sui1.num_days_of_months[off1..],
+            &sui2.num_days_of_months[..off2],
+        ]
+        .concat(),
+        leap_month: lm1.or(lm2),
+    }
+}
+
+#[test]
+fn test_lunisolar() {
+    let ymd = |y, m, d| Date::from(GregorianCalendar::from_ymd(y, m, d).unwrap());
+
+    let data = calc_year_data(2023, &Rules::CHINESE);
+    assert_eq!(data.first_day, ymd(2023, 1, 22));
+    assert_eq!(
+        data.num_days_of_months,
+        [29, 30, 29, 29, 30, 30, 29, 30, 30, 29, 30, 29, 30]
+    );
+    assert_eq!(data.leap_month, Some(2));
+    assert_eq!(winter_solstice(2023, &Rules::CHINESE), ymd(2023, 12, 22));
+    assert!(SUI_DATA_CACHE.lock().unwrap().len() <= YEAR_CACHE_CAPACITY);
+
+    // The Vietnamese calendar celebrated the New Year of 1985 a month before
+    // the Chinese one.
+    assert_eq!(
+        calc_year_data(1985, &Rules::CHINESE).first_day,
+        ymd(1985, 2, 20)
+    );
+    assert_eq!(
+        calc_year_data(1985, &Rules::VIETNAMESE).first_day,
+        ymd(1985, 1, 21)
+    );
+    assert_eq!(
+        calc_year_data(2024, &Rules::KOREAN).first_day,
+        ymd(2024, 2, 10)
+    );
+}
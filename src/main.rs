@@ -1,34 +1,194 @@
-use clap::{Args, Parser, Subcommand};
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+
+use clap::builder::{PossibleValue, TypedValueParser};
+use clap::{Args, CommandFactory, Parser, Subcommand, ValueEnum, ValueHint};
 use strum::VariantArray as _;
 
 use omnical::*;
 
+mod config;
+mod events;
+#[cfg(feature = "render")]
+mod render;
+#[cfg(feature = "tui")]
+mod tui;
+
 #[derive(Parser, Debug)]
-#[command(version, about, long_about = None)]
+#[command(
+    version,
+    about,
+    long_about = None,
+    after_long_help = "Exit codes: 1 for a failure to read a file or run a program, 2 for an \
+        invalid argument or input, 3 for a date or year outside the supported range, and 4 \
+        when nothing is found."
+)]
 struct Cli {
     #[command(flatten)]
     args: PrintArgs,
+    /// The timezone, as an offset like "+8" or "-05:30", or a zone name
+    /// without daylight saving time like "Asia/Shanghai"; that of the
+    /// configuration file, the `TZ` environment variable or UTC+8 by default.
+    #[arg(
+        long,
+        global = true,
+        allow_hyphen_values = true,
+        hide_possible_values = true,
+        value_parser = Hinted::new(parse_tz, ZONES.iter().map(|(name, _)| *name))
+    )]
+    tz: Option<f64>,
+    /// When to color the output.
+    #[arg(long, global = true, value_enum, default_value_t = ColorChoice::Auto)]
+    color: ColorChoice,
+    /// A file of events to highlight in the calendar and to show in lists
+    /// and queries.
+    #[arg(long, global = true, value_name = "FILE", value_hint = ValueHint::FilePath)]
+    events: Option<PathBuf>,
+    /// Do not page long calendars and lists in the terminal.
+    #[arg(long, global = true)]
+    no_pager: bool,
     #[command(subcommand)]
     command: Option<Commands>,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum ColorChoice {
+    /// Color the output if it is a terminal and `NO_COLOR` is not set.
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    fn enabled(&self) -> bool {
+        use std::io::IsTerminal as _;
+        match self {
+            ColorChoice::Auto => {
+                std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none()
+            }
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        }
+    }
+}
+
 #[derive(Subcommand, Debug)]
 enum Commands {
     /// Print a calendar.
     Print(PrintArgs),
     /// List the days of a calendar in details.
     List(ListArgs),
-    // TODO: Convert a date from one calendar to another.
+    /// Convert a date from one calendar to others.
+    Convert(ConvertArgs),
+    /// Export computed dates to other formats.
+    #[command(subcommand)]
+    Export(ExportCommands),
     /// Query the information of a date.
     Query(QueryArgs),
+    /// List the public holidays of a year.
+    Holidays(HolidaysArgs),
+    /// Search for the dates of astronomical or calendar events.
+    #[command(subcommand)]
+    Search(SearchCommands),
+    /// Print the difference between two dates.
+    Diff(DiffArgs),
+    /// Print the lunar phases of a month or a year.
+    Moon(MoonArgs),
+    /// Print the solar terms of a year.
+    Terms(TermsArgs),
+    /// Print the days remaining until a date or the next occurrence of an
+    /// event.
+    Countdown(CountdownArgs),
+    /// Print the Four Pillars (八字) of a moment.
+    Bazi(BaziArgs),
+    /// Print the traditional almanac (黄历) of a day.
+    Almanac(AlmanacArgs),
+    /// Convert between dates and Julian day numbers, Julian dates, modified
+    /// Julian dates and Unix days.
+    Jdn(JdnArgs),
+    /// Print the times of sunrise, sunset and twilight of a day at a place.
+    Sun(SunArgs),
+    /// Find the Gregorian dates of a Chinese month and day in some years.
+    Find(FindArgs),
+    /// List the ISO weeks of a year, or the days of an ISO week.
+    Weeks(WeeksArgs),
+    /// Print a line about today for status bars, e.g. i3status or polybar.
+    Today(TodayArgs),
+    /// Convert between era years, e.g. 康熙三十年 or 令和7年, and Gregorian
+    /// years.
+    Era(EraArgs),
+    /// Browse the calendar interactively in the terminal.
+    #[cfg(feature = "tui")]
+    Tui,
+    /// Render a wall-calendar sheet of a month or a year.
+    #[cfg(feature = "render")]
+    Render(RenderArgs),
+    /// Print the completion script of a shell.
+    Completions(CompletionsArgs),
+    /// Print the manual page in roff.
+    Man,
+}
+
+#[derive(Args, Debug)]
+struct CompletionsArgs {
+    /// The shell to complete in.
+    shell: clap_complete::Shell,
+}
+
+/// A value parser by a function, which suggests some values to complete
+/// without limiting the values to them.
+#[derive(Clone)]
+struct Hinted<T> {
+    parse: fn(&str) -> Result<T, String>,
+    hints: Vec<&'static str>,
+}
+
+impl<T> Hinted<T> {
+    fn new(
+        parse: fn(&str) -> Result<T, String>,
+        hints: impl IntoIterator<Item = &'static str>,
+    ) -> Self {
+        Self {
+            parse,
+            hints: hints.into_iter().collect(),
+        }
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> TypedValueParser for Hinted<T> {
+    type Value = T;
+
+    fn parse_ref(
+        &self,
+        cmd: &clap::Command,
+        arg: Option<&clap::Arg>,
+        value: &std::ffi::OsStr,
+    ) -> Result<T, clap::Error> {
+        self.parse.parse_ref(cmd, arg, value)
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        Some(Box::new(self.hints.iter().copied().map(PossibleValue::new)))
+    }
+}
+
+/// The relative days a date may be given as, to complete.
+const RELATIVE_DAYS: [&str; 3] = ["today", "tomorrow", "yesterday"];
+
+fn parse_text(s: &str) -> Result<String, String> {
+    Ok(s.to_string())
 }
 
 #[derive(Args, Debug)]
 struct RangeArgs {
     /// The year.
     year: Option<i32>,
-    /// The month.
+    /// The month, counting the leap month if any in the Chinese calendar.
     month: Option<u8>,
+    /// The calendar of the year and the month.
+    #[arg(long, value_enum, default_value_t = CalendarKind::Gregorian)]
+    calendar: CalendarKind,
 }
 
 #[derive(Args, Debug)]
@@ -36,6 +196,36 @@ struct PrintArgs {
     /// The range of the calendar to print.
     #[command(flatten)]
     range: RangeArgs,
+    /// The first day of the week, Monday by default.
+    #[arg(long, value_enum)]
+    first_weekday: Option<FirstWeekday>,
+    /// Show the ISO week numbers.
+    #[arg(long)]
+    week_numbers: bool,
+    /// Show the Chinese day, festival or solar term under each Gregorian day.
+    #[arg(short, long)]
+    chinese: bool,
+    /// The number of months printed side by side when printing a year, as
+    /// many as fit in the terminal by default.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..))]
+    columns: Option<u8>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum FirstWeekday {
+    Mon,
+    Sun,
+    Sat,
+}
+
+impl FirstWeekday {
+    fn weekday(&self) -> Weekday {
+        match self {
+            FirstWeekday::Mon => Monday,
+            FirstWeekday::Sun => Sunday,
+            FirstWeekday::Sat => Saturday,
+        }
+    }
 }
 
 #[derive(Args, Debug)]
@@ -55,6 +245,70 @@ struct OptionArgs {
     /// Display the solar term if applicable.
     #[arg(short, long)]
     solar_term: bool,
+    /// Display Chinese text in Traditional Chinese.
+    #[arg(short, long)]
+    traditional: bool,
+    /// Display Chinese dates, lunar phases and solar terms in English.
+    #[arg(short = 'E', long)]
+    english: bool,
+}
+
+impl OptionArgs {
+    fn script(&self) -> Script {
+        if self.traditional {
+            Script::Traditional
+        } else {
+            Script::Simplified
+        }
+    }
+
+    fn format_chinese_day(&self, day: ChineseDay) -> String {
+        if self.english {
+            format!(
+                "{} Year, {}, {}",
+                day.the_year().stem_branch(),
+                day.the_month().english(),
+                day.english()
+            )
+        } else {
+            self.script().display(day).to_string()
+        }
+    }
+
+    fn format_lunar_phase(&self, lunar_phase: LunarPhase) -> String {
+        if self.english {
+            lunar_phase.to_string()
+        } else {
+            self.script().convert(lunar_phase.chinese())
+        }
+    }
+
+    fn format_solar_term(&self, solar_term: SolarTerm) -> String {
+        if self.english {
+            solar_term.english().to_string()
+        } else {
+            self.script().convert(solar_term.chinese())
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    Text,
+    Csv,
+    Tsv,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum Column {
+    Date,
+    Chinese,
+    Weekday,
+    LunarPhase,
+    LunarPhaseEmoji,
+    SolarTerm,
+    /// The names of the events of the events file.
+    Events,
 }
 
 #[derive(Args, Debug)]
@@ -65,181 +319,3437 @@ struct ListArgs {
     /// List options.
     #[command(flatten)]
     option: OptionArgs,
+    /// The output format.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+    /// The columns to list, separated by commas, instead of the date and the
+    /// columns selected by the options.
+    #[arg(long, value_enum, value_delimiter = ',')]
+    columns: Vec<Column>,
+    /// The first day to list, instead of a year or a month.
+    #[arg(long, requires = "to", conflicts_with_all = ["year", "month"], value_parser = parse_any_date)]
+    from: Option<Date>,
+    /// The last day to list.
+    #[arg(long, requires = "from", value_parser = parse_any_date)]
+    to: Option<Date>,
+    /// List the days as an agenda for printing, in aligned columns with a
+    /// heading for each month and a blank line between weeks.
+    #[arg(long, conflicts_with = "format")]
+    agenda: bool,
+    /// List only the days matching a filter, e.g. `weekday=Sat,Sun`,
+    /// `solar-term`, `lunar-phase=FullMoon` or `chinese-day=初一`. All the
+    /// filters given must match.
+    #[arg(
+        long,
+        value_name = "FILTER",
+        hide_possible_values = true,
+        value_parser = Hinted::new(
+            parse_filter,
+            ["weekday=", "solar-term", "lunar-phase", "chinese-day="]
+        )
+    )]
+    only: Vec<Filter>,
+}
+
+/// A filter of the days to list, matching any of its values.
+#[derive(Clone, Debug, PartialEq)]
+enum Filter {
+    Weekday(Vec<Weekday>),
+    /// Any solar term if empty.
+    SolarTerm(Vec<SolarTerm>),
+    /// The principal phases, i.e. new moon, quarters and full moon, if empty.
+    LunarPhase(Vec<LunarPhase>),
+    ChineseDay(Vec<u8>),
+}
+
+impl Filter {
+    fn matches(&self, date: Date, chinese_day: Option<ChineseDay>, tz: f64) -> bool {
+        match self {
+            Filter::Weekday(weekdays) => weekdays.contains(&date.weekday()),
+            Filter::SolarTerm(solar_terms) => date
+                .solar_term(tz)
+                .is_some_and(|st| solar_terms.is_empty() || solar_terms.contains(&st)),
+            Filter::LunarPhase(phases) => {
+                let phase = date.lunar_phase(tz);
+                if phases.is_empty() {
+                    phase.is_principal()
+                } else {
+                    phases.contains(&phase)
+                }
+            }
+            Filter::ChineseDay(days) => {
+                let chinese_day = chinese_day.or_else(|| ChineseDay::try_from(date).ok());
+                chinese_day.is_some_and(|chinese_day| days.contains(&chinese_day.ord()))
+            }
+        }
+    }
+}
+
+/// Finds a variant by its English name, ignoring case, spaces, hyphens and
+/// underscores, by a prefix of at least 3 letters of it, or by its Chinese
+/// name.
+fn parse_variant<T: Copy + strum::VariantArray + std::fmt::Display>(
+    name: &str,
+    chinese: impl Fn(&T) -> String,
+) -> Option<T> {
+    let normalize = |s: &str| {
+        s.chars()
+            .filter(|c| !matches!(c, ' ' | '-' | '_'))
+            .collect::<String>()
+            .to_lowercase()
+    };
+    let name = normalize(name);
+    let english = |variant: &T| normalize(&variant.to_string());
+    T::VARIANTS
+        .iter()
+        .find(|variant| english(variant) == name || chinese(variant) == name)
+        .or_else(|| {
+            T::VARIANTS
+                .iter()
+                .find(|variant| name.len() >= 3 && english(variant).starts_with(&name))
+        })
+        .copied()
+}
+
+fn parse_filter(s: &str) -> Result<Filter, String> {
+    let (key, values) = match s.split_once('=') {
+        Some((key, values)) => (key.trim(), values.split(',').map(str::trim).collect()),
+        None => (s.trim(), Vec::new()),
+    };
+    fn parse_all<T>(
+        key: &str,
+        values: &[&str],
+        parse: impl Fn(&str) -> Option<T>,
+    ) -> Result<Vec<T>, String> {
+        values
+            .iter()
+            .map(|value| parse(value).ok_or_else(|| format!("invalid {}: {}", key, value)))
+            .collect()
+    }
+    let filter = match key {
+        "weekday" => Filter::Weekday(parse_all(key, &values, |value| {
+            parse_variant(value, |weekday: &Weekday| weekday.chinese(0).to_string())
+        })?),
+        "solar-term" => Filter::SolarTerm(parse_all(key, &values, |value| {
+            parse_variant(value, |st: &SolarTerm| st.chinese().to_string())
+        })?),
+        "lunar-phase" => Filter::LunarPhase(parse_all(key, &values, |value| {
+            parse_variant(value, |phase: &LunarPhase| phase.chinese().to_string())
+        })?),
+        "chinese-day" => Filter::ChineseDay(parse_all(key, &values, |value| {
+            value
+                .parse()
+                .ok()
+                .or_else(|| ChineseDay::ord_from_name(value))
+                .filter(|day| (1..=30).contains(day))
+        })?),
+        _ => return Err(format!("unknown filter: {}", key)),
+    };
+    let missing = match &filter {
+        Filter::Weekday(weekdays) => weekdays.is_empty(),
+        Filter::ChineseDay(days) => days.is_empty(),
+        _ => false,
+    };
+    if missing {
+        return Err(format!("missing values of {}", key));
+    }
+    Ok(filter)
+}
+
+impl ListArgs {
+    fn columns(&self) -> Vec<Column> {
+        if !self.columns.is_empty() {
+            return self.columns.clone();
+        }
+        [
+            (Column::Date, true),
+            (
+                Column::Chinese,
+                self.option.chinese || self.range.calendar == CalendarKind::Chinese,
+            ),
+            (Column::Weekday, self.option.weekday),
+            (Column::LunarPhase, self.option.lunar_phase),
+            (Column::LunarPhaseEmoji, self.option.lunar_phase_emoji),
+            (Column::SolarTerm, self.option.solar_term),
+        ]
+        .into_iter()
+        .filter_map(|(column, enabled)| enabled.then_some(column))
+        .collect()
+    }
 }
 
 #[derive(Args, Debug)]
 struct QueryArgs {
-    /// The date to query.
+    /// The date to query, e.g. "2024-02-10", "2024/2/10", "tomorrow",
+    /// "next monday" or "正月初一", today by default.
+    #[arg(hide_possible_values = true, value_parser = Hinted::new(parse_text, RELATIVE_DAYS))]
     date: Option<String>,
     /// Query options.
     #[command(flatten)]
     option: OptionArgs,
+    /// Print all the attributes of the date as "key: value" lines, with keys
+    /// that are stable for scripts.
+    #[arg(long)]
+    all: bool,
+    /// Print all the attributes as a JSON object.
+    #[arg(long, requires = "all")]
+    json: bool,
 }
 
-fn parse_range(args: &RangeArgs) -> (i32, Option<u8>) {
-    match args {
-        RangeArgs {
-            year: Some(y),
-            month: Some(m),
-        } => (*y, Some(*m)),
-        RangeArgs {
-            year: Some(y),
-            month: None,
-        } => (*y, None),
-        RangeArgs {
-            year: None,
-            month: None,
-        } => {
-            let today = Date::from_unix_time_with_tz(unix_time_now(), 8.0);
-            let today = GregorianDay::from(today);
-            (today.the_year().ord(), Some(today.the_month().ord()))
+/// The value of an attribute of a date.
+#[derive(Debug, PartialEq)]
+enum Attribute {
+    Text(String),
+    Number(f64),
+    None,
+}
+
+/// All the attributes of a date, in a stable order.
+fn date_attributes(date: Date, tz: f64) -> Vec<(&'static str, Attribute)> {
+    let day = GregorianDay::from(date);
+    let chinese_day = ChineseDay::try_from(date).ok();
+    let (iso_year, iso_week, _) = day.iso_week();
+    let text = |s: String| Attribute::Text(s);
+    // The Chinese attributes are none outside the Chinese calendar.
+    let chinese = |f: fn(&ChineseDay) -> String| {
+        chinese_day
+            .as_ref()
+            .map_or(Attribute::None, |day| text(f(day)))
+    };
+    let illumination = astronomy::get_moon_illumination(date.noon_jd(tz));
+    vec![
+        ("gregorian", text(day.to_iso8601())),
+        ("chinese", chinese(|day| day.to_string())),
+        ("jdn", Attribute::Number(date.jdn() as f64)),
+        ("mjd", Attribute::Number((date.jdn() - 2400001) as f64)),
+        ("weekday", text(date.weekday().to_string())),
+        ("iso_week", text(format!("{}-W{:02}", iso_year, iso_week))),
+        ("day_of_year", Attribute::Number(day.ord_in_year() as f64)),
+        ("lunar_phase", text(date.lunar_phase(tz).to_string())),
+        (
+            "lunar_illumination",
+            Attribute::Number((illumination * 1000.0).round() / 1000.0),
+        ),
+        (
+            "solar_term",
+            date.solar_term(tz)
+                .map_or(Attribute::None, |st| text(st.to_string())),
+        ),
+        (
+            "year_stem_branch",
+            chinese(|day| format!("{:#}", day.the_year().stem_branch())),
+        ),
+        (
+            "month_stem_branch",
+            chinese(|day| format!("{:#}", day.the_month().stem_branch())),
+        ),
+        (
+            "day_stem_branch",
+            text(format!("{:#}", StemBranch::from_date(date))),
+        ),
+        (
+            "chinese_zodiac",
+            chinese(|day| day.the_year().branch().english().to_string()),
+        ),
+        (
+            "zodiac_sign",
+            text(date.zodiac_sign(ZodiacMethod::Astronomical, tz).to_string()),
+        ),
+        (
+            "festival",
+            chinese_day
+                .and_then(|day| day.festival())
+                .map_or(Attribute::None, |festival| text(festival.to_string())),
+        ),
+    ]
+}
+
+fn attribute_lines(attributes: &[(&str, Attribute)], json: bool) -> Vec<String> {
+    let value = |attribute: &Attribute| match attribute {
+        Attribute::Text(text) if json => json_string(text),
+        Attribute::Text(text) => text.clone(),
+        Attribute::Number(number) => number.to_string(),
+        Attribute::None if json => "null".to_string(),
+        Attribute::None => String::new(),
+    };
+    if json {
+        let fields: Vec<_> = attributes
+            .iter()
+            .map(|(key, attribute)| format!("{}:{}", json_string(key), value(attribute)))
+            .collect();
+        return vec![format!("{{{}}}", fields.join(","))];
+    }
+    attributes
+        .iter()
+        .map(|(key, attribute)| {
+            format!("{}: {}", key, value(attribute))
+                .trim_end()
+                .to_string()
+        })
+        .collect()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum CalendarKind {
+    Gregorian,
+    Chinese,
+}
+
+impl CalendarKind {
+    /// The calendar of the same name in the registry.
+    fn calendar(self) -> &'static dyn registry::AnyCalendar {
+        registry::calendar(self.to_possible_value().unwrap().get_name()).unwrap()
+    }
+}
+
+#[derive(Args, Debug)]
+struct ConvertArgs {
+    /// The calendar of the date.
+    #[arg(short, long, value_enum, default_value_t = CalendarKind::Gregorian)]
+    from: CalendarKind,
+    /// The calendars to convert the date to, separated by commas.
+    #[arg(
+        short,
+        long,
+        value_enum,
+        value_delimiter = ',',
+        default_value = "chinese"
+    )]
+    to: Vec<CalendarKind>,
+    /// The date to convert, e.g. "2024-02-10" or "2023年闰2月5日".
+    #[arg(required_unless_present = "stdin", conflicts_with = "stdin")]
+    date: Option<String>,
+    /// Convert the dates read from the standard input, one per line, and
+    /// write the results of each date on a line.
+    #[arg(long)]
+    stdin: bool,
+    /// Write the results as JSON lines.
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args, Debug)]
+struct HolidaysArgs {
+    /// The Gregorian year, the current year by default.
+    year: Option<i32>,
+    /// The region: cn, us, de or fr; cn by default.
+    #[arg(short, long)]
+    region: Option<holiday::Region>,
+}
+
+#[derive(Subcommand, Debug)]
+enum SearchCommands {
+    /// Print the next dates of an event.
+    Next(SearchArgs),
+}
+
+#[derive(Args, Debug)]
+struct SearchArgs {
+    /// The event, e.g. "full-moon", "Winter Solstice", "冬至", "leap-month",
+    /// "Spring Festival", "甲子 day" or "甲子 year".
+    event: SearchEvent,
+    /// The date to search from, today by default.
+    #[arg(short, long, value_parser = parse_any_date)]
+    from: Option<Date>,
+    /// Search backward for the previous dates instead.
+    #[arg(short, long)]
+    before: bool,
+    /// The number of dates to print.
+    #[arg(short = 'n', long, default_value_t = 1)]
+    count: usize,
+}
+
+#[derive(Args, Debug)]
+struct DiffArgs {
+    /// The start date, e.g. "2024-02-10" or "甲辰年正月初一".
+    #[arg(value_parser = parse_any_date)]
+    start: Date,
+    /// The end date.
+    #[arg(value_parser = parse_any_date)]
+    end: Date,
+}
+
+#[derive(Args, Debug)]
+struct MoonArgs {
+    /// The Gregorian year or month, e.g. "2024" or "202402", the current month
+    /// by default.
+    #[arg(value_parser = parse_period)]
+    period: Option<(i32, Option<u8>)>,
+    /// Also print the illuminated fraction of the moon at noon of each day.
+    #[arg(short, long)]
+    daily: bool,
+}
+
+#[derive(Args, Debug)]
+struct RenderArgs {
+    /// The Gregorian year, the current month by default.
+    year: Option<i32>,
+    /// The month, the whole year by default.
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=12))]
+    month: Option<u8>,
+    /// The first day of the week, Monday by default.
+    #[arg(long, value_enum)]
+    first_weekday: Option<FirstWeekday>,
+    /// The file to write, as PNG if its name ends with ".png"; SVG to the
+    /// standard output by default.
+    #[arg(short, long, value_hint = ValueHint::FilePath)]
+    output: Option<std::path::PathBuf>,
+}
+
+#[derive(Args, Debug)]
+struct BaziArgs {
+    /// The local date and time, e.g. "1990-06-15T08:30" or "1990-06-15 08:30";
+    /// without a time, the hour pillar is omitted.
+    #[arg(value_parser = parse_date_time)]
+    moment: (Date, Option<u32>),
+}
+
+#[derive(Args, Debug)]
+struct AlmanacArgs {
+    /// The date, in any form `query` accepts, today by default.
+    #[arg(hide_possible_values = true, value_parser = Hinted::new(parse_text, RELATIVE_DAYS))]
+    date: Option<String>,
+}
+
+#[derive(Args, Debug)]
+struct JdnArgs {
+    /// A date, in any form `query` accepts, or a day number, e.g. "2460351"
+    /// or "2460350.75".
+    #[arg(allow_hyphen_values = true)]
+    input: String,
+    /// The kind of the day number.
+    #[arg(long, value_enum, default_value_t = DayNumber::Jd)]
+    from: DayNumber,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum DayNumber {
+    /// A Julian day number, or a Julian date if it has a fraction.
+    Jd,
+    /// A modified Julian date, JD − 2400000.5.
+    Mjd,
+    /// Days since 1970-01-01T00:00Z.
+    Unix,
+}
+
+impl DayNumber {
+    /// The Julian date of a day number of this kind.
+    fn to_jd(self, number: f64, integer: bool) -> f64 {
+        match self {
+            // A Julian day number names the day starting at the midnight
+            // before its noon.
+            DayNumber::Jd if integer => number - 0.5,
+            DayNumber::Jd => number,
+            DayNumber::Mjd => number + 2400000.5,
+            DayNumber::Unix => number + 2440587.5,
         }
-        _ => unreachable!(),
     }
 }
 
-fn print_calendar(args: &PrintArgs) {
-    let (y, m) = parse_range(&args.range);
-    if let Some(m) = m {
-        let month = GregorianCalendar::from_ym(y, m).unwrap();
-        println!("{:^28}", format!("{:-}", month));
-        print_month(month);
-    } else {
-        let year = GregorianCalendar::from_y(y).unwrap();
-        println!("{:^28}", format!("Year {}", year));
-        print_year(year);
+#[derive(Args, Debug)]
+struct FindArgs {
+    /// The Chinese month and day, e.g. "八月十五" or "闰四月初一"; the leap
+    /// month of a month, if any, is also searched.
+    #[arg(long)]
+    chinese: String,
+    /// The Chinese years, inclusive, e.g. "2024..2030" or "2024"; the current
+    /// year and the 9 years after by default.
+    #[arg(long, value_parser = parse_years)]
+    years: Option<RangeInclusive<i32>>,
+}
+
+fn parse_years(s: &str) -> Result<RangeInclusive<i32>, String> {
+    let invalid = || format!("invalid years: {}", s);
+    let (first, last) = match s.split_once("..") {
+        Some((first, last)) => (first, last.strip_prefix('=').unwrap_or(last)),
+        None => (s, s),
+    };
+    let (first, last) = (
+        first.trim().parse().map_err(|_| invalid())?,
+        last.trim().parse().map_err(|_| invalid())?,
+    );
+    if first > last {
+        return Err(invalid());
     }
+    Ok(first..=last)
+}
+
+/// The dates of a Chinese month and day in each of the years, with those of
+/// the leap month too if the month is not leap.
+fn find_chinese_dates(month_day: &str, years: RangeInclusive<i32>) -> Vec<ChineseDay> {
+    let month_day = month_day.trim();
+    let leap = (!month_day.starts_with('闰')).then(|| format!("闰{}", month_day));
+    years
+        .flat_map(|year| {
+            [Some(month_day), leap.as_deref()]
+                .into_iter()
+                .flatten()
+                .filter_map(move |month_day| {
+                    ChineseDay::parse_with_pivot(&format!("{}年{}", year, month_day), year).ok()
+                })
+        })
+        .collect()
+}
+
+fn find_lines(days: &[ChineseDay]) -> Vec<String> {
+    days.iter()
+        .map(|day| {
+            let date = Date::from(*day);
+            format!(
+                "{} {} {} {}",
+                day.the_year().ord(),
+                day,
+                GregorianDay::from(date).to_iso8601(),
+                date.weekday()
+            )
+        })
+        .collect()
+}
+
+#[derive(Args, Debug)]
+struct WeeksArgs {
+    /// The ISO week-numbering year, e.g. "2025", or a week, e.g. "2025-W07";
+    /// the current year by default.
+    #[arg(value_parser = parse_weeks_period)]
+    period: Option<WeeksPeriod>,
 }
 
-fn print_year(year: GregorianYear) {
-    for month in year.months() {
-        println!("{:^28}", month.name());
-        print_month(month);
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum WeeksPeriod {
+    Year(i32),
+    /// A week, by its Monday.
+    Week(Date),
+}
+
+fn parse_weeks_period(s: &str) -> Result<WeeksPeriod, String> {
+    let invalid = || format!("invalid year or week: {}", s);
+    if s.contains("-W") {
+        let monday = GregorianDay::from_iso8601(&format!("{}-1", s)).map_err(|_| invalid())?;
+        return Ok(WeeksPeriod::Week(monday.into()));
     }
+    s.parse().map(WeeksPeriod::Year).map_err(|_| invalid())
 }
 
-fn print_month(month: GregorianMonth) {
-    let today: GregorianDay = Date::from_unix_time_with_tz(unix_time_now(), 8.0).into();
-    for weekday in Weekday::VARIANTS {
-        print!(" {:3}", weekday);
+/// The ISO weeks of a year, with their first and last days and the months
+/// they fall in.
+fn weeks_lines(year: i32) -> Vec<String> {
+    let Ok(first) = GregorianDay::from_iso8601(&format!("{}-W01-1", year)) else {
+        return Vec::new();
+    };
+    let mut lines = Vec::new();
+    let mut monday = Date::from(first);
+    while GregorianDay::from(monday).iso_week().0 == year {
+        let (first, last) = (GregorianDay::from(monday), GregorianDay::from(monday + 6));
+        let mut months = vec![first.the_month().name().to_string()];
+        if last.the_month() != first.the_month() {
+            months.push(last.the_month().name().to_string());
+        }
+        lines.push(format!(
+            "{}-W{:02} {}..{} {}",
+            year,
+            first.iso_week().1,
+            first.to_iso8601(),
+            last.to_iso8601(),
+            months.join(", ")
+        ));
+        monday += 7;
     }
-    println!();
-    let days = month.days();
-    for _ in 0..month.first_day().weekday() as u8 {
-        print!("    ");
+    lines
+}
+
+/// The days of the ISO week starting on a Monday.
+fn week_days_lines(monday: Date) -> Vec<String> {
+    (0..7)
+        .map(|i| {
+            let date = monday + i;
+            format!(
+                "{} {} {}",
+                &date.weekday().to_string()[..3],
+                GregorianDay::from(date).to_iso8601(),
+                chinese_text(date)
+            )
+        })
+        .collect()
+}
+
+#[derive(Args, Debug)]
+struct TodayArgs {
+    /// The template of the line, with the keys of `query --all` in braces,
+    /// e.g. "{gregorian}", and "{greg}", "{phase_emoji}" and "{events}";
+    /// "{{" and "}}" for literal braces.
+    #[arg(long, default_value = "{greg} {chinese} {phase_emoji}")]
+    format: String,
+    /// Print the line again at every midnight.
+    #[arg(long)]
+    watch: bool,
+}
+
+/// Fills a template with the attributes of a date.
+fn expand_template(
+    template: &str,
+    date: Date,
+    events: &events::Events,
+    tz: f64,
+) -> Result<String, String> {
+    let attributes = date_attributes(date, tz);
+    let value = |key: &str| {
+        let key = match key {
+            "greg" => "gregorian",
+            "phase_emoji" => return Some(date.lunar_phase(tz).emoji().to_string()),
+            "events" => return Some(events.names(date).join(", ")),
+            key => key,
+        };
+        attributes
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, attribute)| match attribute {
+                Attribute::Text(text) => text.clone(),
+                Attribute::Number(number) => number.to_string(),
+                Attribute::None => String::new(),
+            })
+    };
+    let mut line = String::new();
+    let mut chars = template.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.as_str().starts_with('{') => {
+                chars.next();
+                line.push('{');
+            }
+            '}' if chars.as_str().starts_with('}') => {
+                chars.next();
+                line.push('}');
+            }
+            '{' => {
+                let rest = chars.as_str();
+                let end = rest
+                    .find('}')
+                    .ok_or_else(|| format!("unclosed brace in template: {}", template))?;
+                let key = rest[..end].trim();
+                line += &value(key).ok_or_else(|| format!("unknown key in template: {}", key))?;
+                chars = rest[end + 1..].chars();
+            }
+            '}' => return Err(format!("unmatched brace in template: {}", template)),
+            c => line.push(c),
+        }
     }
-    for day in days {
-        if day == today {
-            print!("[{:>2}]", day.ord());
-        } else {
-            print!(" {:>2} ", day.ord());
+    Ok(line)
+}
+
+/// Seconds from a Unix time to the next midnight in the time zone.
+fn seconds_to_midnight(unix_time: u64, tz: f64) -> u64 {
+    let local = unix_time as i64 + (tz * 3600.0).round() as i64;
+    86400 - local.rem_euclid(86400) as u64
+}
+
+fn print_today(args: &TodayArgs, events: &events::Events, tz: f64) {
+    use std::io::Write as _;
+
+    loop {
+        let now = unix_time_now();
+        let date = Date::from_unix_time_with_tz(now, tz);
+        match expand_template(&args.format, date, events, tz) {
+            Ok(line) => println!("{}", line),
+            Err(e) => CliError::Invalid(e).exit(),
         }
-        if day.weekday() == Weekday::last() {
-            println!();
+        if !args.watch {
+            break;
+        }
+        // Status bars read the lines as they come through a pipe.
+        let _ = std::io::stdout().flush();
+        let seconds = seconds_to_midnight(unix_time_now(), tz);
+        std::thread::sleep(std::time::Duration::from_secs(seconds + 1));
+    }
+}
+
+#[derive(Args, Debug)]
+struct EraArgs {
+    /// A year of an era, e.g. "康熙三十年", "令和7年" or "民国114年", or a
+    /// Gregorian year, e.g. "1987".
+    year: String,
+    /// The countries whose eras to list for a Gregorian year; all by default.
+    #[arg(long, value_delimiter = ',')]
+    country: Vec<EraCountry>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum EraCountry {
+    /// Chinese era names (年号) of the Ming and Qing dynasties.
+    Cn,
+    /// Japanese eras (和暦).
+    Jp,
+    /// The Minguo calendar (民国纪年).
+    Tw,
+    /// The Juche calendar (주체 연호).
+    Kp,
+}
+
+/// An era year with the dynasty of its era, if any, e.g. "清康熙三十年".
+fn era_year_name(era_year: &regnal::EraYear) -> String {
+    format!("{}{}", era_year.era.dynasty.unwrap_or_default(), era_year)
+}
+
+/// The era years of a Gregorian year in the countries, or the year of an era
+/// year.
+fn era_lines(year: &str, countries: &[EraCountry]) -> Result<Vec<String>, String> {
+    let year = year.trim();
+    if let Ok(year) = year.parse::<i32>() {
+        let countries = if countries.is_empty() {
+            EraCountry::value_variants()
+        } else {
+            countries
+        };
+        let mut lines = Vec::new();
+        for country in countries {
+            let country = match country {
+                EraCountry::Cn => regnal::Country::China,
+                EraCountry::Jp => regnal::Country::Japan,
+                EraCountry::Tw => regnal::Country::Taiwan,
+                EraCountry::Kp => regnal::Country::NorthKorea,
+            };
+            lines.extend(regnal::era_years(country, year).iter().map(|era_year| {
+                format!("{} = {} ({:#})", year, era_year_name(era_year), era_year)
+            }));
         }
+        return Ok(lines);
     }
-    if month.last_day().weekday() != Weekday::last() {
-        println!();
+    if let Ok(era_year) = year.parse::<regnal::EraYear>() {
+        return Ok(vec![format!(
+            "{} ({:#}) = {}",
+            era_year_name(&era_year),
+            era_year,
+            era_year.ord()
+        )]);
     }
+    Err(format!("invalid era year: {}", year))
 }
 
-fn list_month(month: GregorianMonth, options: &OptionArgs, chinese_day: &mut Option<ChineseDay>) {
-    for day in month.days() {
-        let date: Date = day.into();
-        print!("{:#}", day);
-        if options.chinese {
-            if chinese_day.is_none() {
-                *chinese_day = Some(ChineseDay::from(date));
-            } else {
-                *chinese_day = Some(chinese_day.unwrap().succ());
-            }
-            print!(" {}", chinese_day.unwrap());
+#[derive(Args, Debug)]
+struct SunArgs {
+    /// The latitude in degrees, positive north.
+    #[arg(long, allow_hyphen_values = true, value_parser = parse_latitude)]
+    lat: f64,
+    /// The longitude in degrees, positive east.
+    #[arg(long, allow_hyphen_values = true, value_parser = parse_longitude)]
+    lon: f64,
+    /// The date, in any form `query` accepts, today by default.
+    #[arg(hide_possible_values = true, value_parser = Hinted::new(parse_text, RELATIVE_DAYS))]
+    date: Option<String>,
+}
+
+fn parse_degrees(s: &str, limit: f64) -> Result<f64, String> {
+    s.parse()
+        .ok()
+        .filter(|degrees: &f64| degrees.abs() <= limit)
+        .ok_or_else(|| format!("must be a number from {} to {}", -limit, limit))
+}
+
+fn parse_latitude(s: &str) -> Result<f64, String> {
+    parse_degrees(s, 90.0)
+}
+
+fn parse_longitude(s: &str) -> Result<f64, String> {
+    parse_degrees(s, 180.0)
+}
+
+#[derive(Args, Debug)]
+struct TermsArgs {
+    /// The Gregorian year, the current year by default.
+    year: Option<i32>,
+}
+
+#[derive(Args, Debug)]
+struct CountdownArgs {
+    /// The target, e.g. "2025-06-01", "Mid-Autumn", "中秋节" or "2025 Spring
+    /// Festival", or a birth date with --birthday.
+    #[arg(
+        hide_possible_values = true,
+        value_parser = Hinted::new(
+            parse_text,
+            ChineseFestival::VARIANTS.iter().map(|festival| festival.chinese())
+        )
+    )]
+    target: String,
+    /// Count down to the next birthday of a person born on the target date.
+    #[arg(short, long)]
+    birthday: bool,
+    /// Observe the birthday in the Chinese calendar.
+    #[arg(short, long, requires = "birthday")]
+    lunar: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SearchEvent {
+    LunarPhase(LunarPhase),
+    SolarTerm(SolarTerm),
+    Festival(ChineseFestival),
+    /// The first day of a leap month.
+    LeapMonth,
+    StemBranchDay(StemBranch),
+    /// The first day of a Chinese year.
+    StemBranchYear(StemBranch),
+}
+
+impl std::str::FromStr for SearchEvent {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalize = |s: &str| {
+            s.chars()
+                .filter(|c| !matches!(c, ' ' | '-' | '_'))
+                .collect::<String>()
+                .to_lowercase()
+        };
+        let name = normalize(s);
+        if name == "leapmonth" || name == "闰月" {
+            return Ok(SearchEvent::LeapMonth);
         }
-        if options.weekday {
-            print!(" {:#}", day.weekday());
+        if let Some(phase) = LunarPhase::VARIANTS
+            .iter()
+            .find(|phase| normalize(&phase.to_string()) == name || phase.chinese() == name)
+        {
+            return Ok(SearchEvent::LunarPhase(*phase));
         }
-        if options.lunar_phase {
-            print!(" {}", date.lunar_phase(8.0).chinese());
+        if let Some(solar_term) = SolarTerm::VARIANTS.iter().find(|solar_term| {
+            normalize(solar_term.english()) == name || solar_term.chinese() == name
+        }) {
+            return Ok(SearchEvent::SolarTerm(*solar_term));
         }
-        if options.lunar_phase_emoji {
-            print!(" {}", date.lunar_phase(8.0).emoji());
+        if let Some(festival) = parse_festival(s) {
+            return Ok(SearchEvent::Festival(festival));
         }
-        if options.solar_term {
-            if let Some(st) = date.solar_term(8.0) {
-                print!(" {}", st.chinese());
+        let stem_branch = |name: &str| {
+            (1..=60)
+                .filter_map(StemBranch::from_ord)
+                .find(|sb| normalize(&format!("{}", sb)) == name || format!("{:#}", sb) == name)
+        };
+        for (suffixes, event) in [
+            (
+                ["day", "日"],
+                SearchEvent::StemBranchDay as fn(StemBranch) -> SearchEvent,
+            ),
+            (["year", "年"], SearchEvent::StemBranchYear),
+        ] {
+            for suffix in suffixes {
+                if let Some(sb) = name.strip_suffix(suffix).and_then(stem_branch) {
+                    return Ok(event(sb));
+                }
             }
         }
-        println!();
+        Err(format!("unknown event: {}", s))
     }
 }
 
-fn list_year(year: GregorianYear, options: &OptionArgs, chinese_day: &mut Option<ChineseDay>) {
-    for month in year.months() {
-        list_month(month, options, chinese_day);
+impl SearchEvent {
+    /// The dates of the event from `begin` (inclusive) to `end` (exclusive),
+    /// in order.
+    fn dates_in(&self, begin: Date, end: Date, tz: f64) -> Vec<Date> {
+        let years = chinese_year_of(begin)..=chinese_year_of(end);
+        let mut dates: Vec<Date> = match self {
+            SearchEvent::LunarPhase(phase) => astronomy::get_lunar_phases(begin, end, tz)
+                .into_iter()
+                .enumerate()
+                .filter(|(_, lp)| lp == phase)
+                .map(|(i, _)| begin + i as i32)
+                .collect(),
+            SearchEvent::SolarTerm(solar_term) => astronomy::find_solar_terms(begin, end, tz)
+                .into_iter()
+                .filter(|(_, st)| st == solar_term)
+                .map(|(date, _)| date)
+                .collect(),
+            SearchEvent::Festival(festival) => {
+                years.filter_map(|year| festival.date(year)).collect()
+            }
+            SearchEvent::LeapMonth => years
+                .filter_map(ChineseCalendar::from_y)
+                .flat_map(|year| {
+                    year.months()
+                        .filter(|month| month.is_leap())
+                        .collect::<Vec<_>>()
+                })
+                .map(|month| month.first_day().into())
+                .collect(),
+            SearchEvent::StemBranchDay(stem_branch) => {
+                let offset = stem_branch.ord() as i32 - StemBranch::from_date(begin).ord() as i32;
+                (0..)
+                    .map(|i| begin + offset.rem_euclid(60) + i * 60)
+                    .take_while(|date| *date < end)
+                    .collect()
+            }
+            SearchEvent::StemBranchYear(stem_branch) => years
+                .filter_map(ChineseCalendar::from_y)
+                .filter(|year| year.stem_branch() == *stem_branch)
+                .map(|year| year.first_day().into())
+                .collect(),
+        };
+        dates.retain(|date| (begin..end).contains(date));
+        dates
     }
 }
 
-fn list_dates(args: &ListArgs) {
-    let (y, m) = parse_range(&args.range);
-    if let Some(m) = m {
-        let month = GregorianCalendar::from_ym(y, m).unwrap();
-        list_month(month, &args.option, &mut None);
-    } else {
-        let year = GregorianCalendar::from_y(y).unwrap();
-        list_year(year, &args.option, &mut None);
-    }
+#[derive(Subcommand, Debug)]
+enum ExportCommands {
+    /// Export events as an iCalendar (.ics) file to the standard output.
+    Ics(IcsArgs),
 }
 
-fn query_date(args: &QueryArgs) {
-    // TODO: Use parse function when it's available.
-    let date = if let Some(date) = &args.date {
-        let y: i32 = date[0..4].parse().unwrap();
-        let m: u8 = date[4..6].parse().unwrap();
-        let d: u8 = date[6..8].parse().unwrap();
-        GregorianCalendar::from_ymd(y, m, d).unwrap().into()
-    } else {
-        Date::from_unix_time_with_tz(unix_time_now(), 8.0)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum EventKind {
+    ChineseFestivals,
+    SolarTerms,
+    NewMoons,
+    FullMoons,
+}
+
+#[derive(Args, Debug)]
+struct IcsArgs {
+    /// The Gregorian year of the events; the current year by default.
+    #[arg(short, long)]
+    year: Option<i32>,
+    /// The kinds of events to export, separated by commas.
+    #[arg(
+        long,
+        value_enum,
+        value_delimiter = ',',
+        default_value = "chinese-festivals,solar-terms"
+    )]
+    include: Vec<EventKind>,
+}
+
+/// Zones with fixed offsets, in hours.
+const ZONES: [(&str, f64); 18] = [
+    ("UTC", 0.0),
+    ("GMT", 0.0),
+    ("Etc/UTC", 0.0),
+    ("Asia/Dubai", 4.0),
+    ("Asia/Kolkata", 5.5),
+    ("Asia/Bangkok", 7.0),
+    ("Asia/Ho_Chi_Minh", 7.0),
+    ("Asia/Jakarta", 7.0),
+    ("PRC", 8.0),
+    ("Asia/Shanghai", 8.0),
+    ("Asia/Chongqing", 8.0),
+    ("Asia/Hong_Kong", 8.0),
+    ("Asia/Macau", 8.0),
+    ("Asia/Taipei", 8.0),
+    ("Asia/Singapore", 8.0),
+    ("Asia/Kuala_Lumpur", 8.0),
+    ("Asia/Tokyo", 9.0),
+    ("Asia/Seoul", 9.0),
+];
+
+/// Parses an offset like "8", "+08", "-5:30" or "+0530", in hours.
+fn parse_offset(s: &str) -> Option<f64> {
+    let (sign, unsigned) = match s.strip_prefix(['+', '-']) {
+        Some(rest) => (if s.starts_with('-') { -1.0 } else { 1.0 }, rest),
+        None => (1.0, s),
     };
-    if args.option.chinese {
-        println!("{}", ChineseDay::from(date));
-    }
-    if args.option.weekday {
-        println!("{}", date.weekday());
-    }
-    if args.option.lunar_phase {
-        println!("{}", date.lunar_phase(8.0).chinese());
+    let (h, m) = match unsigned.split_once(':') {
+        Some((h, m)) => (h, m),
+        None if unsigned.len() == 4 => unsigned.split_at(2),
+        None => (unsigned, "0"),
+    };
+    if h.is_empty() || !h.bytes().chain(m.bytes()).all(|b| b.is_ascii_digit()) {
+        return None;
     }
-    if args.option.lunar_phase_emoji {
-        println!("{}", date.lunar_phase(8.0).emoji());
+    let (h, m): (f64, f64) = (h.parse().ok()?, m.parse().ok()?);
+    (h <= 14.0 && m < 60.0).then_some(sign * (h + m / 60.0))
+}
+
+fn parse_tz(s: &str) -> Result<f64, String> {
+    let tz = ZONES
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(s))
+        .map(|(_, tz)| *tz)
+        .or_else(|| {
+            let offset = ["UTC", "GMT"]
+                .iter()
+                .find_map(|prefix| s.strip_prefix(prefix))
+                .unwrap_or(s);
+            parse_offset(offset)
+        });
+    tz.ok_or_else(|| format!("invalid timezone: {}", s))
+}
+
+/// Parses the `TZ` environment variable, which is either a zone name or a
+/// POSIX string like "CST-8", whose offset is west of UTC.
+fn parse_tz_env(s: &str) -> Option<f64> {
+    let s = s.strip_prefix(':').unwrap_or(s);
+    if let Some((_, tz)) = ZONES.iter().find(|(name, _)| *name == s) {
+        return Some(*tz);
     }
-    if args.option.solar_term {
-        if let Some(st) = date.solar_term(8.0) {
-            println!("{}", st.chinese());
-        }
+    let offset = s.trim_start_matches(|c: char| c.is_ascii_alphabetic());
+    if offset.is_empty() || offset.len() + 3 > s.len() {
+        return None;
     }
-    if !args.option.chinese
-        && !args.option.weekday
-        && !args.option.lunar_phase
-        && !args.option.lunar_phase_emoji
-        && !args.option.solar_term
-    {
-        println!("{}", ChineseDay::from(date));
+    // Any daylight saving time rules after the offset are ignored.
+    let end = offset[1..]
+        .find(|c: char| c.is_ascii_alphabetic())
+        .map_or(offset.len(), |i| i + 1);
+    parse_offset(&offset[..end]).map(|tz| -tz)
+}
+
+impl Cli {
+    fn tz(&self) -> f64 {
+        self.tz
+            .or_else(|| parse_tz_env(&std::env::var("TZ").ok()?))
+            .unwrap_or(8.0)
     }
 }
 
-fn main() {
-    let cli = Cli::parse();
+fn today(tz: f64) -> Date {
+    Date::from_unix_time_with_tz(unix_time_now(), tz)
+}
 
-    match &cli.command {
-        Some(Commands::Print(args)) => print_calendar(args),
-        Some(Commands::List(args)) => list_dates(args),
-        Some(Commands::Query(args)) => query_date(args),
-        None => print_calendar(&cli.args),
-    }
+/// An error of a subcommand, with an exit code telling its kind to scripts.
+#[derive(Debug, PartialEq)]
+enum CliError {
+    /// A failure to read a file, run a program or use the terminal (exit code
+    /// 1).
+    Failed(String),
+    /// An invalid input, e.g. "2024-13-01" (exit code 2, as for invalid
+    /// arguments).
+    Invalid(String),
+    /// A valid date or year outside the supported range (exit code 3).
+    OutOfRange(String),
+    /// Nothing found, e.g. no such Chinese date in the years (exit code 4).
+    NotFound(String),
+}
+
+impl CliError {
+    fn code(&self) -> i32 {
+        match self {
+            CliError::Failed(_) => 1,
+            CliError::Invalid(_) => 2,
+            CliError::OutOfRange(_) => 3,
+            CliError::NotFound(_) => 4,
+        }
+    }
+
+    fn exit(&self) -> ! {
+        eprintln!("error: {}", self);
+        std::process::exit(self.code());
+    }
+}
+
+impl std::fmt::Display for CliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CliError::Failed(message)
+            | CliError::Invalid(message)
+            | CliError::OutOfRange(message)
+            | CliError::NotFound(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+/// The Gregorian years whose days all calendars support; the Chinese day may
+/// be in the Chinese year before the Gregorian one.
+const SUPPORTED_YEARS: RangeInclusive<i32> =
+    *chinese::SUPPORTED_YEARS.start() + 1..=*chinese::SUPPORTED_YEARS.end();
+
+fn check_year(year: i32) -> Result<i32, CliError> {
+    if SUPPORTED_YEARS.contains(&year) {
+        Ok(year)
+    } else {
+        Err(CliError::OutOfRange(format!(
+            "year {} is outside the supported range {}..={}",
+            year,
+            SUPPORTED_YEARS.start(),
+            SUPPORTED_YEARS.end()
+        )))
+    }
+}
+
+fn check_date(date: Date) -> Result<Date, CliError> {
+    check_jd(date.midnight_jd(0.0))?;
+    check_year(GregorianDay::from(date).the_year().ord())?;
+    Ok(date)
+}
+
+fn chinese_day(date: Date) -> Result<ChineseDay, CliError> {
+    ChineseDay::try_from(date).map_err(|e| CliError::OutOfRange(e.to_string()))
+}
+
+/// The Chinese day of a date, or "unsupported" outside the Chinese calendar.
+fn chinese_text(date: Date) -> String {
+    ChineseDay::try_from(date).map_or_else(|_| "unsupported".to_string(), |day| day.to_string())
+}
+
+/// The Chinese year of a date, or its Gregorian year outside the Chinese
+/// calendar, which is as good to bound a search by.
+fn chinese_year_of(date: Date) -> i32 {
+    ChineseDay::try_from(date).map_or_else(|e| e.year, |day| day.the_year().ord())
+}
+
+/// Checks that a Julian date is of a day of the Gregorian calendar.
+fn check_jd(jd: f64) -> Result<f64, CliError> {
+    let years = gregorian::SUPPORTED_YEARS;
+    let first = GregorianCalendar::from_y(*years.start())
+        .unwrap()
+        .first_day();
+    let last = GregorianCalendar::from_y(*years.end()).unwrap().last_day();
+    if jd.is_finite() && (Date::from(first)..=Date::from(last)).contains(&Date::from_jd(jd)) {
+        Ok(jd)
+    } else {
+        Err(CliError::OutOfRange(format!(
+            "Julian date {} is outside the Gregorian years {}..={}",
+            jd,
+            years.start(),
+            years.end()
+        )))
+    }
+}
+
+/// Parses the date argument of a subcommand, today by default.
+fn parse_date_arg(date: Option<&str>, tz: f64) -> Result<Date, CliError> {
+    match date {
+        Some(date) => check_date(parse_natural_date(date, today(tz)).map_err(CliError::Invalid)?),
+        None => Ok(today(tz)),
+    }
+}
+
+fn parse_range(args: &RangeArgs, tz: f64) -> (i32, Option<u8>) {
+    match args {
+        RangeArgs {
+            year: Some(y),
+            month: Some(m),
+            ..
+        } => (*y, Some(*m)),
+        RangeArgs {
+            year: Some(y),
+            month: None,
+            ..
+        } => (*y, None),
+        RangeArgs {
+            year: None,
+            month: None,
+            calendar,
+        } => match calendar {
+            CalendarKind::Gregorian => {
+                let today = GregorianDay::from(today(tz));
+                (today.the_year().ord(), Some(today.the_month().ord()))
+            }
+            CalendarKind::Chinese => {
+                let today = chinese_day(today(tz)).unwrap_or_else(|e| e.exit());
+                (today.the_year().ord(), Some(today.the_month().ord()))
+            }
+        },
+        _ => unreachable!(),
+    }
+}
+
+/// The first day and the day after the last day of the range.
+fn range_dates(args: &RangeArgs, tz: f64) -> Option<(Date, Date)> {
+    let (y, m) = parse_range(args, tz);
+    let (first, last): (Date, Date) = match (args.calendar, m) {
+        (CalendarKind::Gregorian, Some(m)) => {
+            let month = GregorianCalendar::from_ym(y, m)?;
+            (month.first_day().into(), month.last_day().into())
+        }
+        (CalendarKind::Gregorian, None) => {
+            let year = GregorianCalendar::from_y(y)?;
+            (year.first_day().into(), year.last_day().into())
+        }
+        (CalendarKind::Chinese, Some(m)) => {
+            let month = ChineseCalendar::from_ym(y, m)?;
+            (month.first_day().into(), month.last_day().into())
+        }
+        (CalendarKind::Chinese, None) => {
+            let year = ChineseCalendar::from_y(y)?;
+            (year.first_day().into(), year.last_day().into())
+        }
+    };
+    Some((first, last.succ()))
+}
+
+fn invalid_range(args: &RangeArgs, tz: f64) -> ! {
+    let (year, month) = parse_range(args, tz);
+    let error = check_year(year).err().unwrap_or_else(|| {
+        CliError::Invalid(format!(
+            "invalid {:?} month: {}",
+            args.calendar,
+            month.unwrap_or_default()
+        ))
+    });
+    error.exit()
+}
+
+fn calendar_lines(
+    args: &PrintArgs,
+    events: &events::Events,
+    tz: f64,
+    color: bool,
+    terminal_width: Option<usize>,
+) -> Vec<String> {
+    let (y, m) = parse_range(&args.range, tz);
+    let Some((begin, end)) = range_dates(&args.range, tz) else {
+        invalid_range(&args.range, tz);
+    };
+    // Days of the adjacent months may be shown in the first and last weeks.
+    if args.chinese {
+        if let Err(e) = check_date(begin + -7).and(check_date(end + 7)) {
+            e.exit();
+        }
+    }
+    let mut painter = Painter::new(color, today(tz), begin + -7, end + 7, tz);
+    painter.add_events(events, begin + -7, end + 7);
+    let layout = Layout {
+        first_weekday: args.first_weekday.unwrap_or(FirstWeekday::Mon).weekday(),
+        week_numbers: args.week_numbers,
+        chinese: args.chinese,
+    };
+    let columns = |width| match args.columns {
+        Some(columns) => columns as usize,
+        None => fit_columns(width, terminal_width),
+    };
+    let mut lines = Vec::new();
+    match (args.range.calendar, m) {
+        (CalendarKind::Gregorian, Some(m)) => {
+            let Some(month) = GregorianCalendar::from_ym(y, m) else {
+                invalid_range(&args.range, tz);
+            };
+            lines.push(center(
+                &format!("{:-}", month),
+                layout.width(month_cell_width(&layout)),
+            ));
+            lines.extend(month_lines(month, &layout, &painter));
+        }
+        (CalendarKind::Gregorian, None) => {
+            let Some(year) = GregorianCalendar::from_y(y) else {
+                invalid_range(&args.range, tz);
+            };
+            let width = layout.width(month_cell_width(&layout));
+            let columns = columns(width);
+            let year_width = width * columns + 2 * (columns - 1);
+            lines.push(center(&format!("Year {}", year), year_width));
+            let months: Vec<_> = year
+                .months()
+                .map(|month| {
+                    let mut lines = vec![center(month.name().as_ref(), width)];
+                    lines.extend(month_lines(month, &layout, &painter));
+                    lines
+                })
+                .collect();
+            lines.extend(side_by_side(&months, columns, width));
+        }
+        (CalendarKind::Chinese, Some(m)) => {
+            let Some(month) = ChineseCalendar::from_ym(y, m) else {
+                invalid_range(&args.range, tz);
+            };
+            lines.push(center(&month.to_string(), layout.width(6)));
+            lines.extend(chinese_month_lines(month, &layout, &painter));
+        }
+        (CalendarKind::Chinese, None) => {
+            let Some(year) = ChineseCalendar::from_y(y) else {
+                invalid_range(&args.range, tz);
+            };
+            let width = layout.width(6);
+            let columns = columns(width);
+            let year_width = width * columns + 2 * (columns - 1);
+            lines.push(center(&format!("{:#}", year), year_width));
+            let months: Vec<_> = year
+                .months()
+                .map(|month| {
+                    let mut lines = vec![center(&month.name_with_length(), width)];
+                    lines.extend(chinese_month_lines(month, &layout, &painter));
+                    lines
+                })
+                .collect();
+            lines.extend(side_by_side(&months, columns, width));
+        }
+    }
+    lines
+}
+
+/// The number of months of the given width to put side by side in a year,
+/// the most that fit in the terminal among the divisors of 12 up to 6, or 3
+/// without a terminal.
+fn fit_columns(width: usize, terminal_width: Option<usize>) -> usize {
+    let Some(terminal_width) = terminal_width else {
+        return 3;
+    };
+    [6, 4, 3, 2]
+        .into_iter()
+        .find(|columns| width * columns + 2 * (columns - 1) <= terminal_width)
+        .unwrap_or(1)
+}
+
+/// How the weeks of a month are laid out.
+struct Layout {
+    first_weekday: Weekday,
+    /// Whether each week starts with its ISO week number.
+    week_numbers: bool,
+    /// Whether each Gregorian day is annotated with the Chinese calendar.
+    chinese: bool,
+}
+
+impl Layout {
+    /// The number of columns a month takes with cells of the given width.
+    fn width(&self, cell_width: usize) -> usize {
+        cell_width * 7 + if self.week_numbers { 3 } else { 0 }
+    }
+}
+
+/// The styles of the days in a printed calendar, as ANSI escape sequences.
+struct Painter {
+    color: bool,
+    today: Date,
+    solar_terms: BTreeMap<Date, SolarTerm>,
+    festivals: BTreeSet<Date>,
+    events: BTreeSet<Date>,
+}
+
+impl Painter {
+    /// Collects the days to style from `begin` (inclusive) to `end`
+    /// (exclusive).
+    fn new(color: bool, today: Date, begin: Date, end: Date, tz: f64) -> Self {
+        let mut painter = Self {
+            color,
+            today,
+            solar_terms: astronomy::find_solar_terms(begin, end, tz)
+                .into_iter()
+                .collect(),
+            festivals: BTreeSet::new(),
+            events: BTreeSet::new(),
+        };
+        if color {
+            let years = chinese_year_of(begin)..=chinese_year_of(end);
+            painter.festivals = years
+                .flat_map(chinese::festivals::festivals)
+                .map(|(_, date)| date)
+                .collect();
+        }
+        painter
+    }
+
+    /// Collects the days of events from `begin` (inclusive) to `end`
+    /// (exclusive).
+    fn add_events(&mut self, events: &events::Events, begin: Date, end: Date) {
+        if events.is_empty() {
+            return;
+        }
+        let mut date = begin;
+        while date < end {
+            if !events.names(date).is_empty() {
+                self.events.insert(date);
+            }
+            date = date.succ();
+        }
+    }
+
+    /// Formats the label of a day as a cell of the given width, bracketing
+    /// today and styling the day by its kind.
+    fn paint(&self, date: Date, label: &str, in_month: bool, width: usize) -> String {
+        let label = if date == self.today && in_month {
+            format!("[{}]", label)
+        } else {
+            label.to_string()
+        };
+        self.style(date, pad(&center(&label, width), width), in_month)
+    }
+
+    /// Styles the cell of a day by its kind.
+    fn style(&self, date: Date, cell: String, in_month: bool) -> String {
+        if !self.color {
+            return cell;
+        }
+        let style = if !in_month {
+            "2"
+        } else if date == self.today {
+            "1;7"
+        } else if self.events.contains(&date) {
+            "1;4;36"
+        } else if self.festivals.contains(&date) {
+            "1;35"
+        } else if self.solar_terms.contains_key(&date) {
+            "32"
+        } else if matches!(date.weekday(), Saturday | Sunday) {
+            "31"
+        } else {
+            return cell;
+        };
+        format!("\x1b[{}m{}\x1b[0m", style, cell)
+    }
+}
+
+fn print_lines(lines: &[String]) {
+    for line in lines {
+        println!("{}", line.trim_end());
+    }
+}
+
+/// The width and height of the terminal, if the output is one.
+#[cfg(not(target_arch = "wasm32"))]
+fn terminal_size() -> Option<(usize, usize)> {
+    use std::io::IsTerminal as _;
+
+    // The size may be that of standard error or input otherwise.
+    if !std::io::stdout().is_terminal() {
+        return None;
+    }
+    let (terminal_size::Width(width), terminal_size::Height(height)) =
+        terminal_size::terminal_size()?;
+    Some((width as usize, height as usize))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn terminal_size() -> Option<(usize, usize)> {
+    None
+}
+
+/// Prints the lines, through the pager of `$PAGER` or `less` if they do not
+/// fit in the terminal.
+fn page_lines(lines: &[String], pager: bool) {
+    use std::io::Write as _;
+    use std::process::{Command, Stdio};
+
+    let fits = terminal_size().is_none_or(|(_, height)| lines.len() < height);
+    if !pager || fits {
+        return print_lines(lines);
+    }
+    let command = std::env::var("PAGER")
+        .ok()
+        .filter(|command| !command.trim().is_empty())
+        .unwrap_or_else(|| "less -FRX".to_string());
+    let mut words = command.split_whitespace();
+    let child = Command::new(words.next().unwrap())
+        .args(words)
+        .stdin(Stdio::piped())
+        .spawn();
+    let Ok(mut child) = child else {
+        return print_lines(lines);
+    };
+    if let Some(mut stdin) = child.stdin.take() {
+        for line in lines {
+            // The pager may be quit before reading all.
+            if writeln!(stdin, "{}", line.trim_end()).is_err() {
+                break;
+            }
+        }
+    }
+    let _ = child.wait();
+}
+
+/// Truncates the text to the given number of columns, ending it with "…" if
+/// truncated.
+fn truncate(text: &str, width: usize) -> String {
+    if display_width(text) <= width {
+        return text.to_string();
+    }
+    let mut truncated = String::new();
+    let mut used = 0;
+    for c in text.chars() {
+        used += if c.is_ascii() { 1 } else { 2 };
+        // Leave a column for the ellipsis.
+        if used + 1 > width {
+            break;
+        }
+        truncated.push(c);
+    }
+    truncated + "…"
+}
+
+/// Lays out the blocks of lines in rows of the given number of blocks, each
+/// block taking the given number of columns.
+fn side_by_side(blocks: &[Vec<String>], columns: usize, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for row in blocks.chunks(columns) {
+        let height = row.iter().map(Vec::len).max().unwrap_or(0);
+        for i in 0..height {
+            let line: Vec<_> = row
+                .iter()
+                .map(|block| pad(block.get(i).map_or("", String::as_str), width))
+                .collect();
+            lines.push(line.join("  ").trim_end().to_string());
+        }
+    }
+    lines
+}
+
+/// The number of columns the text takes, where a non-ASCII character is
+/// assumed to take two columns and ANSI escape sequences take none.
+fn display_width(text: &str) -> usize {
+    let mut width = 0;
+    let mut escaped = false;
+    for c in text.chars() {
+        match c {
+            '\x1b' => escaped = true,
+            'm' if escaped => escaped = false,
+            _ if escaped => {}
+            _ if c.is_ascii() => width += 1,
+            _ => width += 2,
+        }
+    }
+    width
+}
+
+/// Centers the text in the given number of columns.
+fn center(text: &str, width: usize) -> String {
+    let left = width.saturating_sub(display_width(text)) / 2;
+    format!("{}{}", " ".repeat(left), text)
+}
+
+/// Pads the text with spaces to the given number of columns.
+fn pad(text: &str, width: usize) -> String {
+    let right = width.saturating_sub(display_width(text));
+    format!("{}{}", text, " ".repeat(right))
+}
+
+/// Lays out the weeks of a month in cells of the given width, showing the
+/// days of the adjacent months only if the output is colored.  Each week takes
+/// a second line if the days are annotated.
+fn week_lines(
+    header: impl Fn(Weekday) -> String,
+    days: RangeInclusive<Date>,
+    layout: &Layout,
+    painter: &Painter,
+    width: usize,
+    label: impl Fn(Date) -> String,
+    annotation: Option<&dyn Fn(Date) -> String>,
+) -> Vec<String> {
+    let (first, last) = (*days.start(), *days.end());
+    let first_weekday = layout.first_weekday;
+    let mut line = String::new();
+    if layout.week_numbers {
+        line += "   ";
+    }
+    let mut weekday = first_weekday;
+    for _ in Weekday::VARIANTS {
+        line += &header(weekday);
+        weekday = weekday.succ();
+    }
+    let mut lines = vec![line];
+    let (mut line, mut annotation_line) = (String::new(), String::new());
+    let mut date = first + -((first.weekday() as i32 - first_weekday as i32).rem_euclid(7));
+    while date <= last || date.weekday() != first_weekday {
+        if date.weekday() == first_weekday && layout.week_numbers {
+            // The week with most of its days in the row.
+            let monday = date + (Monday as i32 - first_weekday as i32).rem_euclid(7);
+            line += &format!("{:>2} ", GregorianDay::from(monday).iso_week().1);
+            annotation_line += "   ";
+        }
+        let in_month = days.contains(&date);
+        if in_month || painter.color {
+            line += &painter.paint(date, &label(date), in_month, width);
+            if let Some(annotation) = annotation {
+                let cell = pad(&center(&annotation(date), width), width);
+                annotation_line += &painter.style(date, cell, in_month);
+            }
+        } else {
+            line += &" ".repeat(width);
+            annotation_line += &" ".repeat(width);
+        }
+        if date.weekday() == first_weekday.pred() {
+            lines.push(std::mem::take(&mut line));
+            if annotation.is_some() {
+                lines.push(std::mem::take(&mut annotation_line));
+            }
+        }
+        date = date.succ();
+    }
+    lines
+}
+
+fn month_cell_width(layout: &Layout) -> usize {
+    if layout.chinese {
+        6
+    } else {
+        4
+    }
+}
+
+/// The festival, the solar term, the month on its first day, or the day of
+/// the Chinese calendar.
+fn chinese_annotation(date: Date, painter: &Painter) -> String {
+    let Ok(day) = ChineseDay::try_from(date) else {
+        return String::new();
+    };
+    if let Some(festival) = day.festival() {
+        festival.chinese().to_string()
+    } else if let Some(solar_term) = painter.solar_terms.get(&date) {
+        solar_term.chinese().to_string()
+    } else if day.ord() == 1 {
+        day.the_month().name()
+    } else {
+        day.name().to_string()
+    }
+}
+
+fn month_lines(month: GregorianMonth, layout: &Layout, painter: &Painter) -> Vec<String> {
+    let width = month_cell_width(layout);
+    let annotation = |date| chinese_annotation(date, painter);
+    week_lines(
+        |weekday| pad(&format!(" {:3}", weekday), width),
+        month.first_day().into()..=month.last_day().into(),
+        layout,
+        painter,
+        width,
+        |date| format!("{:>2}", GregorianDay::from(date).ord()),
+        layout
+            .chinese
+            .then_some(&annotation as &dyn Fn(Date) -> String),
+    )
+}
+
+fn chinese_month_lines(month: ChineseMonth, layout: &Layout, painter: &Painter) -> Vec<String> {
+    week_lines(
+        |weekday| format!("  {:#1}  ", weekday),
+        month.first_day().into()..=month.last_day().into(),
+        layout,
+        painter,
+        6,
+        |date| ChineseDay::try_from(date).map_or(String::new(), |day| day.name().to_string()),
+        None,
+    )
+}
+
+fn format_cell(
+    column: Column,
+    day: GregorianDay,
+    chinese_day: Option<ChineseDay>,
+    args: &ListArgs,
+    events: &events::Events,
+    tz: f64,
+) -> String {
+    let date: Date = day.into();
+    let options = &args.option;
+    match column {
+        // The month is in the heading of the agenda.
+        Column::Date if args.agenda => format!("{:>2}", day.ord()),
+        Column::Date if args.format == OutputFormat::Text => format!("{:#}", day),
+        Column::Date => day.to_iso8601(),
+        Column::Chinese => chinese_day.map_or(String::new(), |day| options.format_chinese_day(day)),
+        Column::Weekday => format!("{:#}", day.weekday()),
+        Column::LunarPhase => options.format_lunar_phase(date.lunar_phase(tz)),
+        Column::LunarPhaseEmoji => date.lunar_phase(tz).emoji().to_string(),
+        Column::SolarTerm => date
+            .solar_term(tz)
+            .map_or(String::new(), |st| options.format_solar_term(st)),
+        Column::Events => events.names(date).join(", "),
+    }
+}
+
+fn format_row(cells: &[String], format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Text => {
+            let cells: Vec<_> = cells
+                .iter()
+                .filter(|cell| !cell.is_empty())
+                .cloned()
+                .collect();
+            cells.join(" ")
+        }
+        OutputFormat::Csv => {
+            let cells: Vec<_> = cells
+                .iter()
+                .map(|cell| {
+                    if cell.contains([',', '"', '\n']) {
+                        format!("\"{}\"", cell.replace('"', "\"\""))
+                    } else {
+                        cell.clone()
+                    }
+                })
+                .collect();
+            cells.join(",")
+        }
+        OutputFormat::Tsv => cells.join("\t"),
+    }
+}
+
+/// The lines listing the days, with those of text truncated to the width of
+/// the terminal if any.
+fn list_lines(
+    args: &ListArgs,
+    events: &events::Events,
+    tz: f64,
+    terminal_width: Option<usize>,
+) -> Vec<String> {
+    let (first, end) = match (args.from, args.to) {
+        (Some(from), Some(to)) => (from, to.succ()),
+        _ => range_dates(&args.range, tz).unwrap_or_else(|| invalid_range(&args.range, tz)),
+    };
+    let mut columns = args.columns();
+    if args.columns.is_empty() && !events.is_empty() {
+        columns.push(Column::Events);
+    }
+    let mut lines = Vec::new();
+    if args.format != OutputFormat::Text {
+        let header: Vec<_> = columns
+            .iter()
+            .map(|column| column.to_possible_value().unwrap().get_name().to_string())
+            .collect();
+        lines.push(format_row(&header, args.format));
+    }
+    let use_chinese = columns.contains(&Column::Chinese)
+        || args
+            .only
+            .iter()
+            .any(|filter| matches!(filter, Filter::ChineseDay(_)));
+    let mut chinese_day: Option<ChineseDay> = None;
+    let mut rows = Vec::new();
+    let mut date = first;
+    while date < end {
+        if use_chinese {
+            chinese_day = match chinese_day {
+                Some(chinese_day) => Some(chinese_day.succ()),
+                None => ChineseDay::try_from(date).ok(),
+            };
+        }
+        if !args
+            .only
+            .iter()
+            .all(|filter| filter.matches(date, chinese_day, tz))
+        {
+            date = date.succ();
+            continue;
+        }
+        let cells: Vec<_> = columns
+            .iter()
+            .map(|column| format_cell(*column, date.into(), chinese_day, args, events, tz))
+            .collect();
+        if args.agenda {
+            rows.push((date, cells));
+        } else {
+            lines.push(format_row(&cells, args.format));
+        }
+        date = date.succ();
+    }
+    if args.agenda {
+        lines = agenda_lines(&rows);
+    }
+    match terminal_width {
+        Some(width) if args.format == OutputFormat::Text => {
+            lines.iter().map(|line| truncate(line, width)).collect()
+        }
+        _ => lines,
+    }
+}
+
+/// Lays out the rows of the days in aligned columns, with a heading for each
+/// month and a blank line before each week.
+fn agenda_lines(rows: &[(Date, Vec<String>)]) -> Vec<String> {
+    let mut widths = Vec::new();
+    for (_, cells) in rows {
+        widths.resize(widths.len().max(cells.len()), 0);
+        for (width, cell) in widths.iter_mut().zip(cells) {
+            *width = (*width).max(display_width(cell));
+        }
+    }
+    // The days since the start of the week, with weeks starting on Monday.
+    let week_day = |date: Date| date.weekday() as i32 - Weekday::first() as i32;
+    let mut lines = Vec::new();
+    let mut previous: Option<Date> = None;
+    for (date, cells) in rows {
+        let month = GregorianDay::from(*date).the_month();
+        match previous {
+            Some(previous) if GregorianDay::from(previous).the_month() == month => {
+                if *date - previous > week_day(*date) {
+                    lines.push(String::new());
+                }
+            }
+            _ => {
+                if previous.is_some() {
+                    lines.push(String::new());
+                }
+                lines.push(format!("{:-}", month));
+            }
+        }
+        previous = Some(*date);
+        let line: Vec<_> = cells
+            .iter()
+            .zip(&widths)
+            .filter(|(_, width)| **width > 0)
+            .map(|(cell, width)| pad(cell, *width))
+            .collect();
+        lines.push(line.join(" ").trim_end().to_string());
+    }
+    lines
+}
+
+fn query_date(args: &QueryArgs, events: &events::Events, tz: f64) {
+    let date = parse_date_arg(args.date.as_deref(), tz).unwrap_or_else(|e| e.exit());
+    let chinese_day = || chinese_day(date).unwrap_or_else(|e| e.exit());
+    if args.all {
+        return print_lines(&attribute_lines(&date_attributes(date, tz), args.json));
+    }
+    if args.option.chinese {
+        println!("{}", args.option.format_chinese_day(chinese_day()));
+    }
+    if args.option.weekday {
+        println!("{}", date.weekday());
+    }
+    if args.option.lunar_phase {
+        println!("{}", args.option.format_lunar_phase(date.lunar_phase(tz)));
+    }
+    if args.option.lunar_phase_emoji {
+        println!("{}", date.lunar_phase(tz).emoji());
+    }
+    if args.option.solar_term {
+        if let Some(st) = date.solar_term(tz) {
+            println!("{}", args.option.format_solar_term(st));
+        }
+    }
+    if !args.option.chinese
+        && !args.option.weekday
+        && !args.option.lunar_phase
+        && !args.option.lunar_phase_emoji
+        && !args.option.solar_term
+    {
+        println!("{}", args.option.format_chinese_day(chinese_day()));
+    }
+    for name in events.names(date) {
+        println!("{}", name);
+    }
+}
+
+fn parse_date(calendar: CalendarKind, s: &str) -> Option<Date> {
+    let any_calendar = calendar.calendar();
+    let day = any_calendar.parse_day(s).or_else(|| {
+        // A Gregorian date may also be written without separators, e.g.
+        // "20240210".
+        if calendar != CalendarKind::Gregorian {
+            return None;
+        }
+        let (y, m, d) = (s.get(0..4)?, s.get(4..6)?, s.get(6..)?);
+        any_calendar.day(y.parse().ok()?, m.parse().ok()?, d.parse().ok()?)
+    })?;
+    Some(day.date())
+}
+
+/// Parses a Gregorian or Chinese date.
+fn parse_any_date(s: &str) -> Result<Date, String> {
+    parse_date(CalendarKind::Gregorian, s)
+        .or_else(|| parse_date(CalendarKind::Chinese, s))
+        .ok_or_else(|| format!("invalid date: {}", s))
+}
+
+/// Parses a date as people write it, relative to `today`: a Gregorian or
+/// Chinese date, a relative day or weekday, or a Chinese month and day, which
+/// is resolved to the nearest occurrence.
+fn parse_natural_date(s: &str, today: Date) -> Result<Date, String> {
+    let s = s.trim();
+    let lower = s.to_lowercase();
+    match lower.as_str() {
+        "today" | "今天" => return Ok(today),
+        "tomorrow" | "明天" => return Ok(today.succ()),
+        "yesterday" | "昨天" => return Ok(today.pred()),
+        _ => {}
+    }
+    let weekday = |name: &str| {
+        Weekday::VARIANTS
+            .iter()
+            .find(|weekday| {
+                let full = weekday.as_ref().to_lowercase();
+                full == name || (name.len() >= 3 && full.starts_with(name))
+            })
+            .map(|&weekday| (weekday as i32 - today.weekday() as i32).rem_euclid(7))
+    };
+    if let Some(days) = lower.strip_prefix("next ").and_then(weekday) {
+        return Ok(today + if days == 0 { 7 } else { days });
+    }
+    if let Some(days) = lower.strip_prefix("last ").and_then(weekday) {
+        return Ok(today + (days - 7));
+    }
+    let parts: Vec<_> = s.split(['/', '.']).collect();
+    if let [y, m, d] = parts[..] {
+        if let Some(day) =
+            (|| GregorianCalendar::from_ymd(y.parse().ok()?, m.parse().ok()?, d.parse().ok()?))()
+        {
+            return Ok(day.into());
+        }
+    }
+    if let Ok(date) = parse_any_date(s) {
+        return Ok(date);
+    }
+    if !s.contains('年') {
+        let year = chinese_day(today)
+            .map_err(|e| e.to_string())?
+            .the_year()
+            .ord();
+        if let Some(date) = (year - 1..=year + 1)
+            .filter_map(|y| ChineseDay::parse_with_pivot(&format!("{}年{}", y, s), y).ok())
+            .map(Date::from)
+            .min_by_key(|&date| (date - today).abs())
+        {
+            return Ok(date);
+        }
+    }
+    Err(format!("invalid date: {}", s))
+}
+
+fn format_date(calendar: CalendarKind, date: Date) -> Result<String, CliError> {
+    let any_calendar = calendar.calendar();
+    let day = any_calendar.day_of_date(date).ok_or_else(|| {
+        CliError::OutOfRange(format!(
+            "{} is outside the {} calendar",
+            GregorianDay::from(date).to_iso8601(),
+            any_calendar.display_name()
+        ))
+    })?;
+    Ok(match calendar {
+        CalendarKind::Gregorian => day.to_string(),
+        // In full, e.g. "公元2024年农历甲辰年正月初一".
+        CalendarKind::Chinese => format!("{:#}", day),
+    })
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => json += "\\\"",
+            '\\' => json += "\\\\",
+            '\n' => json += "\\n",
+            c if c.is_control() => json += &format!("\\u{:04x}", c as u32),
+            c => json.push(c),
+        }
+    }
+    json + "\""
+}
+
+/// Converts a date to a line of output, or to an error.
+fn convert_line(args: &ConvertArgs, input: &str) -> Result<String, CliError> {
+    let date = parse_date(args.from, input)
+        .ok_or_else(|| {
+            CliError::Invalid(format!(
+                "invalid {} date: {}",
+                args.from.calendar().display_name(),
+                input
+            ))
+        })
+        .and_then(check_date)
+        .and_then(|date| {
+            args.to
+                .iter()
+                .map(|calendar| format_date(*calendar, date))
+                .collect::<Result<Vec<_>, _>>()
+        });
+    if args.json {
+        let mut fields = vec![format!("\"input\":{}", json_string(input))];
+        match date {
+            Ok(dates) => fields.extend(args.to.iter().zip(dates).map(|(calendar, date)| {
+                let name = calendar.to_possible_value().unwrap().get_name().to_string();
+                format!("{}:{}", json_string(&name), json_string(&date))
+            })),
+            Err(CliError::Invalid(_)) => fields.push(format!(
+                "\"error\":{}",
+                json_string(&format!(
+                    "invalid {} date",
+                    args.from.calendar().display_name()
+                ))
+            )),
+            Err(e) => fields.push(format!("\"error\":{}", json_string(&e.to_string()))),
+        }
+        return Ok(format!("{{{}}}", fields.join(",")));
+    }
+    let separator = if args.stdin { "\t" } else { "\n" };
+    Ok(date?.join(separator))
+}
+
+fn convert_date(args: &ConvertArgs) {
+    use std::io::{BufRead as _, Write as _};
+    let Some(date) = &args.date else {
+        let mut failed = None;
+        let mut stdout = std::io::BufWriter::new(std::io::stdout().lock());
+        for line in std::io::stdin().lock().lines() {
+            let Ok(line) = line else {
+                break;
+            };
+            let output = convert_line(args, line.trim()).unwrap_or_else(|e| {
+                // An empty line keeps the output aligned with the input.
+                eprintln!("error: {}", e);
+                failed = Some(e);
+                String::new()
+            });
+            if writeln!(stdout, "{}", output).is_err() {
+                break;
+            }
+        }
+        let _ = stdout.flush();
+        // The exit code tells the kind of the last error.
+        if let Some(e) = failed {
+            std::process::exit(e.code());
+        }
+        return;
+    };
+    match convert_line(args, date) {
+        Ok(output) => println!("{}", output),
+        Err(e) => e.exit(),
+    }
+}
+
+fn list_holidays(args: &HolidaysArgs, tz: f64) {
+    use holiday::HolidayProvider as _;
+    let year = args
+        .year
+        .map_or_else(
+            || Ok(GregorianDay::from(today(tz)).the_year().ord()),
+            check_year,
+        )
+        .unwrap_or_else(|e| e.exit());
+    let region = args.region.unwrap_or(holiday::Region::China);
+    for holiday in region.holidays(year) {
+        let mut line = format!(
+            "{} {:<9} {}",
+            GregorianDay::from(holiday.date).to_iso8601(),
+            holiday.date.weekday(),
+            holiday.name
+        );
+        if holiday.observed != holiday.date {
+            line += &format!(
+                " (observed {})",
+                GregorianDay::from(holiday.observed).to_iso8601()
+            );
+        }
+        println!("{}", line);
+    }
+}
+
+fn search_next(args: &SearchArgs, tz: f64) {
+    // The dates are scanned in windows, for up to about three centuries.
+    const WINDOW: i32 = 400;
+    const MAX_WINDOWS: i32 = 300;
+    let from = args.from.unwrap_or_else(|| today(tz));
+    let mut found = Vec::new();
+    for i in 0..MAX_WINDOWS {
+        if found.len() >= args.count {
+            break;
+        }
+        if args.before {
+            let mut dates = args
+                .event
+                .dates_in(from + -(i + 1) * WINDOW, from + -i * WINDOW, tz);
+            dates.reverse();
+            found.extend(dates);
+        } else {
+            let begin = from.succ() + i * WINDOW;
+            found.extend(args.event.dates_in(begin, begin + WINDOW, tz));
+        }
+    }
+    if found.is_empty() {
+        CliError::NotFound(format!("no {:?} found", args.event)).exit();
+    }
+    for date in found.into_iter().take(args.count) {
+        println!("{}", GregorianDay::from(date).to_iso8601());
+    }
+}
+
+fn parse_period(s: &str) -> Result<(i32, Option<u8>), String> {
+    let digits = s.replace('-', "");
+    let period = match digits.len() {
+        4 => digits.parse().ok().map(|year| (year, None)),
+        6 => digits[..4]
+            .parse()
+            .ok()
+            .zip(
+                digits[4..]
+                    .parse()
+                    .ok()
+                    .filter(|month| (1..=12).contains(month)),
+            )
+            .map(|(year, month)| (year, Some(month))),
+        _ => None,
+    };
+    period.ok_or_else(|| format!("invalid year or month: {}", s))
+}
+
+/// The local time of an instant given as a Julian date in TT.
+fn instant(jd: f64, tz: f64) -> chinese::DateTime {
+    chinese::DateTime::from_tt_jd(jd, tz, Some(&timescale::LeapSecondTable::builtin()))
+}
+
+fn format_instant(time: chinese::DateTime) -> String {
+    format!(
+        "{} {:02}:{:02}",
+        GregorianDay::from(Date::from(time.day())).to_iso8601(),
+        time.hour(),
+        time.minute()
+    )
+}
+
+fn terms_lines(year: i32, tz: f64) -> Vec<String> {
+    let year = GregorianCalendar::from_y(year).unwrap();
+    let (begin, end) = (year.first_day().into(), Date::from(year.last_day()).succ());
+    astronomy::find_solar_term_instants(begin, end, tz)
+        .into_iter()
+        .map(|(jd, term)| {
+            let time = instant(jd, tz);
+            format!(
+                "{} {} {} {}",
+                format_instant(time),
+                pad(&time.day().to_string(), 18),
+                term.chinese(),
+                term.english()
+            )
+        })
+        .collect()
+}
+
+fn moon_lines(year: i32, month: Option<u8>, daily: bool, tz: f64) -> Vec<String> {
+    let (first, last) = match month {
+        Some(month) => {
+            let month = GregorianCalendar::from_ym(year, month).unwrap();
+            (month.first_day(), month.last_day())
+        }
+        None => {
+            let year = GregorianCalendar::from_y(year).unwrap();
+            (year.first_day(), year.last_day())
+        }
+    };
+    let (begin, end) = (Date::from(first), Date::from(last).succ());
+    let mut lines: Vec<_> = astronomy::find_lunar_phase_instants(begin, end, tz)
+        .into_iter()
+        .map(|(jd, phase)| {
+            format!(
+                "{} {} {:<12} {}",
+                format_instant(instant(jd, tz)),
+                phase.emoji(),
+                phase,
+                phase.chinese()
+            )
+        })
+        .collect();
+    if daily {
+        lines.push(String::new());
+        let phases = astronomy::get_lunar_phases(begin, end, tz);
+        for (i, phase) in phases.into_iter().enumerate() {
+            let date = begin + i as i32;
+            lines.push(format!(
+                "{} {} {:5.1}%",
+                GregorianDay::from(date).to_iso8601(),
+                phase.emoji(),
+                astronomy::get_moon_illumination(date.noon_jd(tz)) * 100.0
+            ));
+        }
+    }
+    lines
+}
+
+fn parse_festival(s: &str) -> Option<ChineseFestival> {
+    let normalize = |s: &str| {
+        s.chars()
+            .filter(|c| !matches!(c, ' ' | '-' | '_'))
+            .collect::<String>()
+            .to_lowercase()
+    };
+    let name = normalize(s);
+    ChineseFestival::VARIANTS.iter().copied().find(|festival| {
+        let english = normalize(&festival.to_string());
+        english == name
+            || english.strip_suffix("festival") == Some(&name)
+            || festival.chinese() == name
+            || festival.chinese().strip_suffix('节') == Some(&name)
+    })
+}
+
+/// Resolves a countdown target to a date, with the age on it for a birthday.
+fn countdown_target(args: &CountdownArgs, today: Date) -> Result<(Date, Option<i32>), String> {
+    let chinese_year = chinese_day(today)
+        .map_err(|e| e.to_string())?
+        .the_year()
+        .ord();
+    if args.birthday {
+        let birth = parse_natural_date(&args.target, today)?;
+        return if args.lunar {
+            let birth = chinese_day(birth).map_err(|e| e.to_string())?;
+            chinese::anniversary::Anniversary::from_day(birth)
+                .dates_in_range(chinese_year, chinese_year + 2)
+                .find(|&date| date >= today)
+                .map(|date| {
+                    let age = chinese_year_of(date) - birth.the_year().ord();
+                    (date, Some(age))
+                })
+        } else {
+            let birth = GregorianDay::from(birth);
+            let year = GregorianDay::from(today).the_year().ord();
+            (year..=year + 1)
+                .map(|y| y - birth.the_year().ord())
+                .filter_map(|age| Some((Date::from(birth.add_months(age * 12)?), Some(age))))
+                .find(|&(date, _)| date >= today)
+        }
+        .ok_or_else(|| {
+            format!(
+                "no birthday after {}",
+                GregorianDay::from(today).to_iso8601()
+            )
+        });
+    }
+    let (year, name) = match args.target.split_once(' ') {
+        Some((year, name)) if year.chars().all(|c| c.is_ascii_digit()) => (year.parse().ok(), name),
+        _ => (None, args.target.as_str()),
+    };
+    if let Some(festival) = parse_festival(name) {
+        let date = match year {
+            Some(year) => festival.date(year),
+            None => (chinese_year - 1..=chinese_year + 1)
+                .filter_map(|year| festival.date(year))
+                .find(|&date| date >= today),
+        };
+        return date
+            .map(|date| (date, None))
+            .ok_or_else(|| format!("no {} found", festival));
+    }
+    Ok((parse_natural_date(&args.target, today)?, None))
+}
+
+fn countdown_lines(date: Date, age: Option<i32>, today: Date, tz: f64) -> Vec<String> {
+    let days = match date - today {
+        0 => "today".to_string(),
+        1 => "1 day".to_string(),
+        -1 => "1 day ago".to_string(),
+        days if days < 0 => format!("{} days ago", -days),
+        days => format!("{} days", days),
+    };
+    let lunar_phase = date.lunar_phase(tz);
+    let mut lines = vec![
+        format!(
+            "Date:        {} {}",
+            GregorianDay::from(date).to_iso8601(),
+            date.weekday()
+        ),
+        format!("Remaining:   {}", days),
+        format!("Chinese:     {}", chinese_text(date)),
+        format!(
+            "Lunar phase: {} {}",
+            lunar_phase.emoji(),
+            lunar_phase.chinese()
+        ),
+    ];
+    if let Some(age) = age {
+        lines.push(format!("Age:         {}", age));
+    }
+    lines
+}
+
+/// Parses a date with an optional time of day, given in seconds.
+fn parse_date_time(s: &str) -> Result<(Date, Option<u32>), String> {
+    let invalid = || format!("invalid date and time: {}", s);
+    let Some((date, time)) = s.trim().split_once(['T', ' ']) else {
+        return Ok((parse_any_date(s)?, None));
+    };
+    let fields = time
+        .split(':')
+        .map(|field| field.parse::<u32>().ok())
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(invalid)?;
+    let seconds = match fields[..] {
+        [h, m] if h < 24 && m < 60 => h * 3600 + m * 60,
+        [h, m, s] if h < 24 && m < 60 && s < 60 => h * 3600 + m * 60 + s,
+        _ => return Err(invalid()),
+    };
+    Ok((parse_any_date(date)?, Some(seconds)))
+}
+
+fn bazi_lines(date: Date, seconds: Option<u32>, tz: f64) -> Vec<String> {
+    // Without a time, take noon, which is in the same day pillar.
+    let jd = date.midnight_jd(tz) + seconds.unwrap_or(12 * 3600) as f64 / 86400.0;
+    let pillars = chinese::FourPillars::from_jd(jd, tz);
+    let mut rows = vec![
+        ("Year: ", pillars.year),
+        ("Month:", pillars.month),
+        ("Day:  ", pillars.day),
+    ];
+    if seconds.is_some() {
+        rows.push(("Hour: ", pillars.hour));
+    }
+    let mut lines: Vec<_> = rows
+        .into_iter()
+        .map(|(name, pillar)| {
+            let nayin = pillar.nayin();
+            format!(
+                "{} {:#} {:<8} {} {}",
+                name,
+                pillar,
+                pillar.to_string(),
+                nayin.chinese(),
+                nayin.english()
+            )
+        })
+        .collect();
+    let branch = pillars.year.branch();
+    lines.push(format!("Zodiac: {} {}", branch.animal(), branch.english()));
+    lines
+}
+
+fn almanac_lines(date: Date, tz: f64) -> Vec<String> {
+    let day = chinese_day(date).unwrap_or_else(|e| e.exit());
+    let pillars = chinese::FourPillars::from_jd(date.noon_jd(tz), tz);
+    let clash = day.clash();
+    let solar_term = match date.solar_term(tz) {
+        Some(solar_term) => format!("今日{}", solar_term.chinese()),
+        None => {
+            let (next, solar_term) = astronomy::find_solar_terms(date, date + 20, tz)[0];
+            format!("距{}{}天", solar_term.chinese(), next - date)
+        }
+    };
+    let auspicious_hours: Vec<_> = day
+        .hour_spirits()
+        .iter()
+        .enumerate()
+        .filter(|(_, spirit)| spirit.is_auspicious())
+        .map(|(i, _)| Branch::from_repr(i).unwrap().chinese().to_string())
+        .collect();
+    let mut lines = vec![
+        format!(
+            "公历 {} {:#}",
+            GregorianDay::from(date).to_iso8601(),
+            date.weekday()
+        ),
+        format!("农历 {}", day),
+        format!(
+            "干支 {:#}年 {:#}月 {:#}日",
+            pillars.year, pillars.month, pillars.day
+        ),
+        format!("纳音 {}", day.nayin().chinese()),
+        format!("建除 {}日", day.day_officer().chinese()),
+        format!("星宿 {}", date.lunar_mansion().chinese(3)),
+        format!(
+            "冲煞 冲{}({}) 煞{}",
+            clash.animal(),
+            clash.chinese(),
+            day.sha_direction()
+        ),
+        format!("节气 {}", solar_term),
+        format!("吉时 {}", auspicious_hours.join(" ")),
+    ];
+    if let Some(festival) = day.festival() {
+        lines.push(format!("节日 {}", festival.chinese()));
+    }
+    lines
+}
+
+/// Resolves the input of `jdn` to a Julian date in UT.
+fn parse_jdn_input(args: &JdnArgs, today: Date) -> Result<f64, String> {
+    if let Ok(date) = parse_natural_date(&args.input, today) {
+        return Ok(date.midnight_jd(0.0));
+    }
+    let number: f64 = args
+        .input
+        .parse()
+        .map_err(|_| format!("invalid date or day number: {}", args.input))?;
+    Ok(args.from.to_jd(number, !args.input.contains('.')))
+}
+
+fn jdn_lines(jd: f64) -> Vec<String> {
+    let date = Date::from_jd(jd);
+    let day = GregorianDay::from(date);
+    vec![
+        format!("Date:      {} {}", day.to_iso8601(), date.weekday()),
+        format!("Chinese:   {}", chinese_text(date)),
+        format!("JDN:       {}", date.jdn()),
+        format!("JD:        {}", jd),
+        format!("MJD:       {}", jd - 2400000.5),
+        format!("Unix days: {}", jd - 2440587.5),
+    ]
+}
+
+fn sun_lines(date: Date, lat: f64, lon: f64, tz: f64) -> Vec<String> {
+    use astronomy::AltitudeCrossing;
+    // Rounded to the nearest minute.
+    let time = |jd: f64| {
+        let time = ChineseDateTime::from_jd(jd + 30.0 / 86400.0, tz);
+        format!("{:02}:{:02}", time.hour(), time.minute())
+    };
+    let crossing = |altitude| astronomy::find_altitude_crossing(date, lat, lon, altitude, tz);
+    let rise_set = |crossing| match crossing {
+        AltitudeCrossing::RiseSet { rise, set } => (time(rise), time(set)),
+        AltitudeCrossing::AlwaysAbove => ("always above".to_string(), "always above".to_string()),
+        AltitudeCrossing::AlwaysBelow => ("always below".to_string(), "always below".to_string()),
+    };
+    let twilights = [
+        ("Astronomical", astronomy::ASTRONOMICAL_TWILIGHT_ALTITUDE),
+        ("Nautical", astronomy::NAUTICAL_TWILIGHT_ALTITUDE),
+        ("Civil", astronomy::CIVIL_TWILIGHT_ALTITUDE),
+    ]
+    .map(|(name, altitude)| (name, rise_set(crossing(altitude))));
+    let sun = crossing(astronomy::SUNRISE_ALTITUDE);
+    let (sunrise, sunset) = rise_set(sun);
+    let mut lines: Vec<_> = twilights
+        .iter()
+        .map(|(name, (dawn, _))| format!("{:<19} {}", format!("{} dawn:", name), dawn))
+        .collect();
+    lines.push(format!("{:<19} {}", "Sunrise:", sunrise));
+    lines.push(format!(
+        "{:<19} {}",
+        "Solar noon:",
+        time(astronomy::find_solar_noon(date, lon, tz))
+    ));
+    lines.push(format!("{:<19} {}", "Sunset:", sunset));
+    lines.extend(
+        twilights
+            .iter()
+            .rev()
+            .map(|(name, (_, dusk))| format!("{:<19} {}", format!("{} dusk:", name), dusk)),
+    );
+    let minutes = match sun {
+        AltitudeCrossing::RiseSet { rise, set } => ((set - rise) * 1440.0).round() as i32,
+        AltitudeCrossing::AlwaysAbove => 1440,
+        AltitudeCrossing::AlwaysBelow => 0,
+    };
+    lines.push(format!(
+        "{:<19} {}h {:02}m",
+        "Day length:",
+        minutes / 60,
+        minutes % 60
+    ));
+    lines
+}
+
+fn diff_lines(start: Date, end: Date) -> Vec<String> {
+    let (sign, start, end) = if end < start {
+        ("-", end, start)
+    } else {
+        ("", start, end)
+    };
+    let days = end - start;
+    let (years, months, rest) = GregorianDay::from(start)
+        .years_months_days_until(end.into())
+        .unwrap();
+    // Each weekday occurs once a week, and once more in the remaining days.
+    let weekdays: Vec<_> = Weekday::VARIANTS
+        .iter()
+        .map(|&weekday| {
+            let offset = (weekday as i32 - start.weekday() as i32).rem_euclid(7);
+            let count = days / 7 + (offset < days % 7) as i32;
+            format!("{} {}", &weekday.to_string()[..3], count)
+        })
+        .collect();
+    vec![
+        format!("Days:     {}{}", sign, days),
+        format!("Weeks:    {}{} weeks {} days", sign, days / 7, days % 7),
+        format!(
+            "Calendar: {}{} years {} months {} days",
+            sign, years, months, rest
+        ),
+        format!("Weekdays: {}", weekdays.join(", ")),
+    ]
+}
+
+#[cfg(feature = "render")]
+fn render_calendar(args: &RenderArgs, tz: f64) -> Result<(), String> {
+    let first_weekday = args.first_weekday.unwrap_or(FirstWeekday::Mon).weekday();
+    let today = GregorianDay::from(today(tz));
+    let svg = match (args.year, args.month) {
+        (Some(y), Some(m)) => {
+            let month = GregorianCalendar::from_ym(y, m).ok_or("invalid month")?;
+            render::month_svg(month, first_weekday, tz)
+        }
+        (Some(y), None) => {
+            let year = GregorianCalendar::from_y(y).ok_or("invalid year")?;
+            render::year_svg(year, first_weekday, tz)
+        }
+        _ => render::month_svg(today.the_month(), first_weekday, tz),
+    };
+    match &args.output {
+        Some(path)
+            if path
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("png")) =>
+        {
+            std::fs::write(path, render::to_png(&svg)?)
+        }
+        Some(path) => std::fs::write(path, svg),
+        None => {
+            print!("{}", svg);
+            Ok(())
+        }
+    }
+    .map_err(|e| e.to_string())
+}
+
+fn ics_date(date: Date) -> String {
+    let day = GregorianDay::from(date);
+    format!(
+        "{:04}{:02}{:02}",
+        day.the_year().ord(),
+        day.the_month().ord(),
+        day.ord()
+    )
+}
+
+/// Folds a content line into lines of at most 75 octets, as required by
+/// RFC 5545.
+fn ics_line(line: &str) -> String {
+    let mut folded = String::new();
+    let mut len = 0;
+    for c in line.chars() {
+        if len + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            len = 1;
+        }
+        folded.push(c);
+        len += c.len_utf8();
+    }
+    folded + "\r\n"
+}
+
+fn export_ics(args: &IcsArgs, tz: f64) {
+    let now = unix_time_now();
+    let year = args
+        .year
+        .map_or_else(
+            || Ok(GregorianDay::from(today(tz)).the_year().ord()),
+            check_year,
+        )
+        .unwrap_or_else(|e| e.exit());
+    let begin: Date = GregorianCalendar::from_y(year).unwrap().first_day().into();
+    let end: Date = GregorianCalendar::from_y(year + 1)
+        .unwrap()
+        .first_day()
+        .into();
+
+    let mut events: Vec<(Date, &str, String)> = Vec::new();
+    for kind in &args.include {
+        match kind {
+            EventKind::ChineseFestivals => {
+                for chinese_year in [year - 1, year] {
+                    for (festival, date) in chinese::festivals::festivals(chinese_year) {
+                        if (begin..end).contains(&date) {
+                            events.push((date, "festival", festival.chinese().to_string()));
+                        }
+                    }
+                }
+            }
+            EventKind::SolarTerms => {
+                for (date, st) in astronomy::find_solar_terms(begin, end, tz) {
+                    events.push((date, "solar-term", st.chinese().to_string()));
+                }
+            }
+            EventKind::NewMoons | EventKind::FullMoons => {
+                let phase = if *kind == EventKind::NewMoons {
+                    NewMoon
+                } else {
+                    FullMoon
+                };
+                for (i, lp) in astronomy::get_lunar_phases(begin, end, tz)
+                    .into_iter()
+                    .enumerate()
+                {
+                    if lp == phase {
+                        events.push((begin + i as i32, "moon", lp.chinese().to_string()));
+                    }
+                }
+            }
+        }
+    }
+    events.sort_by_key(|(date, _, _)| *date);
+
+    let stamp_day = GregorianDay::from(Date::from_unix_time(now));
+    let seconds = now % 86400;
+    let stamp = format!(
+        "{}T{:02}{:02}{:02}Z",
+        ics_date(stamp_day.into()),
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    );
+    let mut ics = String::new();
+    for line in [
+        "BEGIN:VCALENDAR",
+        "VERSION:2.0",
+        "PRODID:-//omnical//omnical//EN",
+        "CALSCALE:GREGORIAN",
+    ] {
+        ics += &ics_line(line);
+    }
+    for (date, kind, summary) in &events {
+        for line in [
+            "BEGIN:VEVENT".to_string(),
+            format!("UID:{}-{}@omnical", ics_date(*date), kind),
+            format!("DTSTAMP:{}", stamp),
+            format!("DTSTART;VALUE=DATE:{}", ics_date(*date)),
+            format!("DTEND;VALUE=DATE:{}", ics_date(date.succ())),
+            format!("SUMMARY:{}", summary),
+            "TRANSP:TRANSPARENT".to_string(),
+            "END:VEVENT".to_string(),
+        ] {
+            ics += &ics_line(&line);
+        }
+    }
+    ics += &ics_line("END:VCALENDAR");
+    print!("{}", ics);
+}
+
+fn main() {
+    let mut cli = Cli::parse();
+    match config::Config::load() {
+        Ok(config) => config.apply(&mut cli),
+        Err(e) => CliError::Failed(e.to_string()).exit(),
+    }
+    let tz = cli.tz();
+    let color = cli.color.enabled();
+    let terminal_width = terminal_size().map(|(width, _)| width);
+    let pager = !cli.no_pager;
+    let events = match &cli.events {
+        Some(path) => {
+            events::Events::load(path).unwrap_or_else(|e| CliError::Failed(e.to_string()).exit())
+        }
+        None => events::Events::default(),
+    };
+
+    match &cli.command {
+        Some(Commands::Print(args)) => page_lines(
+            &calendar_lines(args, &events, tz, color, terminal_width),
+            pager,
+        ),
+        Some(Commands::List(args)) => {
+            page_lines(&list_lines(args, &events, tz, terminal_width), pager)
+        }
+        Some(Commands::Query(args)) => query_date(args, &events, tz),
+        Some(Commands::Convert(args)) => convert_date(args),
+        Some(Commands::Export(ExportCommands::Ics(args))) => export_ics(args, tz),
+        Some(Commands::Holidays(args)) => list_holidays(args, tz),
+        Some(Commands::Search(SearchCommands::Next(args))) => search_next(args, tz),
+        Some(Commands::Diff(args)) => print_lines(&diff_lines(args.start, args.end)),
+        Some(Commands::Moon(args)) => {
+            let (year, month) = args.period.unwrap_or_else(|| {
+                let day = GregorianDay::from(today(tz));
+                (day.the_year().ord(), Some(day.the_month().ord()))
+            });
+            check_year(year).unwrap_or_else(|e| e.exit());
+            print_lines(&moon_lines(year, month, args.daily, tz));
+        }
+        Some(Commands::Countdown(args)) => {
+            let today = today(tz);
+            let (date, age) = countdown_target(args, today)
+                .map_err(CliError::Invalid)
+                .and_then(|(date, age)| Ok((check_date(date)?, age)))
+                .unwrap_or_else(|e| e.exit());
+            print_lines(&countdown_lines(date, age, today, tz));
+        }
+        Some(Commands::Bazi(args)) => {
+            let (date, seconds) = args.moment;
+            check_date(date).unwrap_or_else(|e| e.exit());
+            print_lines(&bazi_lines(date, seconds, tz));
+        }
+        Some(Commands::Almanac(args)) => {
+            let date = parse_date_arg(args.date.as_deref(), tz).unwrap_or_else(|e| e.exit());
+            print_lines(&almanac_lines(date, tz));
+        }
+        Some(Commands::Jdn(args)) => {
+            let jd = parse_jdn_input(args, today(tz))
+                .map_err(CliError::Invalid)
+                .and_then(check_jd)
+                .unwrap_or_else(|e| e.exit());
+            print_lines(&jdn_lines(jd));
+        }
+        Some(Commands::Sun(args)) => {
+            let date = parse_date_arg(args.date.as_deref(), tz).unwrap_or_else(|e| e.exit());
+            print_lines(&sun_lines(date, args.lat, args.lon, tz));
+        }
+        Some(Commands::Find(args)) => {
+            let years = args.years.clone().unwrap_or_else(|| {
+                let year = chinese_day(today(tz))
+                    .unwrap_or_else(|e| e.exit())
+                    .the_year()
+                    .ord();
+                year..=year + 9
+            });
+            if let Err(e) = check_year(*years.start()).and(check_year(*years.end())) {
+                e.exit();
+            }
+            let days = find_chinese_dates(&args.chinese, years);
+            if days.is_empty() {
+                CliError::NotFound(format!("no Chinese date {} in the years", args.chinese)).exit();
+            }
+            print_lines(&find_lines(&days));
+        }
+        Some(Commands::Weeks(args)) => {
+            let period = args.period.unwrap_or_else(|| {
+                WeeksPeriod::Year(GregorianDay::from(today(tz)).the_year().ord())
+            });
+            match period {
+                WeeksPeriod::Year(year) => {
+                    check_year(year).unwrap_or_else(|e| e.exit());
+                    print_lines(&weeks_lines(year))
+                }
+                WeeksPeriod::Week(monday) => {
+                    check_date(monday).unwrap_or_else(|e| e.exit());
+                    print_lines(&week_days_lines(monday))
+                }
+            }
+        }
+        Some(Commands::Today(args)) => print_today(args, &events, tz),
+        Some(Commands::Era(args)) => {
+            let lines = era_lines(&args.year, &args.country)
+                .unwrap_or_else(|e| CliError::Invalid(e).exit());
+            if lines.is_empty() {
+                CliError::NotFound(format!("no era in the year {}", args.year)).exit();
+            }
+            print_lines(&lines);
+        }
+        Some(Commands::Terms(args)) => {
+            let year = args
+                .year
+                .map_or_else(
+                    || Ok(GregorianDay::from(today(tz)).the_year().ord()),
+                    check_year,
+                )
+                .unwrap_or_else(|e| e.exit());
+            print_lines(&terms_lines(year, tz));
+        }
+        #[cfg(feature = "tui")]
+        Some(Commands::Tui) => {
+            if let Err(e) = tui::run(tz) {
+                CliError::Failed(e.to_string()).exit();
+            }
+        }
+        #[cfg(feature = "render")]
+        Some(Commands::Render(args)) => {
+            if let Err(e) = render_calendar(args, tz) {
+                CliError::Failed(e).exit();
+            }
+        }
+        Some(Commands::Completions(args)) => {
+            clap_complete::generate(
+                args.shell,
+                &mut Cli::command(),
+                "omnical",
+                &mut std::io::stdout(),
+            );
+        }
+        Some(Commands::Man) => {
+            if let Err(e) = clap_mangen::Man::new(Cli::command()).render(&mut std::io::stdout()) {
+                CliError::Failed(e.to_string()).exit();
+            }
+        }
+        None => page_lines(
+            &calendar_lines(&cli.args, &events, tz, color, terminal_width),
+            pager,
+        ),
+    }
 }
 
 #[test]
 fn verify_cli() {
     use clap::CommandFactory;
     Cli::command().debug_assert();
+    Cli::parse_from(["omnical", "export", "ics", "--include", "solar-terms"]);
+    let cli = Cli::parse_from(["omnical", "list", "--events", "events.toml"]);
+    assert_eq!(cli.events, Some(PathBuf::from("events.toml")));
+}
+
+#[test]
+fn test_ics_line() {
+    assert_eq!(ics_line("SUMMARY:春节"), "SUMMARY:春节\r\n");
+    let folded = ics_line(&format!("SUMMARY:{}", "春".repeat(30)));
+    assert!(folded.split("\r\n").all(|line| line.len() <= 75));
+    assert_eq!(
+        folded.replace("\r\n ", ""),
+        format!("SUMMARY:{}\r\n", "春".repeat(30))
+    );
+}
+
+#[test]
+fn test_parse_tz() {
+    assert_eq!(parse_tz("8"), Ok(8.0));
+    assert_eq!(parse_tz("+08:00"), Ok(8.0));
+    assert_eq!(parse_tz("-0530"), Ok(-5.5));
+    assert_eq!(parse_tz("UTC-5"), Ok(-5.0));
+    assert_eq!(parse_tz("utc"), Ok(0.0));
+    assert_eq!(parse_tz("Asia/Tokyo"), Ok(9.0));
+    assert!(parse_tz("America/New_York").is_err());
+    assert!(parse_tz("+15").is_err());
+    assert!(parse_tz("8:60").is_err());
+
+    assert_eq!(parse_tz_env("Asia/Shanghai"), Some(8.0));
+    assert_eq!(parse_tz_env(":Asia/Kolkata"), Some(5.5));
+    assert_eq!(parse_tz_env("CST-8"), Some(8.0));
+    assert_eq!(parse_tz_env("EST5EDT,M3.2.0,M11.1.0"), Some(-5.0));
+    assert_eq!(parse_tz_env("UTC0"), Some(0.0));
+    assert_eq!(parse_tz_env("America/New_York"), None);
+    assert_eq!(parse_tz_env("8"), None);
+    assert_eq!(parse_tz_env("EST"), None);
+}
+
+#[test]
+fn test_month_lines() {
+    let month = GregorianCalendar::from_ym(2024, 2).unwrap();
+    let today: Date = GregorianCalendar::from_ymd(2024, 2, 10).unwrap().into();
+    let painter = Painter::new(false, today, today, today, 8.0);
+    let layout = |first_weekday, week_numbers| Layout {
+        first_weekday,
+        week_numbers,
+        chinese: false,
+    };
+    let lines = month_lines(month, &layout(Monday, false), &painter);
+    assert_eq!(lines.len(), 6);
+    assert_eq!(lines[0], " Mon Tue Wed Thu Fri Sat Sun");
+    assert_eq!(lines[1], "              1   2   3   4 ");
+    assert_eq!(lines[2], "  5   6   7   8   9 [10] 11 ");
+    assert_eq!(lines[5], " 26  27  28  29             ");
+    let lines = month_lines(month, &layout(Sunday, false), &painter);
+    assert_eq!(lines[0], " Sun Mon Tue Wed Thu Fri Sat");
+    assert_eq!(lines[1], "                  1   2   3 ");
+    assert_eq!(lines[5], " 25  26  27  28  29         ");
+    let lines = month_lines(month, &layout(Saturday, false), &painter);
+    assert_eq!(lines[1], "                      1   2 ");
+    assert_eq!(lines[2], "  3   4   5   6   7   8   9 ");
+    let lines = month_lines(month, &layout(Monday, true), &painter);
+    assert_eq!(lines[0], "    Mon Tue Wed Thu Fri Sat Sun");
+    assert_eq!(lines[1], " 5               1   2   3   4 ");
+    assert_eq!(lines[5], " 9  26  27  28  29             ");
+    // A Sunday of 2023 belonging to the last week of 2022.
+    let month = GregorianCalendar::from_ym(2023, 1).unwrap();
+    let lines = month_lines(month, &layout(Sunday, true), &painter);
+    assert_eq!(lines[1], " 1   1   2   3   4   5   6   7 ");
+
+    let month = ChineseCalendar::from_ylm(2023, true, 2).unwrap();
+    let lines = chinese_month_lines(month, &layout(Monday, false), &painter);
+    assert_eq!(display_width(&lines[0]), 42);
+    assert_eq!(display_width(&lines[1]), 42);
+    assert_eq!(lines[1].trim(), "初一  初二  初三  初四  初五");
+
+    let month = GregorianCalendar::from_ym(2024, 2).unwrap();
+    let layout = Layout {
+        first_weekday: Monday,
+        week_numbers: false,
+        chinese: true,
+    };
+    let begin = Date::from(month.first_day());
+    let painter = Painter::new(false, today, begin, begin + 29, 8.0);
+    let lines = month_lines(month, &layout, &painter);
+    assert_eq!(lines.len(), 11);
+    assert_eq!(lines[0], " Mon   Tue   Wed   Thu   Fri   Sat   Sun  ");
+    assert_eq!(lines[3], "   5     6     7     8     9   [10]   11  ");
+    // The Beginning of Spring, the New Year's Eve and the Spring Festival.
+    assert_eq!(lines[2].trim(), "廿二  廿三  廿四  立春");
+    assert_eq!(lines[4].trim(), "廿六  廿七  廿八  廿九  除夕  春节  初二");
+    assert_eq!(chinese_annotation(begin + 9, &painter), "春节");
+    assert_eq!(lines.iter().map(|line| display_width(line)).max(), Some(42));
+}
+
+#[test]
+fn test_painter() {
+    let date = |d| Date::from(GregorianCalendar::from_ymd(2024, 2, d).unwrap());
+    let painter = Painter::new(true, date(10), date(1), date(29), 8.0);
+    assert_eq!(
+        painter.paint(date(10), "10", true, 4),
+        "\x1b[1;7m[10]\x1b[0m"
+    );
+    // The Lantern Festival, the Rain Water and a Sunday.
+    assert_eq!(
+        painter.paint(date(24), "24", true, 4),
+        "\x1b[1;35m 24 \x1b[0m"
+    );
+    assert_eq!(
+        painter.paint(date(19), "19", true, 4),
+        "\x1b[32m 19 \x1b[0m"
+    );
+    assert_eq!(
+        painter.paint(date(18), "18", true, 4),
+        "\x1b[31m 18 \x1b[0m"
+    );
+    assert_eq!(painter.paint(date(20), "20", true, 4), " 20 ");
+    assert_eq!(
+        painter.paint(date(18), "18", false, 4),
+        "\x1b[2m 18 \x1b[0m"
+    );
+    assert_eq!(display_width("\x1b[2m 18 \x1b[0m"), 4);
+
+    let month = GregorianCalendar::from_ym(2024, 2).unwrap();
+    let lines = month_lines(
+        month,
+        &Layout {
+            first_weekday: Monday,
+            week_numbers: false,
+            chinese: false,
+        },
+        &painter,
+    );
+    assert!(lines.iter().all(|line| display_width(line) == 28));
+    assert!(lines[1].starts_with("\x1b[2m 29 \x1b[0m"));
+}
+
+#[test]
+fn test_search_event() {
+    assert_eq!("full-moon".parse(), Ok(SearchEvent::LunarPhase(FullMoon)));
+    assert_eq!("满月".parse(), Ok(SearchEvent::LunarPhase(FullMoon)));
+    assert_eq!(
+        "Winter Solstice".parse(),
+        Ok(SearchEvent::SolarTerm(WinterSolstice))
+    );
+    assert_eq!("冬至".parse(), Ok(SearchEvent::SolarTerm(WinterSolstice)));
+    assert_eq!(
+        "spring-festival".parse(),
+        Ok(SearchEvent::Festival(ChineseFestival::SpringFestival))
+    );
+    assert_eq!("leap-month".parse(), Ok(SearchEvent::LeapMonth));
+    let jia_zi = StemBranch::from_ord(1).unwrap();
+    assert_eq!("甲子 day".parse(), Ok(SearchEvent::StemBranchDay(jia_zi)));
+    assert_eq!(
+        "JiaZi year".parse(),
+        Ok(SearchEvent::StemBranchYear(jia_zi))
+    );
+    assert_eq!("甲子日".parse(), Ok(SearchEvent::StemBranchDay(jia_zi)));
+    assert!("甲丑 day".parse::<SearchEvent>().is_err());
+    assert!("blue moon".parse::<SearchEvent>().is_err());
+
+    let date = |y, m, d| Date::from(GregorianCalendar::from_ymd(y, m, d).unwrap());
+    let (begin, end) = (date(2023, 1, 1), date(2026, 1, 1));
+    assert_eq!(
+        SearchEvent::LeapMonth.dates_in(begin, end, 8.0),
+        [date(2023, 3, 22), date(2025, 7, 25)]
+    );
+    assert_eq!(
+        SearchEvent::SolarTerm(WinterSolstice).dates_in(begin, end, 8.0),
+        [date(2023, 12, 22), date(2024, 12, 21), date(2025, 12, 21)]
+    );
+    assert_eq!(
+        SearchEvent::StemBranchYear(StemBranch::from_ord(41).unwrap()).dates_in(begin, end, 8.0),
+        [date(2024, 2, 10)]
+    );
+    let days = SearchEvent::StemBranchDay(jia_zi).dates_in(begin, end, 8.0);
+    assert!(days.iter().all(|day| StemBranch::from_date(*day) == jia_zi));
+    assert_eq!(days.len(), 19);
+    assert_eq!(
+        SearchEvent::LunarPhase(FullMoon).dates_in(date(2024, 2, 1), date(2024, 3, 1), 8.0),
+        [date(2024, 2, 24)]
+    );
+}
+
+#[test]
+fn test_convert_line() {
+    let mut args = ConvertArgs {
+        from: CalendarKind::Gregorian,
+        to: vec![CalendarKind::Gregorian, CalendarKind::Chinese],
+        date: None,
+        stdin: true,
+        json: false,
+    };
+    assert_eq!(
+        convert_line(&args, "20240210"),
+        Ok("2024-02-10\t公元2024年农历甲辰年正月初一".to_string())
+    );
+    assert!(convert_line(&args, "2024-02-30").is_err());
+    args.json = true;
+    assert_eq!(
+        convert_line(&args, "2024-02-10"),
+        Ok(r#"{"input":"2024-02-10","gregorian":"2024-02-10","chinese":"公元2024年农历甲辰年正月初一"}"#.to_string())
+    );
+    assert_eq!(
+        convert_line(&args, "a\"b"),
+        Ok(r#"{"input":"a\"b","error":"invalid Gregorian date"}"#.to_string())
+    );
+}
+
+#[test]
+fn test_diff_lines() {
+    let date = |y, m, d| Date::from(GregorianCalendar::from_ymd(y, m, d).unwrap());
+    assert_eq!(
+        diff_lines(date(2024, 2, 10), date(2025, 1, 29)),
+        [
+            "Days:     354",
+            "Weeks:    50 weeks 4 days",
+            "Calendar: 0 years 11 months 19 days",
+            "Weekdays: Mon 51, Tue 51, Wed 50, Thu 50, Fri 50, Sat 51, Sun 51",
+        ]
+    );
+    assert_eq!(
+        diff_lines(date(2025, 2, 10), date(2024, 2, 10))[..3],
+        [
+            "Days:     -366",
+            "Weeks:    -52 weeks 2 days",
+            "Calendar: -1 years 0 months 0 days",
+        ]
+    );
+}
+
+#[test]
+fn test_moon_lines() {
+    assert_eq!(parse_period("2024"), Ok((2024, None)));
+    assert_eq!(parse_period("2024-02"), Ok((2024, Some(2))));
+    assert!(parse_period("202413").is_err());
+
+    let lines = moon_lines(2023, Some(12), true, 8.0);
+    assert_eq!(lines[0], "2023-12-05 13:50 🌗 LastQuarter  下弦月");
+    assert_eq!(lines[1], "2023-12-13 07:32 🌑 NewMoon      新月");
+    assert_eq!(lines[3], "2023-12-27 08:33 🌕 FullMoon     满月");
+    assert_eq!(lines.len(), 4 + 1 + 31);
+    assert_eq!(lines[5 + 12], "2023-12-13 🌑   0.0%");
+    assert_eq!(moon_lines(2024, None, false, 8.0).len(), 50);
+}
+
+#[test]
+fn test_terms_lines() {
+    let lines = terms_lines(2025, 8.0);
+    assert_eq!(lines.len(), 24);
+    assert_eq!(
+        lines[0],
+        "2025-01-05 10:32 甲辰年十二月初六   小寒 Minor Cold"
+    );
+    assert_eq!(
+        lines[23],
+        "2025-12-21 23:02 乙巳年十一月初二   冬至 Winter Solstice"
+    );
+}
+
+#[test]
+fn test_parse_natural_date() {
+    let date = |y, m, d| Date::from(GregorianCalendar::from_ymd(y, m, d).unwrap());
+    // A Thursday.
+    let today = date(2024, 2, 8);
+    for (input, expected) in [
+        ("2024-02-10", date(2024, 2, 10)),
+        ("20240210", date(2024, 2, 10)),
+        ("2024/2/10", date(2024, 2, 10)),
+        ("2024.2.10", date(2024, 2, 10)),
+        ("today", today),
+        ("Tomorrow", date(2024, 2, 9)),
+        ("yesterday", date(2024, 2, 7)),
+        ("next monday", date(2024, 2, 12)),
+        ("next thu", date(2024, 2, 15)),
+        ("last Friday", date(2024, 2, 2)),
+        ("甲辰年正月初一", date(2024, 2, 10)),
+        ("正月初一", date(2024, 2, 10)),
+        ("腊月廿三", date(2024, 2, 2)),
+        ("八月十五", date(2023, 9, 29)),
+    ] {
+        assert_eq!(parse_natural_date(input, today), Ok(expected), "{}", input);
+    }
+    assert!(parse_natural_date("2024/2/30", today).is_err());
+    assert!(parse_natural_date("next day", today).is_err());
+    assert!(parse_natural_date("x", today).is_err());
+}
+
+#[test]
+fn test_countdown() {
+    let date = |y, m, d| Date::from(GregorianCalendar::from_ymd(y, m, d).unwrap());
+    let today = date(2024, 10, 1);
+    let target = |target: &str, birthday, lunar| {
+        let args = CountdownArgs {
+            target: target.to_string(),
+            birthday,
+            lunar,
+        };
+        countdown_target(&args, today)
+    };
+    assert_eq!(
+        target("2025-06-01", false, false),
+        Ok((date(2025, 6, 1), None))
+    );
+    assert_eq!(
+        target("2025 Spring Festival", false, false),
+        Ok((date(2025, 1, 29), None))
+    );
+    assert_eq!(
+        target("Mid-Autumn", false, false),
+        Ok((date(2025, 10, 6), None))
+    );
+    assert_eq!(target("春节", false, false), Ok((date(2025, 1, 29), None)));
+    assert_eq!(
+        target("2000-02-29", true, false),
+        Ok((date(2025, 2, 28), Some(25)))
+    );
+    assert_eq!(
+        target("1990-10-01", true, false),
+        Ok((date(2024, 10, 1), Some(34)))
+    );
+    // Born on 庚午年八月十五.
+    assert_eq!(
+        target("1990-10-03", true, true),
+        Ok((date(2025, 10, 6), Some(35)))
+    );
+    assert!(target("someday", false, false).is_err());
+
+    assert_eq!(
+        countdown_lines(date(2025, 1, 29), None, today, 8.0),
+        [
+            "Date:        2025-01-29 Wednesday",
+            "Remaining:   120 days",
+            "Chinese:     乙巳年正月初一",
+            "Lunar phase: 🌑 新月",
+        ]
+    );
+    assert_eq!(
+        countdown_lines(today, Some(34), today, 8.0)[1..],
+        [
+            "Remaining:   today",
+            "Chinese:     甲辰年八月廿九",
+            "Lunar phase: 🌘 残月",
+            "Age:         34"
+        ]
+    );
+}
+
+#[test]
+fn test_bazi_lines() {
+    let date = |y, m, d| Date::from(GregorianCalendar::from_ymd(y, m, d).unwrap());
+    assert_eq!(
+        parse_date_time("1990-06-15T08:30"),
+        Ok((date(1990, 6, 15), Some(8 * 3600 + 30 * 60)))
+    );
+    assert_eq!(
+        parse_date_time("1990-06-15 23:59:59"),
+        Ok((date(1990, 6, 15), Some(86399)))
+    );
+    assert_eq!(parse_date_time("1990-06-15"), Ok((date(1990, 6, 15), None)));
+    assert!(parse_date_time("1990-06-15T24:00").is_err());
+    assert!(parse_date_time("1990-06-15T8").is_err());
+
+    let lines = bazi_lines(date(1990, 6, 15), Some(8 * 3600 + 30 * 60), 8.0);
+    assert_eq!(lines.len(), 5);
+    assert_eq!(
+        lines[0],
+        "Year:  庚午 GengWu   路旁土 Earth by the Roadside"
+    );
+    assert!(lines[1].starts_with("Month: 壬午"));
+    assert!(lines[3].starts_with("Hour:  "));
+    assert_eq!(lines[4], "Zodiac: 马 Horse");
+    assert_eq!(bazi_lines(date(1990, 6, 15), None, 8.0).len(), 4);
+}
+
+#[test]
+fn test_almanac_lines() {
+    let date = |y, m, d| Date::from(GregorianCalendar::from_ymd(y, m, d).unwrap());
+    let lines = almanac_lines(date(2024, 2, 10), 8.0);
+    assert_eq!(lines[0], "公历 2024-02-10 星期六");
+    assert_eq!(lines[1], "农历 甲辰年正月初一");
+    assert_eq!(lines[2], "干支 甲辰年 丙寅月 甲辰日");
+    assert_eq!(lines[7], "节气 距雨水9天");
+    assert_eq!(lines.last().unwrap(), "节日 春节");
+    assert_eq!(almanac_lines(date(2024, 2, 4), 8.0)[7], "节气 今日立春");
+    assert_eq!(almanac_lines(date(2024, 2, 5), 8.0).len(), 9);
+}
+
+#[test]
+fn test_jdn_lines() {
+    let today = Date::from_jdn(2460351);
+    let jd = |input: &str, from| {
+        let args = JdnArgs {
+            input: input.to_string(),
+            from,
+        };
+        parse_jdn_input(&args, today)
+    };
+    assert_eq!(jd("2024-02-10", DayNumber::Jd), Ok(2460350.5));
+    assert_eq!(jd("today", DayNumber::Jd), Ok(2460350.5));
+    assert_eq!(jd("2460351", DayNumber::Jd), Ok(2460350.5));
+    assert_eq!(jd("2460350.75", DayNumber::Jd), Ok(2460350.75));
+    assert_eq!(jd("60350", DayNumber::Mjd), Ok(2460350.5));
+    assert_eq!(jd("-1", DayNumber::Unix), Ok(2440586.5));
+    assert!(jd("x", DayNumber::Jd).is_err());
+    assert_eq!(jdn_lines(-0.5)[1], "Chinese:   unsupported");
+
+    assert_eq!(
+        jdn_lines(2460350.75),
+        [
+            "Date:      2024-02-10 Saturday",
+            "Chinese:   甲辰年正月初一",
+            "JDN:       2460351",
+            "JD:        2460350.75",
+            "MJD:       60350.25",
+            "Unix days: 19763.25",
+        ]
+    );
+}
+
+#[test]
+fn test_sun_lines() {
+    let date = |y, m, d| Date::from(GregorianCalendar::from_ymd(y, m, d).unwrap());
+    let lines = sun_lines(date(2024, 6, 21), 39.9, 116.4, 8.0);
+    assert_eq!(lines.len(), 10);
+    assert_eq!(lines[3], "Sunrise:            04:46");
+    assert_eq!(lines[5], "Sunset:             19:46");
+    assert_eq!(lines[9], "Day length:         15h 00m");
+    let lines = sun_lines(date(2024, 6, 21), 69.6, 19.0, 2.0);
+    assert_eq!(lines[3], "Sunrise:            always above");
+    assert_eq!(lines[0], "Astronomical dawn:  always above");
+    assert_eq!(lines[9], "Day length:         24h 00m");
+    assert!(parse_latitude("91").is_err());
+    assert_eq!(parse_longitude("-74.0"), Ok(-74.0));
+}
+
+#[test]
+fn test_agenda_lines() {
+    let date = |y, m, d| Date::from(GregorianCalendar::from_ymd(y, m, d).unwrap());
+    let rows: Vec<_> = (0..5)
+        .map(|i| {
+            let date = date(2024, 2, 3) + i * 13;
+            let term = if i == 1 { "雨水" } else { "" };
+            let day = format!("{:>2}", GregorianDay::from(date).ord());
+            (
+                date,
+                vec![day, format!("{:#}", date.weekday()), term.to_string()],
+            )
+        })
+        .collect();
+    assert_eq!(
+        agenda_lines(&rows[..2]),
+        ["February 2024", " 3 星期六", "", "16 星期五 雨水"]
+    );
+    assert_eq!(
+        agenda_lines(&rows[2..]),
+        [
+            "February 2024",
+            "29 星期四",
+            "",
+            "March 2024",
+            "13 星期三",
+            "",
+            "26 星期二"
+        ]
+    );
+}
+
+#[test]
+fn test_filter() {
+    assert_eq!(
+        parse_filter("weekday=Sat, sunday,星期一"),
+        Ok(Filter::Weekday(vec![Saturday, Sunday, Monday]))
+    );
+    assert_eq!(parse_filter("solar-term"), Ok(Filter::SolarTerm(vec![])));
+    assert_eq!(
+        parse_filter("solar-term=winter solstice,夏至"),
+        Ok(Filter::SolarTerm(vec![WinterSolstice, SummerSolstice]))
+    );
+    assert_eq!(
+        parse_filter("lunar-phase=FullMoon,new-moon"),
+        Ok(Filter::LunarPhase(vec![FullMoon, NewMoon]))
+    );
+    assert_eq!(
+        parse_filter("chinese-day=初一,15"),
+        Ok(Filter::ChineseDay(vec![1, 15]))
+    );
+    assert!(parse_filter("weekday").is_err());
+    assert!(parse_filter("weekday=Fun").is_err());
+    assert!(parse_filter("chinese-day=31").is_err());
+    assert!(parse_filter("holiday").is_err());
+
+    let date = |y, m, d| Date::from(GregorianCalendar::from_ymd(y, m, d).unwrap());
+    // The Mid-Autumn Festival of 2024, with a full moon the next day.
+    let (festival, full_moon) = (date(2024, 9, 17), date(2024, 9, 18));
+    let filter = Filter::ChineseDay(vec![15]);
+    assert!(filter.matches(festival, None, 8.0));
+    assert!(!filter.matches(full_moon, None, 8.0));
+    assert!(Filter::LunarPhase(vec![]).matches(full_moon, None, 8.0));
+    assert!(!Filter::LunarPhase(vec![]).matches(festival, None, 8.0));
+    assert!(Filter::SolarTerm(vec![]).matches(date(2024, 9, 22), None, 8.0));
+    assert!(Filter::Weekday(vec![Tuesday]).matches(festival, None, 8.0));
+}
+
+#[test]
+fn test_terminal_width() {
+    assert_eq!(fit_columns(20, None), 3);
+    assert_eq!(fit_columns(20, Some(80)), 3);
+    assert_eq!(fit_columns(20, Some(86)), 4);
+    assert_eq!(fit_columns(20, Some(200)), 6);
+    assert_eq!(fit_columns(20, Some(40)), 1);
+
+    assert_eq!(truncate("2025年1月6日 Standup", 10), "2025年1月…");
+    assert_eq!(truncate("2025年1月6日", 12), "2025年1月6日");
+}
+
+#[test]
+fn test_completions() {
+    let mut script = Vec::new();
+    clap_complete::generate(
+        clap_complete::Shell::Bash,
+        &mut Cli::command(),
+        "omnical",
+        &mut script,
+    );
+    let script = String::from_utf8(script).unwrap();
+    assert!(script.contains("countdown"));
+    assert!(script.contains("Asia/Shanghai"));
+    assert!(script.contains("tomorrow"));
+    assert!(script.contains("gregorian chinese"));
+
+    let mut page = Vec::new();
+    clap_mangen::Man::new(Cli::command())
+        .render(&mut page)
+        .unwrap();
+    assert!(String::from_utf8(page).unwrap().starts_with(".ie"));
+    assert_eq!(
+        Cli::parse_from(["omnical", "--tz", "Asia/Tokyo"]).tz,
+        Some(9.0)
+    );
+}
+
+#[test]
+fn test_date_attributes() {
+    let date = Date::from(GregorianCalendar::from_ymd(2024, 2, 10).unwrap());
+    let attributes = date_attributes(date, 8.0);
+    assert_eq!(
+        attribute_lines(&attributes, false),
+        [
+            "gregorian: 2024-02-10",
+            "chinese: 甲辰年正月初一",
+            "jdn: 2460351",
+            "mjd: 60350",
+            "weekday: Saturday",
+            "iso_week: 2024-W06",
+            "day_of_year: 41",
+            "lunar_phase: NewMoon",
+            "lunar_illumination: 0.001",
+            "solar_term:",
+            "year_stem_branch: 甲辰",
+            "month_stem_branch: 丙寅",
+            "day_stem_branch: 甲辰",
+            "chinese_zodiac: Dragon",
+            "zodiac_sign: Aquarius",
+            "festival: SpringFestival",
+        ]
+    );
+    let json = &attribute_lines(&attributes, true)[0];
+    assert!(
+        json.starts_with(r#"{"gregorian":"2024-02-10","chinese":"甲辰年正月初一","jdn":2460351,"#)
+    );
+    assert!(json.contains(r#""solar_term":null,"#));
+
+    // The Chinese attributes of a date outside the Chinese calendar are none.
+    let date = Date::from(GregorianCalendar::from_ymd(7000, 1, 1).unwrap());
+    let lines = attribute_lines(&date_attributes(date, 8.0), false);
+    assert_eq!(lines[1], "chinese:");
+    assert_eq!(lines[12], "day_stem_branch: 辛亥");
+}
+
+#[test]
+fn test_find_chinese_dates() {
+    assert_eq!(parse_years("2024..2030"), Ok(2024..=2030));
+    assert_eq!(parse_years("2024..=2030"), Ok(2024..=2030));
+    assert_eq!(parse_years("2024"), Ok(2024..=2024));
+    assert!(parse_years("2030..2024").is_err());
+    assert!(parse_years("2024-2030").is_err());
+
+    assert_eq!(
+        find_lines(&find_chinese_dates("八月十五", 2024..=2026)),
+        [
+            "2024 甲辰年八月十五 2024-09-17 Tuesday",
+            "2025 乙巳年八月十五 2025-10-06 Monday",
+            "2026 丙午年八月十五 2026-09-25 Friday",
+        ]
+    );
+    // 2025 has a leap sixth month.
+    assert_eq!(
+        find_lines(&find_chinese_dates("六月初一", 2025..=2025)),
+        [
+            "2025 乙巳年六月初一 2025-06-25 Wednesday",
+            "2025 乙巳年闰六月初一 2025-07-25 Friday",
+        ]
+    );
+    assert_eq!(find_chinese_dates("闰六月初一", 2024..=2026).len(), 1);
+    assert!(find_chinese_dates("十三月初一", 2024..=2026).is_empty());
+}
+
+#[test]
+fn test_weeks() {
+    let date = |y, m, d| Date::from(GregorianCalendar::from_ymd(y, m, d).unwrap());
+    assert_eq!(parse_weeks_period("2025"), Ok(WeeksPeriod::Year(2025)));
+    assert_eq!(
+        parse_weeks_period("2025-W07"),
+        Ok(WeeksPeriod::Week(date(2025, 2, 10)))
+    );
+    assert!(parse_weeks_period("2025-W54").is_err());
+    assert!(parse_weeks_period("W07").is_err());
+
+    let lines = weeks_lines(2026);
+    assert_eq!(lines.len(), 53);
+    assert_eq!(
+        lines[0],
+        "2026-W01 2025-12-29..2026-01-04 December, January"
+    );
+    assert_eq!(
+        lines[52],
+        "2026-W53 2026-12-28..2027-01-03 December, January"
+    );
+    assert_eq!(weeks_lines(2025).len(), 52);
+
+    let lines = week_days_lines(date(2025, 2, 10));
+    assert_eq!(lines.len(), 7);
+    assert_eq!(lines[2], "Wed 2025-02-12 乙巳年正月十五");
+}
+
+#[test]
+fn test_era() {
+    assert_eq!(
+        era_lines("1989", &[EraCountry::Jp]),
+        Ok(vec![
+            "1989 = 昭和64年 (Showa 64)".to_string(),
+            "1989 = 平成元年 (Heisei 1)".to_string(),
+        ])
+    );
+    assert_eq!(
+        era_lines("2025", &[EraCountry::Tw, EraCountry::Kp]).unwrap(),
+        [
+            "2025 = 民国114年 (Minguo 114)",
+            "2025 = 주체114년 (Juche 114)"
+        ]
+    );
+    assert_eq!(
+        era_lines("令和7年", &[]),
+        Ok(vec!["令和7年 (Reiwa 7) = 2025".to_string()])
+    );
+    assert_eq!(
+        era_lines("康熙三十年", &[]),
+        Ok(vec!["清康熙三十年 (Kangxi 30) = 1691".to_string()])
+    );
+    assert_eq!(
+        era_lines("1911", &[EraCountry::Cn, EraCountry::Tw]),
+        Ok(vec!["1911 = 清宣统三年 (Xuantong 3)".to_string()])
+    );
+    assert!(era_lines("令和", &[]).is_err());
+}
+
+#[test]
+fn test_today() {
+    let events = events::Events::default();
+    let date = Date::from(GregorianCalendar::from_ymd(2024, 9, 17).unwrap());
+    let template = |template| expand_template(template, date, &events, 8.0);
+    assert_eq!(
+        template("{greg} {chinese} {phase_emoji}"),
+        Ok("2024-09-17 甲辰年八月十五 🌔".to_string())
+    );
+    assert_eq!(
+        template("{{{ weekday }}} {day_of_year}{festival}"),
+        Ok("{Tuesday} 261MidAutumnFestival".to_string())
+    );
+    assert!(template("{unknown}").is_err());
+    assert!(template("{greg").is_err());
+    assert!(template("greg}").is_err());
+
+    assert_eq!(seconds_to_midnight(0, 0.0), 86400);
+    assert_eq!(seconds_to_midnight(86399, 0.0), 1);
+    assert_eq!(seconds_to_midnight(0, 8.0), 57600);
+    assert_eq!(seconds_to_midnight(0, -5.0), 18000);
+}
+
+#[test]
+fn test_cli_error() {
+    let date = |y, m, d| Date::from(GregorianCalendar::from_ymd(y, m, d).unwrap());
+    assert_eq!(check_year(2024), Ok(2024));
+    assert_eq!(check_year(6000), Ok(6000));
+    assert_eq!(
+        check_year(-2000),
+        Err(CliError::OutOfRange(
+            "year -2000 is outside the supported range -1999..=6000".to_string()
+        ))
+    );
+    assert_eq!(check_date(date(2024, 2, 10)), Ok(date(2024, 2, 10)));
+    assert_eq!(check_date(date(7000, 1, 1)).map_err(|e| e.code()), Err(3));
+    assert!(check_jd(2460351.5).is_ok());
+    assert!(check_jd(1e12).is_err());
+    assert!(check_jd(f64::NAN).is_err());
+
+    let args = ConvertArgs {
+        from: CalendarKind::Gregorian,
+        to: vec![CalendarKind::Chinese],
+        date: None,
+        stdin: false,
+        json: false,
+    };
+    assert_eq!(
+        convert_line(&args, "2024-02-30").map_err(|e| e.code()),
+        Err(2)
+    );
+    assert_eq!(
+        convert_line(&args, "7000-01-01").map_err(|e| e.code()),
+        Err(3)
+    );
+    assert_eq!(
+        format_date(CalendarKind::Chinese, date(7000, 1, 1)).map_err(|e| e.to_string()),
+        Err("7000-01-01 is outside the Chinese calendar".to_string())
+    );
+    assert_eq!(CliError::NotFound(String::new()).code(), 4);
 }
@@ -0,0 +1,185 @@
+//! Free-form metadata attached to months and solar terms.
+//!
+//! Each value is a string stored under a key.  A default data set is built in
+//! for the keys below, and applications can add their own keys or override the
+//! defaults at run time, process-wide.
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+#[cfg(feature = "astronomy")]
+use strum::EnumCount;
+
+use crate::*;
+
+/// The modern birthstone of a month, e.g. "Garnet".
+pub const BIRTHSTONE: &str = "birthstone";
+/// The birth flower of a month, e.g. "Carnation".
+pub const BIRTH_FLOWER: &str = "birth_flower";
+/// The season, e.g. "winter": the meteorological season of the northern
+/// hemisphere for a month, and the traditional Chinese season for a solar term.
+pub const SEASON: &str = "season";
+/// The zodiac signs whose date ranges overlap a month, e.g.
+/// "Capricorn, Aquarius".
+pub const ZODIAC: &str = "zodiac";
+
+const BIRTHSTONES: [&str; 12] = [
+    "Garnet",
+    "Amethyst",
+    "Aquamarine",
+    "Diamond",
+    "Emerald",
+    "Pearl",
+    "Ruby",
+    "Peridot",
+    "Sapphire",
+    "Opal",
+    "Topaz",
+    "Turquoise",
+];
+
+const BIRTH_FLOWERS: [&str; 12] = [
+    "Carnation",
+    "Violet",
+    "Daffodil",
+    "Daisy",
+    "Lily of the valley",
+    "Rose",
+    "Larkspur",
+    "Gladiolus",
+    "Aster",
+    "Marigold",
+    "Chrysanthemum",
+    "Narcissus",
+];
+
+const SEASONS: [&str; 4] = ["spring", "summer", "autumn", "winter"];
+
+type RegistryKey = (&'static str, usize, String);
+
+static REGISTRY: Mutex<BTreeMap<RegistryKey, String>> = Mutex::new(BTreeMap::new());
+
+/// A type whose values can carry metadata.
+pub trait Metadata: Copy {
+    /// The name distinguishing the type in the registry.
+    const KIND: &'static str;
+
+    /// The index distinguishing the value among those of the type.
+    fn metadata_index(&self) -> usize;
+
+    /// The built-in value for the given key, if any.
+    fn default_metadata(&self, _key: &str) -> Option<String> {
+        None
+    }
+
+    /// The value for the given key, set at run time or built in.
+    fn metadata(&self, key: &str) -> Option<String> {
+        REGISTRY
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&(Self::KIND, self.metadata_index(), key.to_string()))
+            .cloned()
+            .or_else(|| self.default_metadata(key))
+    }
+
+    /// Sets the value for the given key, overriding the built-in one.
+    fn set_metadata(&self, key: &str, value: &str) {
+        REGISTRY.lock().unwrap_or_else(|e| e.into_inner()).insert(
+            (Self::KIND, self.metadata_index(), key.to_string()),
+            value.to_string(),
+        );
+    }
+
+    /// Removes the value set for the given key, restoring the built-in one.
+    fn remove_metadata(&self, key: &str) {
+        REGISTRY.lock().unwrap_or_else(|e| e.into_inner()).remove(&(
+            Self::KIND,
+            self.metadata_index(),
+            key.to_string(),
+        ));
+    }
+}
+
+impl Metadata for MonthName {
+    const KIND: &'static str = "MonthName";
+
+    fn metadata_index(&self) -> usize {
+        *self as usize
+    }
+
+    fn default_metadata(&self, key: &str) -> Option<String> {
+        let i = *self as usize;
+        match key {
+            BIRTHSTONE => Some(BIRTHSTONES[i].to_string()),
+            BIRTH_FLOWER => Some(BIRTH_FLOWERS[i].to_string()),
+            SEASON => Some(SEASONS[(i + 10) % 12 / 3].to_string()),
+            #[cfg(feature = "astronomy")]
+            ZODIAC => {
+                let first = ZodiacSign::from_month_day(self.ord(), 1)?;
+                Some(format!("{}, {}", first, first.succ()))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "astronomy")]
+impl Metadata for SolarTerm {
+    const KIND: &'static str = "SolarTerm";
+
+    fn metadata_index(&self) -> usize {
+        *self as usize
+    }
+
+    fn default_metadata(&self, key: &str) -> Option<String> {
+        match key {
+            // The seasons begin with the Beginning of Spring (立春) and so on.
+            SEASON => {
+                let months = (*self as usize + SolarTerm::COUNT - BeginningOfSpring as usize)
+                    % SolarTerm::COUNT;
+                Some(SEASONS[months / 6].to_string())
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "astronomy")]
+#[test]
+fn test_metadata() {
+    assert_eq!(January.metadata(BIRTHSTONE).as_deref(), Some("Garnet"));
+    assert_eq!(
+        May.metadata(BIRTH_FLOWER).as_deref(),
+        Some("Lily of the valley")
+    );
+    assert_eq!(December.metadata(SEASON).as_deref(), Some("winter"));
+    assert_eq!(February.metadata(SEASON).as_deref(), Some("winter"));
+    assert_eq!(March.metadata(SEASON).as_deref(), Some("spring"));
+    assert_eq!(
+        January.metadata(ZODIAC).as_deref(),
+        Some("Capricorn, Aquarius")
+    );
+    assert_eq!(January.metadata("unknown"), None);
+
+    assert_eq!(
+        BeginningOfSpring.metadata(SEASON).as_deref(),
+        Some("spring")
+    );
+    assert_eq!(GrainRain.metadata(SEASON).as_deref(), Some("spring"));
+    assert_eq!(WinterSolstice.metadata(SEASON).as_deref(), Some("winter"));
+    assert_eq!(MajorCold.metadata(SEASON).as_deref(), Some("winter"));
+    assert_eq!(WinterSolstice.metadata(BIRTHSTONE), None);
+
+    // Keys unused by other tests, as the registry is process-wide.
+    June.set_metadata("test_key", "value");
+    assert_eq!(June.metadata("test_key").as_deref(), Some("value"));
+    assert_eq!(July.metadata("test_key"), None);
+    assert_eq!(SummerSolstice.metadata("test_key"), None);
+    June.remove_metadata("test_key");
+    assert_eq!(June.metadata("test_key"), None);
+
+    October.set_metadata(BIRTHSTONE, "Tourmaline");
+    assert_eq!(October.metadata(BIRTHSTONE).as_deref(), Some("Tourmaline"));
+    October.remove_metadata(BIRTHSTONE);
+    assert_eq!(October.metadata(BIRTHSTONE).as_deref(), Some("Opal"));
+}
@@ -0,0 +1,102 @@
+//! Numbers written in Chinese characters or in digits, shared by the Chinese
+//! calendar and the era years of [`regnal`](crate::regnal).
+
+fn digit(c: char) -> Option<u32> {
+    match c {
+        '〇' | '零' => Some(0),
+        '一' => Some(1),
+        '二' | '两' => Some(2),
+        '三' => Some(3),
+        '四' => Some(4),
+        '五' => Some(5),
+        '六' => Some(6),
+        '七' => Some(7),
+        '八' => Some(8),
+        '九' => Some(9),
+        _ => c.to_digit(10),
+    }
+}
+
+/// Parses a number written either digit by digit ("二〇二四", "2024") or
+/// positionally ("十五", "廿三", "一百零八").
+pub(crate) fn parse_numeral(s: &str) -> Option<u32> {
+    if s.is_empty() {
+        return None;
+    }
+    if !s.contains(['十', '百', '廿', '卅']) {
+        return s
+            .chars()
+            .try_fold(0u32, |n, c| n.checked_mul(10)?.checked_add(digit(c)?));
+    }
+    let mut total = 0;
+    let mut curr = None;
+    for c in s.chars() {
+        match c {
+            '十' => total += curr.take().unwrap_or(1) * 10,
+            '百' => total += curr.take()? * 100,
+            '廿' => total += 20,
+            '卅' => total += 30,
+            _ => match digit(c)? {
+                0 => {}
+                d => curr = Some(d),
+            },
+        }
+    }
+    Some(total + curr.unwrap_or(0))
+}
+
+/// Parses the year of an era, "元" or a numeral, e.g. "三十" or "30".
+pub(crate) fn parse_era_year(s: &str) -> Option<u32> {
+    match s {
+        "元" => Some(1),
+        _ => parse_numeral(s).filter(|&year| year >= 1),
+    }
+}
+
+/// Writes a number positionally in Chinese characters, e.g. "一百一十三", as
+/// [`parse_numeral`] reads it back.
+pub(crate) fn format_numeral(n: u32) -> String {
+    const DIGITS: [&str; 10] = ["〇", "一", "二", "三", "四", "五", "六", "七", "八", "九"];
+    match n {
+        0..=9 => DIGITS[n as usize].to_string(),
+        10..=99 => {
+            let tens = if n < 20 { "" } else { DIGITS[n as usize / 10] };
+            let ones = match n % 10 {
+                0 => "",
+                d => DIGITS[d as usize],
+            };
+            format!("{}十{}", tens, ones)
+        }
+        100..=999 => {
+            let rest = n % 100;
+            let rest = match rest {
+                0 => String::new(),
+                1..=9 => format!("零{}", DIGITS[rest as usize]),
+                10..=19 => format!("一{}", format_numeral(rest)),
+                _ => format_numeral(rest),
+            };
+            format!("{}百{}", DIGITS[n as usize / 100], rest)
+        }
+        _ => n.to_string(),
+    }
+}
+
+#[test]
+fn test_numeral() {
+    assert_eq!(parse_numeral("二〇二四"), Some(2024));
+    assert_eq!(parse_numeral("十"), Some(10));
+    assert_eq!(parse_numeral("十五"), Some(15));
+    assert_eq!(parse_numeral("廿三"), Some(23));
+    assert_eq!(parse_numeral("一百零八"), Some(108));
+    assert_eq!(parse_numeral("甲"), None);
+    assert_eq!(parse_era_year("元"), Some(1));
+    assert_eq!(parse_era_year("〇"), None);
+}
+
+#[test]
+fn test_format_numeral() {
+    assert_eq!(format_numeral(113), "一百一十三");
+    for n in [0, 9, 10, 15, 20, 61, 100, 108, 113, 999] {
+        assert_eq!(parse_numeral(&format_numeral(n)), Some(n));
+    }
+}
@@ -0,0 +1,360 @@
+//! Python bindings with the `python` feature, built into a module `omnical`
+//! by `pip install .` with the `pyproject.toml` of the repository.
+//!
+//! Dates may be given as [`Date`], [`GregorianDay`] or [`ChineseDay`], or as
+//! Python objects with `year`, `month` and `day` attributes, such as
+//! `datetime.date` and `pandas.Timestamp`, so the classes can be mapped over
+//! a column of dates.
+
+// The methods of Python classes take `&self`, and the code generated by pyo3
+// converts errors into themselves.
+#![allow(clippy::wrong_self_convention, clippy::useless_conversion)]
+
+use pyo3::exceptions::{PyOverflowError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyInt;
+
+use crate::calendar::{Calendar as _, Day as _, Month as _, Year as _};
+use crate::*;
+
+/// A day as its Julian day number.
+#[pyclass(name = "Date", module = "omnical", frozen, eq, ord)]
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+struct PyDate(Date);
+
+/// A day of the Gregorian calendar.
+#[pyclass(name = "GregorianDay", module = "omnical", frozen, eq)]
+#[derive(Clone, Copy, PartialEq)]
+struct PyGregorianDay(GregorianDay);
+
+/// A day of the Chinese calendar.
+#[pyclass(name = "ChineseDay", module = "omnical", frozen, eq)]
+#[derive(Clone, Copy, PartialEq)]
+struct PyChineseDay(ChineseDay);
+
+fn value_error(message: impl Into<String>) -> PyErr {
+    PyValueError::new_err(message.into())
+}
+
+/// Extracts a date from any of the classes or from an object with `year`,
+/// `month` and `day` attributes.
+fn extract_date(date: &Bound<'_, PyAny>) -> PyResult<Date> {
+    if let Ok(date) = date.extract::<PyDate>() {
+        return Ok(date.0);
+    }
+    if let Ok(day) = date.extract::<PyGregorianDay>() {
+        return Ok(day.0.into());
+    }
+    if let Ok(day) = date.extract::<PyChineseDay>() {
+        return Ok(day.0.into());
+    }
+    let (year, month, day) = (
+        date.getattr("year")?.extract()?,
+        date.getattr("month")?.extract()?,
+        date.getattr("day")?.extract()?,
+    );
+    GregorianCalendar::from_ymd(year, month, day)
+        .map(Date::from)
+        .ok_or_else(|| value_error(format!("invalid date: {}-{}-{}", year, month, day)))
+}
+
+/// The Gregorian day of a date, failing with `ValueError` outside
+/// [`gregorian::SUPPORTED_YEARS`].
+fn gregorian_day(date: Date) -> PyResult<GregorianDay> {
+    GregorianDay::checked_from_date_with_tz(date, 0.0).ok_or_else(|| {
+        value_error(format!(
+            "Julian day number {} is outside the Gregorian years {}..={}",
+            date.jdn(),
+            gregorian::SUPPORTED_YEARS.start(),
+            gregorian::SUPPORTED_YEARS.end()
+        ))
+    })
+}
+
+fn chinese_day(date: Date) -> PyResult<ChineseDay> {
+    ChineseDay::try_from(date).map_err(|e| value_error(e.to_string()))
+}
+
+#[pymethods]
+impl PyDate {
+    #[new]
+    fn new(date: &Bound<'_, PyAny>) -> PyResult<Self> {
+        extract_date(date).map(Self)
+    }
+
+    /// Fails with `ValueError` for a day outside the Gregorian calendar, so
+    /// that every `Date` has a Gregorian day.
+    #[staticmethod]
+    fn from_jdn(jdn: i32) -> PyResult<Self> {
+        let date = Date::from_jdn(jdn);
+        gregorian_day(date)?;
+        Ok(Self(date))
+    }
+
+    #[getter]
+    fn jdn(&self) -> i32 {
+        self.0.jdn()
+    }
+
+    #[getter]
+    fn weekday(&self) -> String {
+        self.0.weekday().to_string()
+    }
+
+    fn gregorian(&self) -> PyResult<PyGregorianDay> {
+        gregorian_day(self.0).map(PyGregorianDay)
+    }
+
+    fn chinese(&self) -> PyResult<PyChineseDay> {
+        chinese_day(self.0).map(PyChineseDay)
+    }
+
+    /// Fails with `OverflowError` past the Gregorian calendar, as
+    /// `datetime.date` does past its range.
+    fn __add__(&self, days: &Bound<'_, PyInt>) -> PyResult<Self> {
+        let days: i64 = days.extract()?;
+        i64::from(self.0.jdn())
+            .checked_add(days)
+            .and_then(|jdn| i32::try_from(jdn).ok())
+            .map(Date::from_jdn)
+            .filter(|&date| gregorian_day(date).is_ok())
+            .map(Self)
+            .ok_or_else(|| PyOverflowError::new_err("date value out of range"))
+    }
+
+    fn __sub__(&self, other: &Self) -> i64 {
+        i64::from(self.0.jdn()) - i64::from(other.0.jdn())
+    }
+
+    fn __hash__(&self) -> u64 {
+        self.0.jdn() as u64
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Date({})", self.0.jdn())
+    }
+}
+
+#[pymethods]
+impl PyGregorianDay {
+    #[new]
+    fn new(year: i32, month: u8, day: u8) -> PyResult<Self> {
+        GregorianCalendar::from_ymd(year, month, day)
+            .map(Self)
+            .ok_or_else(|| value_error(format!("invalid date: {}-{}-{}", year, month, day)))
+    }
+
+    #[staticmethod]
+    fn from_date(date: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(Self(extract_date(date)?.into()))
+    }
+
+    #[staticmethod]
+    fn from_iso8601(s: &str) -> PyResult<Self> {
+        GregorianDay::from_iso8601(s)
+            .map(Self)
+            .map_err(|e| value_error(format!("{}: {}", e, s)))
+    }
+
+    #[getter]
+    fn year(&self) -> i32 {
+        self.0.the_year().ord()
+    }
+
+    #[getter]
+    fn month(&self) -> u8 {
+        self.0.the_month().ord()
+    }
+
+    #[getter]
+    fn day(&self) -> u8 {
+        self.0.ord()
+    }
+
+    #[getter]
+    fn weekday(&self) -> String {
+        self.0.weekday().to_string()
+    }
+
+    fn to_date(&self) -> PyDate {
+        PyDate(self.0.into())
+    }
+
+    fn to_iso8601(&self) -> String {
+        self.0.to_iso8601()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_iso8601()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("GregorianDay({})", self.0.to_iso8601())
+    }
+}
+
+#[pymethods]
+impl PyChineseDay {
+    #[new]
+    #[pyo3(signature = (year, month, day, leap = false))]
+    fn new(year: i32, month: u8, day: u8, leap: bool) -> PyResult<Self> {
+        ChineseCalendar::try_from_y(year).map_err(|e| value_error(e.to_string()))?;
+        ChineseCalendar::from_ylmd(year, leap, month, day)
+            .map(Self)
+            .ok_or_else(|| value_error("invalid Chinese date"))
+    }
+
+    #[staticmethod]
+    fn from_date(date: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let date = extract_date(date)?;
+        chinese_day(date).map(Self)
+    }
+
+    /// Parses a Chinese date, e.g. "2024年正月初一" or "甲辰年正月初一".
+    #[staticmethod]
+    fn parse(s: &str) -> PyResult<Self> {
+        s.parse()
+            .map(Self)
+            .map_err(|e: chinese::ParseDayError| value_error(format!("{}: {}", e, s)))
+    }
+
+    #[getter]
+    fn year(&self) -> i32 {
+        self.0.the_year().ord()
+    }
+
+    /// The number of the month, the same for a leap month and the month
+    /// before it.
+    #[getter]
+    fn month(&self) -> u8 {
+        self.0.the_month().ord_no_leap()
+    }
+
+    #[getter]
+    fn leap(&self) -> bool {
+        self.0.the_month().is_leap()
+    }
+
+    #[getter]
+    fn day(&self) -> u8 {
+        self.0.ord()
+    }
+
+    #[getter]
+    fn stem_branch(&self) -> String {
+        format!("{:#}", self.0.stem_branch())
+    }
+
+    #[getter]
+    fn festival(&self) -> Option<String> {
+        self.0.festival().map(|festival| festival.to_string())
+    }
+
+    fn to_date(&self) -> PyDate {
+        PyDate(self.0.into())
+    }
+
+    fn to_gregorian(&self) -> PyGregorianDay {
+        PyGregorianDay(Date::from(self.0).into())
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ChineseDay({})", self.0)
+    }
+}
+
+/// The lunar phase of a day, e.g. "FullMoon".
+#[pyfunction]
+#[pyo3(signature = (date, tz = 8.0))]
+fn lunar_phase(date: &Bound<'_, PyAny>, tz: f64) -> PyResult<String> {
+    Ok(extract_date(date)?.lunar_phase(tz).to_string())
+}
+
+/// The solar term starting on a day, if any, e.g. "BeginningOfSpring".
+#[pyfunction]
+#[pyo3(signature = (date, tz = 8.0))]
+fn solar_term(date: &Bound<'_, PyAny>, tz: f64) -> PyResult<Option<String>> {
+    Ok(extract_date(date)?
+        .solar_term(tz)
+        .map(|term| term.to_string()))
+}
+
+/// The solar terms of a Gregorian year, as (day, name, Chinese name) tuples.
+#[pyfunction]
+#[pyo3(signature = (year, tz = 8.0))]
+fn solar_terms(year: i32, tz: f64) -> PyResult<Vec<(PyGregorianDay, String, String)>> {
+    let year = GregorianCalendar::from_y(year).ok_or_else(|| value_error("invalid year"))?;
+    let (begin, end) = (year.first_day().into(), Date::from(year.last_day()).succ());
+    Ok(astronomy::find_solar_terms(begin, end, tz)
+        .into_iter()
+        .map(|(date, term)| {
+            (
+                PyGregorianDay(date.into()),
+                term.to_string(),
+                term.chinese().to_string(),
+            )
+        })
+        .collect())
+}
+
+#[pymodule]
+fn omnical(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyDate>()?;
+    m.add_class::<PyGregorianDay>()?;
+    m.add_class::<PyChineseDay>()?;
+    m.add_function(wrap_pyfunction!(lunar_phase, m)?)?;
+    m.add_function(wrap_pyfunction!(solar_term, m)?)?;
+    m.add_function(wrap_pyfunction!(solar_terms, m)?)?;
+    Ok(())
+}
+
+#[test]
+fn test_python() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let module = PyModule::new_bound(py, "omnical").unwrap();
+        omnical(&module).unwrap();
+        let globals = pyo3::types::PyDict::new_bound(py);
+        globals.set_item("omnical", module).unwrap();
+        py.run_bound(
+            r#"
+import datetime
+day = omnical.ChineseDay.from_date(datetime.date(2024, 2, 10))
+assert (day.year, day.month, day.leap, day.day) == (2024, 1, False, 1)
+assert str(day) == "甲辰年正月初一"
+assert day.stem_branch == "甲辰" and day.festival == "SpringFestival"
+assert str(omnical.ChineseDay(2023, 2, 1, leap=True).to_gregorian()) == "2023-03-22"
+date = omnical.GregorianDay(2024, 9, 17).to_date()
+assert date + 1 == omnical.Date.from_jdn(date.jdn + 1)
+assert str(date.chinese()) == "甲辰年八月十五"
+assert omnical.lunar_phase(datetime.date(2024, 2, 10)) == "NewMoon"
+assert omnical.solar_terms(2024)[2][1:] == ("BeginningOfSpring", "立春")
+try:
+    omnical.ChineseDay.from_date(datetime.date(7000, 1, 1))
+    assert False
+except ValueError:
+    pass
+for f, error in [
+    (lambda: omnical.Date.from_jdn(2**31 - 1), ValueError),
+    (lambda: date + 2**31, OverflowError),
+    (lambda: date + 2**70, OverflowError),
+]:
+    try:
+        f()
+        assert False
+    except error:
+        pass
+last = omnical.GregorianDay(5000000, 12, 31).to_date()
+first = omnical.GregorianDay(-5000000, 1, 1).to_date()
+assert last - first == last.jdn - first.jdn > 2**31
+"#,
+            Some(&globals),
+            None,
+        )
+        .unwrap();
+    });
+}
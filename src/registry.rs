@@ -0,0 +1,291 @@
+//! Calendars selected by name at runtime.
+//!
+//! [`AnyCalendar`] and [`AnyDay`] erase the associated types of
+//! [`Calendar`], so that a calendar can be picked from a string, e.g. a
+//! command line option, without a match arm for each calendar:
+//!
+//! ```
+//! # #[cfg(feature = "astronomy")] {
+//! use omnical::registry;
+//!
+//! let chinese = registry::calendar("chinese").unwrap();
+//! let day = chinese.day(2024, 1, 1).unwrap();
+//! let gregorian = registry::calendar("gregorian").unwrap();
+//! assert_eq!(gregorian.day_of_date(day.date()).unwrap().to_string(), "2024-02-10");
+//! # }
+//! ```
+
+use std::marker::PhantomData;
+use std::ops::RangeInclusive;
+
+use crate::calendar::{Calendar, CalendarInfo, Day as _, Month as _, Ordinal as _, Year as _};
+use crate::*;
+
+/// A calendar with its types erased.
+///
+/// Months are numbered by their order in the year, as in [`Calendar`], so a
+/// leap month of the Chinese calendar takes the number after the month it
+/// follows.
+pub trait AnyCalendar {
+    /// The name of the calendar in the registry, e.g. "gregorian".
+    fn name(&self) -> &'static str;
+    /// The name of the calendar for display, e.g. "Gregorian".
+    fn display_name(&self) -> &'static str;
+    /// The years supported by the calendar.
+    fn supported_years(&self) -> RangeInclusive<i32>;
+    /// The fewest and the most months of a year.
+    fn months_per_year(&self) -> RangeInclusive<usize>;
+    /// Whether some years have a leap month.
+    fn has_leap_months(&self) -> bool;
+    /// The first day of the year 1, from which the years are numbered.
+    fn epoch(&self) -> Date;
+    /// The number of months of a year, if the year is supported.
+    fn num_months(&self, year: i32) -> Option<usize>;
+    /// The number of days of a year, if the year is supported.
+    fn num_days(&self, year: i32) -> Option<usize>;
+    fn day(&self, year: i32, month: u32, day: u32) -> Option<Box<dyn AnyDay>>;
+    fn day_in_year(&self, year: i32, day_ord: u16) -> Option<Box<dyn AnyDay>>;
+    fn day_of_date(&self, date: Date) -> Option<Box<dyn AnyDay>>;
+    /// Parses a day as the `FromStr` of its calendar does, e.g. an ISO 8601
+    /// date of the Gregorian calendar.
+    fn parse_day(&self, s: &str) -> Option<Box<dyn AnyDay>>;
+}
+
+/// A day of an [`AnyCalendar`].
+pub trait AnyDay: std::fmt::Display {
+    /// The name of the calendar of the day.
+    fn calendar(&self) -> &'static str;
+    fn year(&self) -> i32;
+    /// The order of the month in the year.
+    fn month(&self) -> u32;
+    fn day(&self) -> u32;
+    fn ord_in_year(&self) -> u16;
+    /// Whether the month of the day is a leap month.
+    fn is_leap_month(&self) -> bool;
+    fn date(&self) -> Date;
+    fn succ(&self) -> Box<dyn AnyDay>;
+    fn pred(&self) -> Box<dyn AnyDay>;
+}
+
+impl std::fmt::Debug for dyn AnyDay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.calendar(), self)
+    }
+}
+
+impl PartialEq for dyn AnyDay {
+    fn eq(&self, other: &Self) -> bool {
+        self.calendar() == other.calendar() && self.date() == other.date()
+    }
+}
+
+struct Erased<C> {
+    name: &'static str,
+    calendar: PhantomData<fn() -> C>,
+}
+
+impl<C> Erased<C> {
+    const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            calendar: PhantomData,
+        }
+    }
+}
+
+impl<C> Erased<C>
+where
+    C: Calendar + 'static,
+{
+    fn erase(&self, day: C::Day) -> Box<dyn AnyDay> {
+        Box::new(ErasedDay::<C> {
+            name: self.name,
+            day,
+        })
+    }
+}
+
+impl<C> AnyCalendar for Erased<C>
+where
+    C: CalendarInfo + 'static,
+    C::Day: TryFrom<Date> + std::str::FromStr,
+{
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn display_name(&self) -> &'static str {
+        C::display_name()
+    }
+
+    fn supported_years(&self) -> RangeInclusive<i32> {
+        C::supported_years()
+    }
+
+    fn months_per_year(&self) -> RangeInclusive<usize> {
+        C::months_per_year()
+    }
+
+    fn has_leap_months(&self) -> bool {
+        C::has_leap_months()
+    }
+
+    fn epoch(&self) -> Date {
+        C::epoch()
+    }
+
+    fn num_months(&self, year: i32) -> Option<usize> {
+        Some(C::from_y(year)?.num_months())
+    }
+
+    fn num_days(&self, year: i32) -> Option<usize> {
+        Some(C::from_y(year)?.num_days())
+    }
+
+    fn day(&self, year: i32, month: u32, day: u32) -> Option<Box<dyn AnyDay>> {
+        let (month, day) = (C::MonthOrd::from_u32(month)?, C::DayOrd::from_u32(day)?);
+        Some(self.erase(C::from_ymd(year, month, day)?))
+    }
+
+    fn day_in_year(&self, year: i32, day_ord: u16) -> Option<Box<dyn AnyDay>> {
+        Some(self.erase(C::from_yo(year, day_ord)?))
+    }
+
+    fn day_of_date(&self, date: Date) -> Option<Box<dyn AnyDay>> {
+        Some(self.erase(C::Day::try_from(date).ok()?))
+    }
+
+    fn parse_day(&self, s: &str) -> Option<Box<dyn AnyDay>> {
+        Some(self.erase(s.parse().ok()?))
+    }
+}
+
+struct ErasedDay<C: Calendar> {
+    name: &'static str,
+    day: C::Day,
+}
+
+impl<C: Calendar> std::fmt::Display for ErasedDay<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.day, f)
+    }
+}
+
+impl<C: Calendar + 'static> AnyDay for ErasedDay<C> {
+    fn calendar(&self) -> &'static str {
+        self.name
+    }
+
+    fn year(&self) -> i32 {
+        self.day.the_year().ord()
+    }
+
+    fn month(&self) -> u32 {
+        self.day.the_month().ord().to_u32()
+    }
+
+    fn day(&self) -> u32 {
+        self.day.ord().to_u32()
+    }
+
+    fn ord_in_year(&self) -> u16 {
+        self.day.ord_in_year()
+    }
+
+    fn is_leap_month(&self) -> bool {
+        self.day.the_month().is_leap()
+    }
+
+    fn date(&self) -> Date {
+        self.day.into()
+    }
+
+    fn succ(&self) -> Box<dyn AnyDay> {
+        Box::new(Self {
+            name: self.name,
+            day: self.day.succ(),
+        })
+    }
+
+    fn pred(&self) -> Box<dyn AnyDay> {
+        Box::new(Self {
+            name: self.name,
+            day: self.day.pred(),
+        })
+    }
+}
+
+/// All registered calendars.
+pub static CALENDARS: &[&(dyn AnyCalendar + Sync)] = &[
+    &Erased::<GregorianCalendar>::new("gregorian"),
+    #[cfg(feature = "astronomy")]
+    &Erased::<ChineseCalendar>::new("chinese"),
+];
+
+/// Finds a calendar by its name, ignoring case.
+pub fn calendar(name: &str) -> Option<&'static dyn AnyCalendar> {
+    CALENDARS
+        .iter()
+        .find(|calendar| calendar.name().eq_ignore_ascii_case(name))
+        .map(|&calendar| calendar as &dyn AnyCalendar)
+}
+
+/// The names of all registered calendars.
+pub fn names() -> impl Iterator<Item = &'static str> {
+    CALENDARS.iter().map(|calendar| calendar.name())
+}
+
+#[test]
+fn test_registry() {
+    assert!(calendar("julian").is_none());
+
+    let gregorian = calendar("Gregorian").unwrap();
+    assert_eq!(gregorian.name(), "gregorian");
+    assert_eq!(gregorian.display_name(), "Gregorian");
+    assert_eq!(gregorian.supported_years(), gregorian::SUPPORTED_YEARS);
+    assert_eq!(gregorian.months_per_year(), 12..=12);
+    assert!(!gregorian.has_leap_months());
+    assert_eq!(gregorian.epoch(), Date::from_jdn(1721426));
+    assert_eq!(gregorian.num_months(2024), Some(12));
+    assert_eq!(gregorian.num_days(2024), Some(366));
+    let day = gregorian.day(2024, 2, 10).unwrap();
+    assert_eq!(day.calendar(), "gregorian");
+    assert_eq!((day.year(), day.month(), day.day()), (2024, 2, 10));
+    assert_eq!(day.ord_in_year(), 41);
+    assert_eq!(day.to_string(), "2024-02-10");
+    assert_eq!(day.succ().to_string(), "2024-02-11");
+    assert_eq!(&*gregorian.day_in_year(2024, 41).unwrap(), &*day);
+    assert!(gregorian.day(2024, 2, 30).is_none());
+    assert_eq!(&*gregorian.parse_day("2024-02-10").unwrap(), &*day);
+    assert!(gregorian.parse_day("2024-02-30").is_none());
+}
+
+#[cfg(feature = "astronomy")]
+#[test]
+fn test_registry_chinese() {
+    assert_eq!(names().collect::<Vec<_>>(), ["gregorian", "chinese"]);
+
+    let chinese = calendar("chinese").unwrap();
+    assert_eq!(chinese.display_name(), "Chinese");
+    assert_eq!(chinese.supported_years(), chinese::SUPPORTED_YEARS);
+    assert_eq!(chinese.months_per_year(), 12..=13);
+    assert!(chinese.has_leap_months());
+    assert_eq!(
+        chinese.day_of_date(chinese.epoch()).unwrap().to_string(),
+        "辛酉年正月初一"
+    );
+    assert_eq!(chinese.num_months(2023), Some(13));
+    let new_year = chinese
+        .day_of_date(GregorianCalendar::from_ymd(2024, 2, 10).unwrap().into())
+        .unwrap();
+    assert_eq!(new_year.to_string(), "甲辰年正月初一");
+    assert_eq!(format!("{:#}", new_year), "公元2024年农历甲辰年正月初一");
+    assert_eq!(new_year.pred().to_string(), "癸卯年十二月三十");
+    assert!(chinese.day_of_date(Date::from_jdn(i32::MAX)).is_none());
+    assert_eq!(&*chinese.parse_day("2024年正月初一").unwrap(), &*new_year);
+    // The leap month of 2023 is the third month.
+    let leap = chinese.day(2023, 3, 1).unwrap();
+    assert!(leap.is_leap_month());
+    assert_eq!(leap.to_string(), "癸卯年闰二月初一");
+    assert!(chinese.num_months(9999).is_none());
+}
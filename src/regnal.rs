@@ -0,0 +1,251 @@
+//! Era years: the era names (年号) of the Ming and Qing dynasties of China, the
+//! eras of Japan (和暦), the Minguo (民国) calendar of Taiwan and the Juche
+//! (주체) calendar of North Korea.
+//!
+//! The eras of China count Chinese years and the others Gregorian years; the
+//! Minguo years are the same in both, as are their numbers. The year in which
+//! an era changes belongs to both eras. The Chinese calendar maps its years to
+//! the eras of China and the Minguo calendar in [`chinese::era`].
+//!
+//! [`chinese::era`]: crate::chinese::era
+
+use crate::numeral::{format_numeral, parse_era_year};
+
+/// The country using an era.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Country {
+    China,
+    Japan,
+    Taiwan,
+    NorthKorea,
+}
+
+/// An era, e.g. 令和 of Japan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Era {
+    pub country: Country,
+    /// The dynasty of an era of China, e.g. 清.
+    pub dynasty: Option<&'static str>,
+    pub name: &'static str,
+    pub english: &'static str,
+    /// The year of the first year (元年) of the era, Chinese for the eras of
+    /// China and Gregorian otherwise.
+    pub first_year: i32,
+    /// The year of the last year of the era.
+    pub last_year: i32,
+}
+
+const fn era(
+    country: Country,
+    name: &'static str,
+    english: &'static str,
+    first_year: i32,
+    last_year: i32,
+) -> Era {
+    Era {
+        country,
+        dynasty: None,
+        name,
+        english,
+        first_year,
+        last_year,
+    }
+}
+
+const fn chinese_era(
+    dynasty: &'static str,
+    name: &'static str,
+    english: &'static str,
+    first_year: i32,
+    last_year: i32,
+) -> Era {
+    Era {
+        dynasty: Some(dynasty),
+        ..era(Country::China, name, english, first_year, last_year)
+    }
+}
+
+/// All known eras, in chronological order by country.
+pub const ERAS: &[Era] = &[
+    chinese_era("明", "洪武", "Hongwu", 1368, 1398),
+    chinese_era("明", "建文", "Jianwen", 1399, 1402),
+    chinese_era("明", "永乐", "Yongle", 1403, 1424),
+    chinese_era("明", "洪熙", "Hongxi", 1425, 1425),
+    chinese_era("明", "宣德", "Xuande", 1426, 1435),
+    chinese_era("明", "正统", "Zhengtong", 1436, 1449),
+    chinese_era("明", "景泰", "Jingtai", 1450, 1457),
+    chinese_era("明", "天顺", "Tianshun", 1457, 1464),
+    chinese_era("明", "成化", "Chenghua", 1465, 1487),
+    chinese_era("明", "弘治", "Hongzhi", 1488, 1505),
+    chinese_era("明", "正德", "Zhengde", 1506, 1521),
+    chinese_era("明", "嘉靖", "Jiajing", 1522, 1566),
+    chinese_era("明", "隆庆", "Longqing", 1567, 1572),
+    chinese_era("明", "万历", "Wanli", 1573, 1620),
+    chinese_era("明", "泰昌", "Taichang", 1620, 1620),
+    chinese_era("明", "天启", "Tianqi", 1621, 1627),
+    chinese_era("明", "崇祯", "Chongzhen", 1628, 1644),
+    chinese_era("清", "顺治", "Shunzhi", 1644, 1661),
+    chinese_era("清", "康熙", "Kangxi", 1662, 1722),
+    chinese_era("清", "雍正", "Yongzheng", 1723, 1735),
+    chinese_era("清", "乾隆", "Qianlong", 1736, 1795),
+    chinese_era("清", "嘉庆", "Jiaqing", 1796, 1820),
+    chinese_era("清", "道光", "Daoguang", 1821, 1850),
+    chinese_era("清", "咸丰", "Xianfeng", 1851, 1861),
+    chinese_era("清", "同治", "Tongzhi", 1862, 1874),
+    chinese_era("清", "光绪", "Guangxu", 1875, 1908),
+    chinese_era("清", "宣统", "Xuantong", 1909, 1911),
+    era(Country::Japan, "明治", "Meiji", 1868, 1912),
+    era(Country::Japan, "大正", "Taisho", 1912, 1926),
+    era(Country::Japan, "昭和", "Showa", 1926, 1989),
+    era(Country::Japan, "平成", "Heisei", 1989, 2019),
+    era(Country::Japan, "令和", "Reiwa", 2019, i32::MAX),
+    era(Country::Taiwan, "民国", "Minguo", 1912, i32::MAX),
+    era(Country::NorthKorea, "주체", "Juche", 1912, i32::MAX),
+];
+
+impl Era {
+    /// Finds an era by its name or English name.
+    pub fn from_name(name: &str) -> Option<&'static Era> {
+        ERAS.iter()
+            .find(|era| era.name == name || era.english.eq_ignore_ascii_case(name))
+    }
+
+    /// The year of the given year of the era, Chinese for the eras of China
+    /// and Gregorian otherwise.
+    pub fn year(&self, era_year: u32) -> Option<i32> {
+        let year = self
+            .first_year
+            .checked_add(i32::try_from(era_year).ok()? - 1)?;
+        if era_year >= 1 && year <= self.last_year {
+            Some(year)
+        } else {
+            None
+        }
+    }
+
+    pub fn contains(&self, year: i32) -> bool {
+        (self.first_year..=self.last_year).contains(&year)
+    }
+}
+
+/// A year of an era, e.g. 令和7年 or 康熙六十一年.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EraYear {
+    pub era: &'static Era,
+    pub year: u32,
+}
+
+impl EraYear {
+    /// The year, Chinese for the eras of China and Gregorian otherwise.
+    pub fn ord(&self) -> i32 {
+        self.era.first_year + self.year as i32 - 1
+    }
+}
+
+/// All eras of a country containing the given year, Chinese for China and
+/// Gregorian otherwise, in chronological order.
+pub fn era_years(country: Country, year: i32) -> Vec<EraYear> {
+    ERAS.iter()
+        .filter(|era| era.country == country && era.contains(year))
+        .map(|era| EraYear {
+            era,
+            year: (year - era.first_year + 1) as u32,
+        })
+        .collect()
+}
+
+/// An error which can be returned when parsing an era year.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseEraYearError;
+
+impl std::fmt::Display for ParseEraYearError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid era year")
+    }
+}
+
+impl std::error::Error for ParseEraYearError {}
+
+impl std::str::FromStr for EraYear {
+    type Err = ParseEraYearError;
+
+    /// Parses a year of an era, e.g. "令和7年", "平成元年", "Reiwa 7",
+    /// "주체114년", "康熙三十年" or "清康熙元年".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s.trim_end_matches(['年', '년']);
+        ERAS.iter()
+            .find_map(|era| {
+                let name = match era.dynasty {
+                    Some(dynasty) => s.strip_prefix(dynasty).unwrap_or(s),
+                    None => s,
+                };
+                let rest = name.strip_prefix(era.name).or_else(|| {
+                    let prefix = s.get(..era.english.len())?;
+                    prefix
+                        .eq_ignore_ascii_case(era.english)
+                        .then(|| &s[era.english.len()..])
+                })?;
+                let year = parse_era_year(rest.trim_start())?;
+                era.year(year)?;
+                Some(EraYear { era, year })
+            })
+            .ok_or(ParseEraYearError)
+    }
+}
+
+impl std::fmt::Display for EraYear {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            return write!(f, "{} {}", self.era.english, self.year);
+        }
+        match self.era.country {
+            Country::NorthKorea => write!(f, "{}{}년", self.era.name, self.year),
+            _ if self.year == 1 => write!(f, "{}元年", self.era.name),
+            Country::China => write!(f, "{}{}年", self.era.name, format_numeral(self.year)),
+            _ => write!(f, "{}{}年", self.era.name, self.year),
+        }
+    }
+}
+
+#[test]
+fn test_regnal() {
+    let reiwa = Era::from_name("令和").unwrap();
+    assert_eq!(Era::from_name("reiwa"), Some(reiwa));
+    assert_eq!(reiwa.year(7), Some(2025));
+    assert_eq!(Era::from_name("平成").unwrap().year(32), None);
+
+    let years = era_years(Country::Japan, 1989);
+    assert_eq!(years.len(), 2);
+    assert_eq!(years[0].to_string(), "昭和64年");
+    assert_eq!(years[1].to_string(), "平成元年");
+    assert_eq!(format!("{:#}", years[1]), "Heisei 1");
+    assert_eq!(era_years(Country::Taiwan, 2025)[0].to_string(), "民国114年");
+    assert_eq!(era_years(Country::Taiwan, 1912)[0].to_string(), "民国元年");
+    let years = era_years(Country::China, 1722);
+    assert_eq!(years[0].to_string(), "康熙六十一年");
+    assert_eq!(format!("{:#}", years[0]), "Kangxi 61");
+    assert_eq!(
+        era_years(Country::NorthKorea, 2025)[0].to_string(),
+        "주체114년"
+    );
+    assert!(era_years(Country::Japan, 1867).is_empty());
+
+    for (s, year) in [
+        ("令和7年", 2025),
+        ("平成元年", 1989),
+        ("Reiwa 7", 2025),
+        ("昭和六十四年", 1989),
+        ("民国114年", 2025),
+        ("Juche 114", 2025),
+        ("주체114년", 2025),
+        ("康熙三十年", 1691),
+        ("清康熙元年", 1662),
+        ("Kangxi 61", 1722),
+    ] {
+        assert_eq!(s.parse::<EraYear>().map(|y| y.ord()), Ok(year), "{}", s);
+    }
+    assert!("平成32年".parse::<EraYear>().is_err());
+    assert!("Reiwa".parse::<EraYear>().is_err());
+    assert!("明康熙元年".parse::<EraYear>().is_err());
+}
@@ -0,0 +1,286 @@
+//! The `render` subcommand, a printable wall-calendar sheet as SVG or PNG.
+
+use std::fmt::Write as _;
+
+use omnical::*;
+
+use super::{chinese_annotation, Painter};
+
+/// The sizes of the parts of a month, in pixels.
+struct Sheet {
+    cell_width: u32,
+    cell_height: u32,
+    title_size: u32,
+    day_size: u32,
+    note_size: u32,
+}
+
+impl Sheet {
+    const MONTH: Sheet = Sheet {
+        cell_width: 120,
+        cell_height: 90,
+        title_size: 32,
+        day_size: 26,
+        note_size: 15,
+    };
+
+    const YEAR: Sheet = Sheet {
+        cell_width: 44,
+        cell_height: 40,
+        title_size: 18,
+        day_size: 15,
+        note_size: 9,
+    };
+
+    fn width(&self) -> u32 {
+        self.cell_width * 7
+    }
+
+    /// The height of a month, with its title, the weekdays and 6 weeks.
+    fn height(&self) -> u32 {
+        self.title_size * 2 + self.note_size * 2 + self.cell_height * 6
+    }
+}
+
+const MARGIN: u32 = 30;
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn text(svg: &mut String, x: u32, y: u32, size: u32, fill: &str, anchor: &str, text: &str) {
+    writeln!(
+        svg,
+        r#"<text x="{}" y="{}" font-size="{}" fill="{}" text-anchor="{}">{}</text>"#,
+        x,
+        y,
+        size,
+        fill,
+        anchor,
+        escape(text)
+    )
+    .unwrap();
+}
+
+/// Draws a month with its top left corner at (x, y).
+fn month(
+    svg: &mut String,
+    month: GregorianMonth,
+    (x, y): (u32, u32),
+    sheet: &Sheet,
+    first_weekday: Weekday,
+    painter: &Painter,
+) {
+    let (first, last): (Date, Date) = (month.first_day().into(), month.last_day().into());
+    let mut top = y + sheet.title_size * 3 / 2;
+    let title = match (ChineseDay::try_from(first), ChineseDay::try_from(last)) {
+        (Ok(first), Ok(last)) if first.the_year() == last.the_year() => {
+            format!("{:-}  {}", month, first.the_year())
+        }
+        (Ok(first), Ok(last)) => format!("{:-}  {}/{}", month, first.the_year(), last.the_year()),
+        // A month outside the Chinese calendar.
+        _ => format!("{:-}", month),
+    };
+    text(
+        svg,
+        x + sheet.width() / 2,
+        top,
+        sheet.title_size,
+        "#222",
+        "middle",
+        &title,
+    );
+    top += sheet.note_size * 2;
+    let mut weekday = first_weekday;
+    for column in 0..7 {
+        text(
+            svg,
+            x + sheet.cell_width * column + sheet.cell_width / 2,
+            top,
+            sheet.note_size,
+            "#666",
+            "middle",
+            &weekday.to_string()[..3],
+        );
+        weekday = weekday.succ();
+    }
+    top += sheet.note_size;
+
+    let offset = (first.weekday() as i32 - first_weekday as i32).rem_euclid(7);
+    let mut date = first;
+    while date <= last {
+        let index = (date - first + offset) as u32;
+        let (cell_x, cell_y) = (
+            x + sheet.cell_width * (index % 7),
+            top + sheet.cell_height * (index / 7),
+        );
+        writeln!(
+            svg,
+            r##"<rect x="{}" y="{}" width="{}" height="{}" fill="none" stroke="#ccc"/>"##,
+            cell_x, cell_y, sheet.cell_width, sheet.cell_height
+        )
+        .unwrap();
+        let day_fill = if matches!(date.weekday(), Saturday | Sunday) {
+            "#c00"
+        } else {
+            "#222"
+        };
+        let padding = sheet.cell_width / 12;
+        text(
+            svg,
+            cell_x + padding,
+            cell_y + padding + sheet.day_size,
+            sheet.day_size,
+            day_fill,
+            "start",
+            &GregorianDay::from(date).ord().to_string(),
+        );
+        let note_fill = if ChineseDay::try_from(date).is_ok_and(|day| day.festival().is_some()) {
+            "#a0a"
+        } else if painter.solar_terms.contains_key(&date) {
+            "#080"
+        } else {
+            "#666"
+        };
+        text(
+            svg,
+            cell_x + padding,
+            cell_y + sheet.cell_height - padding,
+            sheet.note_size,
+            note_fill,
+            "start",
+            &chinese_annotation(date, painter),
+        );
+        date = date.succ();
+    }
+}
+
+fn document(width: u32, height: u32, body: &str) -> String {
+    format!(
+        concat!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}" font-family="sans-serif">"#,
+            "\n",
+            r#"<rect width="100%" height="100%" fill="white"/>"#,
+            "\n{2}</svg>\n"
+        ),
+        width, height, body
+    )
+}
+
+/// A sheet of a month.
+pub fn month_svg(gregorian_month: GregorianMonth, first_weekday: Weekday, tz: f64) -> String {
+    let sheet = Sheet::MONTH;
+    let (begin, end) = (
+        gregorian_month.first_day().into(),
+        Date::from(gregorian_month.last_day()).succ(),
+    );
+    let painter = Painter::new(false, begin, begin, end, tz);
+    let mut body = String::new();
+    month(
+        &mut body,
+        gregorian_month,
+        (MARGIN, MARGIN),
+        &sheet,
+        first_weekday,
+        &painter,
+    );
+    document(
+        sheet.width() + MARGIN * 2,
+        sheet.height() + MARGIN * 2,
+        &body,
+    )
+}
+
+/// A sheet of a year, with 3 months in a row.
+pub fn year_svg(year: GregorianYear, first_weekday: Weekday, tz: f64) -> String {
+    let sheet = Sheet::YEAR;
+    let (begin, end) = (year.first_day().into(), Date::from(year.last_day()).succ());
+    let painter = Painter::new(false, begin, begin, end, tz);
+    let title_height = Sheet::MONTH.title_size * 2;
+    let mut body = String::new();
+    text(
+        &mut body,
+        MARGIN + (sheet.width() * 3 + MARGIN * 2) / 2,
+        MARGIN + Sheet::MONTH.title_size,
+        Sheet::MONTH.title_size,
+        "#222",
+        "middle",
+        &format!("{:-}", year),
+    );
+    for (i, gregorian_month) in year.months().enumerate() {
+        let (row, column) = (i as u32 / 3, i as u32 % 3);
+        let origin = (
+            MARGIN + (sheet.width() + MARGIN) * column,
+            MARGIN + title_height + (sheet.height() + MARGIN) * row,
+        );
+        month(
+            &mut body,
+            gregorian_month,
+            origin,
+            &sheet,
+            first_weekday,
+            &painter,
+        );
+    }
+    document(
+        sheet.width() * 3 + MARGIN * 4,
+        title_height + (sheet.height() + MARGIN) * 4 + MARGIN,
+        &body,
+    )
+}
+
+/// Rasterizes an SVG document to PNG, with the fonts of the system.
+pub fn to_png(svg: &str) -> Result<Vec<u8>, String> {
+    use resvg::{tiny_skia, usvg};
+
+    let mut options = usvg::Options::default();
+    let fontdb = options.fontdb_mut();
+    fontdb.load_system_fonts();
+    // The default sans-serif family, Arial, may be missing, so prefer a font
+    // with Chinese characters, or at least a sans-serif one, among those
+    // installed.
+    let families: Vec<_> = fontdb
+        .faces()
+        .flat_map(|face| face.families.iter().map(|(family, _)| family.clone()))
+        .collect();
+    if let Some(family) = [
+        "Noto Sans CJK SC",
+        "Source Han Sans SC",
+        "WenQuanYi Micro Hei",
+        "DejaVu Sans",
+    ]
+    .iter()
+    .map(|family| family.to_string())
+    .find(|family| families.contains(family))
+    .or_else(|| families.first().cloned())
+    {
+        fontdb.set_sans_serif_family(family);
+    }
+    let tree = usvg::Tree::from_str(svg, &options).map_err(|e| e.to_string())?;
+    let size = tree.size().to_int_size();
+    let mut pixmap =
+        tiny_skia::Pixmap::new(size.width(), size.height()).ok_or("invalid image size")?;
+    resvg::render(&tree, tiny_skia::Transform::default(), &mut pixmap.as_mut());
+    pixmap.encode_png().map_err(|e| e.to_string())
+}
+
+#[test]
+fn test_render() {
+    let month = GregorianCalendar::from_ym(2024, 2).unwrap();
+    let svg = month_svg(month, Monday, 8.0);
+    assert!(svg.starts_with("<svg "));
+    assert!(svg.contains(">February 2024  癸卯年/甲辰年<"));
+    assert!(svg.contains(">春节<"));
+    assert!(svg.contains(">雨水<"));
+    assert_eq!(svg.matches("<rect ").count(), 1 + 29);
+
+    let year = GregorianCalendar::from_y(2024).unwrap();
+    let svg = year_svg(year, Sunday, 8.0);
+    assert_eq!(svg.matches("<rect ").count(), 1 + 366);
+    assert!(svg.contains(">中秋节<"));
+
+    let png = to_png(&svg).unwrap();
+    assert!(png.starts_with(b"\x89PNG"));
+}
@@ -0,0 +1,150 @@
+//! Simplified and Traditional Chinese scripts.
+
+/// The pairs of simplified and traditional characters used in the Chinese
+/// names of this crate, sorted by the simplified character.
+const TRADITIONAL_CHARS: [(char, char); 64] = [
+    ('万', '萬'),
+    ('东', '東'),
+    ('丰', '豐'),
+    ('乌', '烏'),
+    ('乐', '樂'),
+    ('农', '農'),
+    ('剑', '劍'),
+    ('匮', '匱'),
+    ('华', '華'),
+    ('历', '曆'),
+    ('参', '參'),
+    ('双', '雙'),
+    ('启', '啟'),
+    ('国', '國'),
+    ('处', '處'),
+    ('头', '頭'),
+    ('娄', '婁'),
+    ('庆', '慶'),
+    ('开', '開'),
+    ('张', '張'),
+    ('惊', '驚'),
+    ('执', '執'),
+    ('时', '時'),
+    ('杨', '楊'),
+    ('残', '殘'),
+    ('毕', '畢'),
+    ('涧', '澗'),
+    ('满', '滿'),
+    ('灭', '滅'),
+    ('灯', '燈'),
+    ('炉', '爐'),
+    ('狮', '獅'),
+    ('猪', '豬'),
+    ('祯', '禎'),
+    ('种', '種'),
+    ('统', '統'),
+    ('绪', '緒'),
+    ('绿', '綠'),
+    ('胜', '勝'),
+    ('腊', '臘'),
+    ('节', '節'),
+    ('虚', '虛'),
+    ('蛰', '蟄'),
+    ('蜡', '蠟'),
+    ('蝎', '蠍'),
+    ('谷', '穀'),
+    ('负', '負'),
+    ('轸', '軫'),
+    ('钏', '釧'),
+    ('钗', '釵'),
+    ('锋', '鋒'),
+    ('长', '長'),
+    ('闭', '閉'),
+    ('闰', '閏'),
+    ('阳', '陽'),
+    ('陈', '陳'),
+    ('雳', '靂'),
+    ('顺', '順'),
+    ('马', '馬'),
+    ('驿', '驛'),
+    ('鱼', '魚'),
+    ('鸡', '雞'),
+    ('黄', '黃'),
+    ('龙', '龍'),
+];
+
+/// The script in which Chinese text is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Script {
+    #[default]
+    Simplified,
+    Traditional,
+}
+
+impl Script {
+    /// Converts Chinese text produced by this crate, which is in simplified
+    /// script, into this script.
+    pub fn convert(&self, s: &str) -> String {
+        match self {
+            Script::Simplified => s.to_string(),
+            Script::Traditional => s
+                .chars()
+                .map(|c| {
+                    TRADITIONAL_CHARS
+                        .binary_search_by_key(&c, |&(s, _)| s)
+                        .map_or(c, |i| TRADITIONAL_CHARS[i].1)
+                })
+                .collect(),
+        }
+    }
+
+    /// Wraps a value so that it is displayed in this script.
+    pub fn display<T: std::fmt::Display>(&self, value: T) -> Scripted<T> {
+        Scripted {
+            script: *self,
+            value,
+        }
+    }
+}
+
+/// A value displayed in a given script, created by [`Script::display`].
+#[derive(Debug, Clone, Copy)]
+pub struct Scripted<T> {
+    script: Script,
+    value: T,
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for Scripted<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = if f.alternate() {
+            format!("{:#}", self.value)
+        } else {
+            self.value.to_string()
+        };
+        f.write_str(&self.script.convert(&s))
+    }
+}
+
+#[test]
+fn test_script() {
+    assert!(TRADITIONAL_CHARS.windows(2).all(|w| w[0].0 < w[1].0));
+    assert_eq!(Script::Traditional.convert("惊蛰"), "驚蟄");
+    assert_eq!(Script::Simplified.convert("惊蛰"), "惊蛰");
+}
+
+#[cfg(feature = "astronomy")]
+#[test]
+fn test_script_display() {
+    use crate::*;
+
+    assert_eq!(
+        Script::Traditional.convert(AwakeningOfInsects.chinese()),
+        "驚蟄"
+    );
+
+    let day = ChineseCalendar::from_ylmd(2023, true, 2, 1).unwrap();
+    assert_eq!(
+        Script::Traditional.display(day).to_string(),
+        "癸卯年閏二月初一"
+    );
+    assert_eq!(
+        format!("{:#}", Script::Traditional.display(day)),
+        "公元2023年農曆癸卯年閏二月初一"
+    );
+}
@@ -0,0 +1,140 @@
+//! Serde and JSON Schema support with the `serde` and `schemars` features,
+//! e.g. for services publishing OpenAPI schemas of their dates.
+//!
+//! [`Date`] is serialized as its Julian day number, e.g. 2460351.
+//! [`GregorianDay`] is serialized as an ISO 8601 date, e.g. "2024-02-10", and
+//! with the `astronomy` feature, [`ChineseDay`] as its code of [`code`], e.g.
+//! "C:2023-02L-05", as in SQL with the `sqlx` and `diesel` features. Days are
+//! checked when deserialized, so an invalid day is an error rather than a
+//! value.
+
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::*;
+
+impl Serialize for Date {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(self.jdn())
+    }
+}
+
+impl<'de> Deserialize<'de> for Date {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        i32::deserialize(deserializer).map(Date::from_jdn)
+    }
+}
+
+impl Serialize for GregorianDay {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_iso8601())
+    }
+}
+
+impl<'de> Deserialize<'de> for GregorianDay {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        GregorianDay::from_iso8601(&s).map_err(|e| D::Error::custom(format!("{}: {}", e, s)))
+    }
+}
+
+#[cfg(feature = "astronomy")]
+impl Serialize for ChineseDay {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_code())
+    }
+}
+
+#[cfg(feature = "astronomy")]
+impl<'de> Deserialize<'de> for ChineseDay {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        ChineseDay::from_code(&s).map_err(|e| D::Error::custom(format!("{}: {}", e, s)))
+    }
+}
+
+#[cfg(feature = "schemars")]
+mod schemars_impls {
+    use std::borrow::Cow;
+
+    use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+    use super::*;
+
+    impl JsonSchema for Date {
+        fn schema_name() -> Cow<'static, str> {
+            "Date".into()
+        }
+
+        fn json_schema(_: &mut SchemaGenerator) -> Schema {
+            json_schema!({
+                "type": "integer",
+                "format": "int32",
+                "description": "The Julian day number of the day.",
+            })
+        }
+    }
+
+    impl JsonSchema for GregorianDay {
+        fn schema_name() -> Cow<'static, str> {
+            "GregorianDay".into()
+        }
+
+        fn json_schema(_: &mut SchemaGenerator) -> Schema {
+            json_schema!({
+                "type": "string",
+                "pattern": "^([0-9]{4}|[+-][0-9]{5,})-[0-9]{2}-[0-9]{2}$",
+                "description": "A day of the Gregorian calendar in ISO 8601, e.g. \"2024-02-10\".",
+            })
+        }
+    }
+
+    #[cfg(feature = "astronomy")]
+    impl JsonSchema for ChineseDay {
+        fn schema_name() -> Cow<'static, str> {
+            "ChineseDay".into()
+        }
+
+        fn json_schema(_: &mut SchemaGenerator) -> Schema {
+            json_schema!({
+                "type": "string",
+                "pattern": "^C:([0-9]{4}|~[0-9]{7}|![0-9]{7})-[0-9]{2}L?-[0-9]{2}$",
+                "description": "A day of the Chinese calendar as its code, e.g. \"C:2023-02L-05\" in the leap month after the second month.",
+            })
+        }
+    }
+
+    #[test]
+    fn test_schemars() {
+        let schema = schemars::schema_for!(GregorianDay);
+        assert_eq!(schema.get("type"), Some(&"string".into()));
+        let pattern = schema.get("pattern").unwrap().as_str().unwrap();
+        assert!(pattern.contains("[0-9]{4}"));
+        let schema = schemars::schema_for!(Date);
+        assert_eq!(schema.get("type"), Some(&"integer".into()));
+    }
+}
+
+#[test]
+fn test_serde() {
+    let day = GregorianCalendar::from_ymd(2024, 2, 10).unwrap();
+    let date = Date::from(day);
+    assert_eq!(serde_json::to_string(&date).unwrap(), "2460351");
+    assert_eq!(serde_json::from_str::<Date>("2460351").unwrap(), date);
+    assert_eq!(serde_json::to_string(&day).unwrap(), r#""2024-02-10""#);
+    assert_eq!(
+        serde_json::from_str::<GregorianDay>(r#""2024-02-10""#).unwrap(),
+        day
+    );
+    assert!(serde_json::from_str::<GregorianDay>(r#""2024-02-30""#).is_err());
+}
+
+#[cfg(feature = "astronomy")]
+#[test]
+fn test_serde_chinese() {
+    let day = ChineseCalendar::from_ylmd(2023, true, 2, 5).unwrap();
+    let json = serde_json::to_string(&day).unwrap();
+    assert_eq!(json, format!(r#""{}""#, day.to_code()));
+    assert_eq!(serde_json::from_str::<ChineseDay>(&json).unwrap(), day);
+    assert!(serde_json::from_str::<ChineseDay>(r#""C:2024-02L-05""#).is_err());
+}
@@ -0,0 +1,307 @@
+//! SQL types with the `sqlx` and `diesel` features, for PostgreSQL and SQLite.
+//!
+//! [`Date`] maps to `DATE`, which PostgreSQL stores as the days since
+//! 2000-01-01 and SQLite as ISO 8601 text, e.g. "2024-02-10".
+//!
+//! With the `astronomy` feature, [`ChineseDay`] maps to text in its code of
+//! [`code`], e.g. "C:2023-02L-05", see [`ChineseDay::to_code`]. Unlike the
+//! stem-branch year of its `Display` output, the code of a day does not
+//! depend on the current year, and the codes sort as the days do.
+
+use crate::*;
+
+/// The Julian day number of 2000-01-01, the epoch of `DATE` in PostgreSQL.
+const PG_EPOCH_JDN: i32 = 2451545;
+
+fn pg_days(date: Date) -> Option<i32> {
+    date.jdn().checked_sub(PG_EPOCH_JDN)
+}
+
+fn from_pg_days(days: i32) -> Option<Date> {
+    days.checked_add(PG_EPOCH_JDN).map(Date::from_jdn)
+}
+
+fn parse_sqlite_date(s: &str) -> Option<Date> {
+    GregorianDay::from_iso8601(s).ok().map(Date::from)
+}
+
+fn out_of_range(date: Date) -> String {
+    format!("date out of range: JDN {}", date.jdn())
+}
+
+#[cfg(feature = "sqlx")]
+mod sqlx_impls {
+    use sqlx::encode::IsNull;
+    use sqlx::error::BoxDynError;
+    use sqlx::postgres::types::Oid;
+    use sqlx::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+    use sqlx::sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef};
+    #[cfg(feature = "astronomy")]
+    use sqlx::Database;
+    use sqlx::{Decode, Encode, Type, TypeInfo as _};
+
+    use super::*;
+    use crate::calendar::{Day as _, Month as _, Year as _};
+
+    /// The OID of `DATE` in PostgreSQL.
+    const PG_DATE_OID: Oid = Oid(1082);
+
+    /// Parses a date in the text format of PostgreSQL, e.g. "2024-02-10" or
+    /// "0044-03-15 BC".
+    fn parse_pg_date(s: &str) -> Option<Date> {
+        let (s, bc) = match s.strip_suffix(" BC") {
+            Some(s) => (s, true),
+            None => (s, false),
+        };
+        let day = GregorianDay::from_iso8601(s).ok()?;
+        if !bc {
+            return Some(day.into());
+        }
+        let year = 1 - day.the_year().ord();
+        GregorianCalendar::from_ymd(year, day.the_month().ord(), day.ord()).map(Date::from)
+    }
+
+    impl Type<Postgres> for Date {
+        fn type_info() -> PgTypeInfo {
+            PgTypeInfo::with_oid(PG_DATE_OID)
+        }
+    }
+
+    impl Encode<'_, Postgres> for Date {
+        fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+            let days = pg_days(*self).ok_or_else(|| out_of_range(*self))?;
+            Encode::<Postgres>::encode(days, buf)
+        }
+    }
+
+    impl<'r> Decode<'r, Postgres> for Date {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+            match value.format() {
+                PgValueFormat::Binary => {
+                    let days = Decode::<Postgres>::decode(value)?;
+                    Ok(from_pg_days(days).ok_or("date out of range")?)
+                }
+                PgValueFormat::Text => {
+                    let s = value.as_str()?;
+                    Ok(parse_pg_date(s).ok_or_else(|| format!("invalid date: {}", s))?)
+                }
+            }
+        }
+    }
+
+    impl Type<Sqlite> for Date {
+        fn type_info() -> SqliteTypeInfo {
+            <String as Type<Sqlite>>::type_info()
+        }
+
+        fn compatible(ty: &SqliteTypeInfo) -> bool {
+            matches!(ty.name(), "DATE" | "TEXT")
+        }
+    }
+
+    impl<'q> Encode<'q, Sqlite> for Date {
+        fn encode_by_ref(
+            &self,
+            buf: &mut Vec<SqliteArgumentValue<'q>>,
+        ) -> Result<IsNull, BoxDynError> {
+            Encode::<Sqlite>::encode(GregorianDay::from(*self).to_iso8601(), buf)
+        }
+    }
+
+    impl<'r> Decode<'r, Sqlite> for Date {
+        fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+            let s = <&str as Decode<Sqlite>>::decode(value)?;
+            Ok(parse_sqlite_date(s).ok_or_else(|| format!("invalid date: {}", s))?)
+        }
+    }
+
+    #[cfg(feature = "astronomy")]
+    impl<DB: Database> Type<DB> for ChineseDay
+    where
+        String: Type<DB>,
+    {
+        fn type_info() -> DB::TypeInfo {
+            <String as Type<DB>>::type_info()
+        }
+
+        fn compatible(ty: &DB::TypeInfo) -> bool {
+            <String as Type<DB>>::compatible(ty)
+        }
+    }
+
+    #[cfg(feature = "astronomy")]
+    impl<'q, DB: Database> Encode<'q, DB> for ChineseDay
+    where
+        String: Encode<'q, DB>,
+    {
+        fn encode_by_ref(
+            &self,
+            buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+        ) -> Result<IsNull, BoxDynError> {
+            self.to_code().encode(buf)
+        }
+    }
+
+    #[cfg(feature = "astronomy")]
+    impl<'r, DB: Database> Decode<'r, DB> for ChineseDay
+    where
+        &'r str: Decode<'r, DB>,
+    {
+        fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+            let s = <&str as Decode<DB>>::decode(value)?;
+            Ok(ChineseDay::from_code(s).map_err(|e| format!("{}: {}", e, s))?)
+        }
+    }
+
+    #[test]
+    fn test_sqlx() {
+        let date = Date::from(GregorianCalendar::from_ymd(2024, 2, 10).unwrap());
+        let mut buf = PgArgumentBuffer::default();
+        assert!(matches!(
+            Encode::<Postgres>::encode(date, &mut buf),
+            Ok(IsNull::No)
+        ));
+        assert_eq!(&buf[..], 8806i32.to_be_bytes());
+
+        let mut args = Vec::new();
+        assert!(matches!(
+            Encode::<Sqlite>::encode(date, &mut args),
+            Ok(IsNull::No)
+        ));
+        assert!(matches!(
+            &args[..],
+            [SqliteArgumentValue::Text(date)] if date == "2024-02-10"
+        ));
+
+        assert_eq!(
+            parse_pg_date("0044-03-15 BC"),
+            GregorianCalendar::from_ymd(-43, 3, 15).map(Date::from)
+        );
+    }
+
+    #[cfg(feature = "astronomy")]
+    #[test]
+    fn test_sqlx_chinese() {
+        let day = ChineseCalendar::from_ylmd(2023, true, 2, 5).unwrap();
+        let mut args = Vec::new();
+        assert!(matches!(
+            Encode::<Sqlite>::encode(day, &mut args),
+            Ok(IsNull::No)
+        ));
+        assert!(matches!(
+            &args[..],
+            [SqliteArgumentValue::Text(text)] if *text == day.to_code()
+        ));
+    }
+}
+
+#[cfg(feature = "diesel")]
+mod diesel_impls {
+    use std::io::Write as _;
+
+    use diesel::backend::Backend;
+    use diesel::deserialize::{self, FromSql};
+    use diesel::pg::{Pg, PgValue};
+    use diesel::serialize::{self, IsNull, Output, ToSql};
+    use diesel::sql_types;
+    use diesel::sqlite::Sqlite;
+
+    use super::*;
+
+    impl ToSql<sql_types::Date, Pg> for Date {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+            let days = pg_days(*self).ok_or_else(|| out_of_range(*self))?;
+            out.write_all(&days.to_be_bytes())?;
+            Ok(IsNull::No)
+        }
+    }
+
+    impl FromSql<sql_types::Date, Pg> for Date {
+        fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+            let days = <i32 as FromSql<sql_types::Integer, Pg>>::from_sql(value)?;
+            Ok(from_pg_days(days).ok_or("date out of range")?)
+        }
+    }
+
+    impl ToSql<sql_types::Date, Sqlite> for Date {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+            out.set_value(GregorianDay::from(*self).to_iso8601());
+            Ok(IsNull::No)
+        }
+    }
+
+    impl FromSql<sql_types::Date, Sqlite> for Date {
+        fn from_sql(value: <Sqlite as Backend>::RawValue<'_>) -> deserialize::Result<Self> {
+            let s = <String as FromSql<sql_types::Date, Sqlite>>::from_sql(value)?;
+            Ok(parse_sqlite_date(&s).ok_or_else(|| format!("invalid date: {}", s))?)
+        }
+    }
+
+    #[cfg(feature = "astronomy")]
+    impl ToSql<sql_types::Text, Pg> for ChineseDay {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+            out.write_all(self.to_code().as_bytes())?;
+            Ok(IsNull::No)
+        }
+    }
+
+    #[cfg(feature = "astronomy")]
+    impl ToSql<sql_types::Text, Sqlite> for ChineseDay {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+            out.set_value(self.to_code());
+            Ok(IsNull::No)
+        }
+    }
+
+    #[cfg(feature = "astronomy")]
+    impl<DB: Backend> FromSql<sql_types::Text, DB> for ChineseDay
+    where
+        String: FromSql<sql_types::Text, DB>,
+    {
+        fn from_sql(value: DB::RawValue<'_>) -> deserialize::Result<Self> {
+            let s = <String as FromSql<sql_types::Text, DB>>::from_sql(value)?;
+            Ok(ChineseDay::from_code(&s).map_err(|e| format!("{}: {}", e, s))?)
+        }
+    }
+
+    #[test]
+    fn test_diesel() {
+        use diesel::{dsl, Connection as _, RunQueryDsl as _};
+
+        let mut conn = diesel::SqliteConnection::establish(":memory:").unwrap();
+        let date = Date::from(GregorianCalendar::from_ymd(2024, 2, 10).unwrap());
+        let next: Date = diesel::select(
+            dsl::sql::<sql_types::Date>("date(")
+                .bind::<sql_types::Date, _>(date)
+                .sql(", '+1 day')"),
+        )
+        .get_result(&mut conn)
+        .unwrap();
+        assert_eq!(next, date.succ());
+    }
+
+    #[cfg(feature = "astronomy")]
+    #[test]
+    fn test_diesel_chinese() {
+        use diesel::{Connection as _, IntoSql as _, RunQueryDsl as _};
+
+        let mut conn = diesel::SqliteConnection::establish(":memory:").unwrap();
+        let day = ChineseCalendar::from_ylmd(2023, true, 2, 5).unwrap();
+        let text: String = diesel::select(day.into_sql::<sql_types::Text>())
+            .get_result(&mut conn)
+            .unwrap();
+        assert_eq!(text, day.to_code());
+        let back: ChineseDay = diesel::select(day.into_sql::<sql_types::Text>())
+            .get_result(&mut conn)
+            .unwrap();
+        assert_eq!(back, day);
+        assert_eq!(code::parse(&text), Ok(back.into()));
+    }
+}
+
+#[test]
+fn test_sql() {
+    let date = Date::from(GregorianCalendar::from_ymd(2000, 1, 1).unwrap());
+    assert_eq!(pg_days(date), Some(0));
+    assert_eq!(from_pg_days(8805), Some(date + 8805));
+}
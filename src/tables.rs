@@ -0,0 +1,168 @@
+//! Const tables generated at build time: the days before each month of the
+//! Gregorian calendar, and the Chinese calendar and the solar terms of
+//! 1900–2100.
+//!
+//! The tables are computed by the build script with the integer series of
+//! `astronomy::fixed`, so they are the same as the Chinese calendar in
+//! `Mode::Deterministic`, and the tests check them against it when the
+//! `astronomy` feature is enabled. The tables and their functions are const
+//! and use no floating-point arithmetic. Except for [`solar_term`], which
+//! takes a `SolarTerm`, they do not need the `astronomy` feature either.
+
+use crate::*;
+
+pub const FIRST_YEAR: i32 = 1900;
+pub const LAST_YEAR: i32 = 2100;
+const NUM_YEARS: usize = (LAST_YEAR - FIRST_YEAR + 1) as usize;
+
+/// A Chinese year in [`CHINESE_YEARS`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChineseYear {
+    /// The first day of the first month.
+    pub first_day: Date,
+    /// Whether the n-th month of the year, including the leap month if any,
+    /// has 30 days, in bit n.
+    pub long_months: u16,
+    /// The index of the leap month, counting from 0, or 13 if there is none.
+    pub leap_month: u8,
+}
+
+impl ChineseYear {
+    pub const fn num_months(&self) -> usize {
+        if self.leap_month < 13 {
+            13
+        } else {
+            12
+        }
+    }
+
+    /// The number of days of the month of the given index, counting from 0
+    /// and including the leap month if any.
+    pub const fn num_days_of_month(&self, index: usize) -> Option<u8> {
+        if index < self.num_months() {
+            Some(29 + ((self.long_months >> index) & 1) as u8)
+        } else {
+            None
+        }
+    }
+
+    pub const fn num_days(&self) -> u16 {
+        29 * self.num_months() as u16 + self.long_months.count_ones() as u16
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/tables.rs"));
+
+/// The number of days before each month, and in the whole year, of the given
+/// Gregorian year.
+pub const fn gregorian_days_before_month(year: i32) -> &'static [u16; 13] {
+    let is_leap = year % 400 == 0 || (year % 4 == 0 && year % 100 != 0);
+    &GREGORIAN_DAYS_BEFORE_MONTH[is_leap as usize]
+}
+
+/// The Chinese year beginning in the given Gregorian year, if it is in the
+/// table.
+pub const fn chinese_year(year: i32) -> Option<ChineseYear> {
+    if year >= FIRST_YEAR && year <= LAST_YEAR {
+        Some(CHINESE_YEARS[(year - FIRST_YEAR) as usize])
+    } else {
+        None
+    }
+}
+
+/// The days of the solar terms of the given Gregorian year in Beijing time,
+/// from the Minor Cold (小寒) to the Winter Solstice (冬至), if it is in the
+/// table.
+pub const fn solar_terms(year: i32) -> Option<&'static [Date; 24]> {
+    if year >= FIRST_YEAR && year <= LAST_YEAR {
+        Some(&SOLAR_TERMS[(year - FIRST_YEAR) as usize])
+    } else {
+        None
+    }
+}
+
+/// The day of the given solar term of the given Gregorian year in Beijing
+/// time, if it is in the table.
+#[cfg(feature = "astronomy")]
+pub const fn solar_term(year: i32, term: SolarTerm) -> Option<Date> {
+    match solar_terms(year) {
+        Some(dates) => Some(dates[(term as usize + 23) % 24]),
+        None => None,
+    }
+}
+
+#[test]
+fn test_gregorian_table() {
+    use crate::calendar::{Calendar as _, Day as _, Month as _, Year as _};
+
+    for year in [1900, 2000, 2023, 2024] {
+        let days_before_month = gregorian_days_before_month(year);
+        let year = GregorianCalendar::from_y(year).unwrap();
+        for month in year.months() {
+            let ord = month.ord() as usize;
+            assert_eq!(
+                days_before_month[ord - 1] + 1,
+                month.first_day().ord_in_year()
+            );
+            assert_eq!(
+                (days_before_month[ord] - days_before_month[ord - 1]) as usize,
+                month.num_days()
+            );
+        }
+        assert_eq!(days_before_month[12] as usize, year.num_days());
+    }
+}
+
+#[cfg(feature = "astronomy")]
+#[test]
+fn test_chinese_table() {
+    use crate::calendar::{Month as _, Year as _};
+    use crate::chinese::Mode;
+
+    const YEAR: Option<ChineseYear> = chinese_year(2023);
+    assert_eq!(YEAR.unwrap().leap_month, 2);
+    assert_eq!(chinese_year(FIRST_YEAR - 1), None);
+    assert_eq!(chinese_year(LAST_YEAR + 1), None);
+    for year in FIRST_YEAR..=LAST_YEAR {
+        let data = chinese_year(year).unwrap();
+        let cy = ChineseCalendar::from_y_with_mode(year, Mode::Deterministic).unwrap();
+        assert_eq!(data.first_day, Date::from(cy.first_day()), "{}", year);
+        assert_eq!(data.num_months(), cy.num_months(), "{}", year);
+        assert_eq!(data.num_days() as usize, cy.num_days(), "{}", year);
+        for (i, month) in cy.months().enumerate() {
+            assert_eq!(data.num_days_of_month(i), Some(month.num_days() as u8));
+            if month.is_leap() {
+                assert_eq!(data.leap_month as usize, i);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "astronomy")]
+#[test]
+fn test_solar_term_table() {
+    use strum::VariantArray;
+
+    assert_eq!(
+        solar_term(2024, BeginningOfSpring),
+        Some(GregorianCalendar::from_ymd(2024, 2, 4).unwrap().into())
+    );
+    assert_eq!(solar_terms(LAST_YEAR + 1), None);
+    for year in FIRST_YEAR..=LAST_YEAR {
+        let begin: Date = GregorianCalendar::from_ymd(year, 1, 1).unwrap().into();
+        let end: Date = GregorianCalendar::from_ymd(year + 1, 1, 1).unwrap().into();
+        let expected: Vec<_> = astronomy::fixed::get_solar_terms(begin, end, 8.0)
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, st)| st.map(|st| (begin + i as i32, st)))
+            .collect();
+        let actual: Vec<_> = SolarTerm::VARIANTS
+            .iter()
+            .map(|&st| (solar_term(year, st).unwrap(), st))
+            .collect();
+        assert_eq!(actual.len(), expected.len());
+        for pair in &expected {
+            assert!(actual.contains(pair), "{} {:?}", year, pair);
+        }
+    }
+}
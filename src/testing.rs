@@ -0,0 +1,309 @@
+//! Utilities to test calendars built on the traits of [`calendar`], also
+//! usable by calendars outside this crate.
+//!
+//! [`Dates`] and [`Ymds`] generate pseudo-random dates from a seed, and the
+//! `check_*` functions tell whether a calendar keeps an invariant for them:
+//!
+//! ```
+//! use omnical::testing::{self, Dates};
+//! use omnical::GregorianCalendar;
+//!
+//! for date in Dates::new(1, testing::SUPPORTED_YEARS).take(100) {
+//!     testing::check_date_round_trip::<GregorianCalendar>(date).unwrap();
+//! }
+//! ```
+//!
+//! With the `proptest` feature, [`strategy`] has the same generators as
+//! strategies, and with the `arbitrary` feature, [`Date`] implements
+//! `arbitrary::Arbitrary`.
+
+use std::ops::RangeInclusive;
+
+use crate::calendar::{Calendar, Day, Month, Ordinal, Year};
+use crate::*;
+
+/// The Gregorian years whose days are supported by all calendars of this
+/// crate; the Chinese day may be in the Chinese year before the Gregorian
+/// one.
+#[cfg(feature = "astronomy")]
+pub const SUPPORTED_YEARS: RangeInclusive<i32> =
+    *chinese::SUPPORTED_YEARS.start() + 1..=*chinese::SUPPORTED_YEARS.end();
+/// The Gregorian years whose days are supported by all calendars of this
+/// crate.
+#[cfg(not(feature = "astronomy"))]
+pub const SUPPORTED_YEARS: RangeInclusive<i32> = gregorian::SUPPORTED_YEARS;
+
+/// A pseudo-random generator (SplitMix64), good enough to pick test dates.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in the range, nearly uniformly.
+    pub fn in_range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let len = (range.end() - range.start()) as u64 + 1;
+        range.start() + (self.next_u64() % len) as i64
+    }
+}
+
+/// The first and the last dates of the Gregorian years.
+fn date_range(years: &RangeInclusive<i32>) -> (Date, Date) {
+    let first = GregorianCalendar::from_y(*years.start())
+        .unwrap()
+        .first_day();
+    let last = GregorianCalendar::from_y(*years.end()).unwrap().last_day();
+    (first.into(), last.into())
+}
+
+/// An endless iterator of pseudo-random dates in the Gregorian years.
+#[derive(Debug, Clone)]
+pub struct Dates {
+    rng: Rng,
+    first: Date,
+    last: Date,
+}
+
+impl Dates {
+    pub fn new(seed: u64, years: RangeInclusive<i32>) -> Self {
+        let (first, last) = date_range(&years);
+        Self {
+            rng: Rng::new(seed),
+            first,
+            last,
+        }
+    }
+}
+
+impl Iterator for Dates {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Date> {
+        let jdn = self
+            .rng
+            .in_range(self.first.jdn() as i64..=self.last.jdn() as i64);
+        Some(Date::from_jdn(jdn as i32))
+    }
+}
+
+/// An iterator of pseudo-random valid (year, month, day) of a calendar in
+/// its years, with months numbered by their order in the year, ending at a
+/// year the calendar does not support.
+#[derive(Debug, Clone)]
+pub struct Ymds<C> {
+    rng: Rng,
+    years: RangeInclusive<i32>,
+    calendar: std::marker::PhantomData<fn() -> C>,
+}
+
+impl<C: Calendar> Ymds<C> {
+    pub fn new(seed: u64, years: RangeInclusive<i32>) -> Self {
+        Self {
+            rng: Rng::new(seed),
+            years,
+            calendar: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<C: Calendar> Iterator for Ymds<C> {
+    type Item = (i32, C::MonthOrd, C::DayOrd);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, end) = (*self.years.start() as i64, *self.years.end() as i64);
+        let year = C::from_y(self.rng.in_range(start..=end) as i32)?;
+        let month = year
+            .month(C::MonthOrd::from_u32(
+                self.rng.in_range(1..=year.num_months() as i64) as u32,
+            )?)
+            .unwrap();
+        let day = C::DayOrd::from_u32(self.rng.in_range(1..=month.num_days() as i64) as u32)?;
+        Some((year.ord(), month.ord(), day))
+    }
+}
+
+fn day_of_date<C>(date: Date) -> Result<C::Day, String>
+where
+    C: Calendar,
+    C::Day: TryFrom<Date>,
+{
+    C::Day::try_from(date).map_err(|_| format!("{:?} is out of range", date.jdn()))
+}
+
+/// Checks that a date converted to a day of the calendar converts back to
+/// itself.
+pub fn check_date_round_trip<C>(date: Date) -> Result<(), String>
+where
+    C: Calendar,
+    C::Day: TryFrom<Date> + std::fmt::Debug,
+{
+    let day = day_of_date::<C>(date)?;
+    let back: Date = day.into();
+    if back == date {
+        Ok(())
+    } else {
+        Err(format!("{:?} -> {:?} -> {:?}", date.jdn(), day, back.jdn()))
+    }
+}
+
+/// Checks that a day made of (year, month, day) has them, and converts to a
+/// date and back to itself.
+pub fn check_ymd_round_trip<C>(year: i32, month: C::MonthOrd, day: C::DayOrd) -> Result<(), String>
+where
+    C: Calendar,
+    C::Day: TryFrom<Date> + PartialEq + std::fmt::Debug,
+{
+    let the_day = C::from_ymd(year, month, day)
+        .ok_or_else(|| format!("({}, {}, {}) is invalid", year, month, day))?;
+    let ymd = (
+        the_day.the_year().ord(),
+        the_day.the_month().ord(),
+        the_day.ord(),
+    );
+    if ymd != (year, month, day) {
+        return Err(format!("({}, {}, {}) -> {:?}", year, month, day, ymd));
+    }
+    let back = day_of_date::<C>(the_day.into())?;
+    if back != the_day {
+        return Err(format!("{:?} -> {:?}", the_day, back));
+    }
+    Ok(())
+}
+
+/// Checks that the day before the day after a day, and the day after the day
+/// before it, is the day itself, one day apart in dates.
+pub fn check_succ_pred<C>(day: C::Day) -> Result<(), String>
+where
+    C: Calendar,
+    C::Day: PartialEq + std::fmt::Debug,
+{
+    let date: Date = day.into();
+    let (succ, pred) = (day.succ(), day.pred());
+    if succ.pred() != day || pred.succ() != day {
+        return Err(format!("{:?}: {:?}, {:?}", day, succ.pred(), pred.succ()));
+    }
+    if succ.into() != date.succ() || pred.into() != date.pred() {
+        return Err(format!(
+            "{:?}: not one day apart from {:?}, {:?}",
+            day, succ, pred
+        ));
+    }
+    Ok(())
+}
+
+/// Runs all the checks on a number of pseudo-random dates of the calendar in
+/// the Gregorian years.
+pub fn check_calendar<C>(seed: u64, years: RangeInclusive<i32>, count: usize) -> Result<(), String>
+where
+    C: Calendar,
+    C::Day: TryFrom<Date> + PartialEq + std::fmt::Debug,
+{
+    for date in Dates::new(seed, years).take(count) {
+        check_date_round_trip::<C>(date)?;
+        let day = day_of_date::<C>(date)?;
+        check_succ_pred::<C>(day)?;
+        check_ymd_round_trip::<C>(day.the_year().ord(), day.the_month().ord(), day.ord())?;
+    }
+    Ok(())
+}
+
+/// The generators as strategies of proptest.
+#[cfg(feature = "proptest")]
+pub mod strategy {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// Dates in the Gregorian years.
+    pub fn dates(years: RangeInclusive<i32>) -> impl Strategy<Value = Date> {
+        let (first, last) = date_range(&years);
+        (first.jdn()..=last.jdn()).prop_map(Date::from_jdn)
+    }
+
+    /// Valid (year, month, day) of a calendar in its years.
+    pub fn ymds<C: Calendar>(
+        years: RangeInclusive<i32>,
+    ) -> impl Strategy<Value = (i32, C::MonthOrd, C::DayOrd)> {
+        years
+            .prop_filter("unsupported year", |year| C::from_y(*year).is_some())
+            .prop_flat_map(|year| {
+                let months = 1..=C::from_y(year).unwrap().num_months() as u32;
+                (
+                    Just(year),
+                    months.prop_filter_map("too many months", C::MonthOrd::from_u32),
+                )
+            })
+            .prop_flat_map(|(year, month)| {
+                let days = 1..=C::from_ym(year, month).unwrap().num_days() as u32;
+                let days = days.prop_filter_map("too many days", C::DayOrd::from_u32);
+                (Just(year), Just(month), days)
+            })
+    }
+}
+
+/// A date in [`SUPPORTED_YEARS`].
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Date {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let (first, last) = date_range(&SUPPORTED_YEARS);
+        Ok(Date::from_jdn(u.int_in_range(first.jdn()..=last.jdn())?))
+    }
+}
+
+#[test]
+fn test_testing() {
+    let dates: Vec<_> = Dates::new(7, 2000..=2000).take(100).collect();
+    assert_eq!(
+        dates,
+        Dates::new(7, 2000..=2000).take(100).collect::<Vec<_>>()
+    );
+    assert!(dates
+        .iter()
+        .all(|date| GregorianDay::from(*date).the_year().ord() == 2000));
+
+    check_calendar::<GregorianCalendar>(1, SUPPORTED_YEARS, 1000).unwrap();
+    assert!(check_ymd_round_trip::<GregorianCalendar>(2024, 2, 30).is_err());
+}
+
+#[cfg(feature = "astronomy")]
+#[test]
+fn test_testing_chinese() {
+    for (year, month, day) in Ymds::<ChineseCalendar>::new(7, 2023..=2024).take(100) {
+        assert!((2023..=2024).contains(&year));
+        assert!(ChineseCalendar::from_ymd(year, month, day).is_some());
+    }
+
+    check_calendar::<ChineseCalendar>(1, 1900..=2100, 20).unwrap();
+}
+
+#[cfg(all(feature = "proptest", feature = "astronomy"))]
+proptest::proptest! {
+    #[test]
+    fn test_strategy(
+        date in strategy::dates(1900..=2100),
+        (year, month, day) in strategy::ymds::<ChineseCalendar>(1900..=2100),
+    ) {
+        check_date_round_trip::<ChineseCalendar>(date).unwrap();
+        check_ymd_round_trip::<ChineseCalendar>(year, month, day).unwrap();
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn test_arbitrary() {
+    use arbitrary::Arbitrary as _;
+    for bytes in [[0x00; 4], [0x7f; 4], [0xff; 4]] {
+        let date = Date::arbitrary(&mut arbitrary::Unstructured::new(&bytes)).unwrap();
+        assert!(SUPPORTED_YEARS.contains(&GregorianDay::from(date).the_year().ord()));
+    }
+}
@@ -0,0 +1,139 @@
+//! Time scales: Coordinated Universal Time (UTC), International Atomic Time
+//! (TAI) and Terrestrial Time (TT).
+//!
+//! By default, this crate follows POSIX: a Unix time counts every day as
+//! 86400 seconds, and Julian dates derived from it are in UTC, ignoring leap
+//! seconds and the offset of TT, which astronomical instants are computed in.
+//! The conversions here relate the two using a table of leap seconds.
+
+/// The seconds from TAI to TT.
+pub const TT_MINUS_TAI: f64 = 32.184;
+
+const UNIX_EPOCH_JD: f64 = 2440587.5;
+
+/// The leap seconds announced by the IERS up to Bulletin C 70: the Unix times
+/// from which each value of TAI − UTC is in effect.
+const BUILTIN_LEAP_SECONDS: [(u64, i32); 28] = [
+    (63072000, 10),   // 1972-01-01
+    (78796800, 11),   // 1972-07-01
+    (94694400, 12),   // 1973-01-01
+    (126230400, 13),  // 1974-01-01
+    (157766400, 14),  // 1975-01-01
+    (189302400, 15),  // 1976-01-01
+    (220924800, 16),  // 1977-01-01
+    (252460800, 17),  // 1978-01-01
+    (283996800, 18),  // 1979-01-01
+    (315532800, 19),  // 1980-01-01
+    (362793600, 20),  // 1981-07-01
+    (394329600, 21),  // 1982-07-01
+    (425865600, 22),  // 1983-07-01
+    (489024000, 23),  // 1985-07-01
+    (567993600, 24),  // 1988-01-01
+    (631152000, 25),  // 1990-01-01
+    (662688000, 26),  // 1991-01-01
+    (709948800, 27),  // 1992-07-01
+    (741484800, 28),  // 1993-07-01
+    (773020800, 29),  // 1994-07-01
+    (820454400, 30),  // 1996-01-01
+    (867715200, 31),  // 1997-07-01
+    (915148800, 32),  // 1999-01-01
+    (1136073600, 33), // 2006-01-01
+    (1230768000, 34), // 2009-01-01
+    (1341100800, 35), // 2012-07-01
+    (1435708800, 36), // 2015-07-01
+    (1483228800, 37), // 2017-01-01
+];
+
+/// A table of TAI − UTC, as changed by leap seconds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeapSecondTable {
+    entries: Vec<(u64, i32)>,
+}
+
+impl Default for LeapSecondTable {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl LeapSecondTable {
+    /// A table of the Unix times from which each value of TAI − UTC, in
+    /// seconds, is in effect, e.g. loaded from an up-to-date `leap-seconds.list`.
+    pub fn new(mut entries: Vec<(u64, i32)>) -> Self {
+        entries.sort();
+        Self { entries }
+    }
+
+    /// The table of the leap seconds known to this crate.
+    pub fn builtin() -> Self {
+        Self::new(BUILTIN_LEAP_SECONDS.to_vec())
+    }
+
+    /// TAI − UTC in seconds at the given Unix time, or `None` before the first
+    /// entry of the table.
+    pub fn tai_minus_utc(&self, unix_time: u64) -> Option<i32> {
+        let i = self.entries.partition_point(|&(t, _)| t <= unix_time);
+        i.checked_sub(1).map(|i| self.entries[i].1)
+    }
+
+    /// TT − UTC in seconds at the given Unix time, or `None` before the first
+    /// entry of the table.
+    pub fn tt_minus_utc(&self, unix_time: u64) -> Option<f64> {
+        Some(self.tai_minus_utc(unix_time)? as f64 + TT_MINUS_TAI)
+    }
+}
+
+/// The Julian date in UTC of the given Unix time.
+pub fn unix_time_to_jd(unix_time: u64) -> f64 {
+    unix_time as f64 / 86400.0 + UNIX_EPOCH_JD
+}
+
+/// The Julian date in TT of the given Unix time.
+///
+/// Without a table, or before its first entry, the result equals the Julian
+/// date in UTC, as in the POSIX default.
+pub fn unix_time_to_tt_jd(unix_time: u64, table: Option<&LeapSecondTable>) -> f64 {
+    let offset = table.and_then(|t| t.tt_minus_utc(unix_time)).unwrap_or(0.0);
+    unix_time_to_jd(unix_time) + offset / 86400.0
+}
+
+/// The Julian date in TT of the given Julian date in UTC.
+pub fn utc_jd_to_tt_jd(jd: f64, table: Option<&LeapSecondTable>) -> f64 {
+    let unix_time = ((jd - UNIX_EPOCH_JD) * 86400.0).max(0.0) as u64;
+    let offset = table.and_then(|t| t.tt_minus_utc(unix_time)).unwrap_or(0.0);
+    jd + offset / 86400.0
+}
+
+/// The Julian date in UTC of the given Julian date in TT, the inverse of
+/// [`utc_jd_to_tt_jd`] except within a leap second.
+pub fn tt_jd_to_utc_jd(jd: f64, table: Option<&LeapSecondTable>) -> f64 {
+    // TT − UTC changes rarely, so that of the first approximation is almost
+    // always the final one.
+    let approx = utc_jd_to_tt_jd(jd, table) - jd;
+    let offset = utc_jd_to_tt_jd(jd - approx, table) - (jd - approx);
+    jd - offset
+}
+
+#[test]
+fn test_timescale() {
+    let table = LeapSecondTable::builtin();
+    assert_eq!(table.tai_minus_utc(0), None);
+    assert_eq!(table.tai_minus_utc(63072000), Some(10));
+    assert_eq!(table.tai_minus_utc(1483228799), Some(36));
+    assert_eq!(table.tai_minus_utc(1483228800), Some(37));
+    assert_eq!(table.tt_minus_utc(1707537600), Some(69.184));
+
+    let unix_time = 1707566400;
+    let jd = unix_time_to_jd(unix_time);
+    assert_eq!(jd, 2460351.0);
+    assert_eq!(unix_time_to_tt_jd(unix_time, None), jd);
+    let tt_jd = unix_time_to_tt_jd(unix_time, Some(&table));
+    assert!((tt_jd - jd - 69.184 / 86400.0).abs() < 1e-9);
+    assert_eq!(utc_jd_to_tt_jd(jd, Some(&table)), tt_jd);
+    assert!((tt_jd_to_utc_jd(tt_jd, Some(&table)) - jd).abs() < 1e-9);
+    assert_eq!(tt_jd_to_utc_jd(jd, None), jd);
+
+    let table = LeapSecondTable::new(vec![(200, 2), (100, 1)]);
+    assert_eq!(table.tai_minus_utc(150), Some(1));
+    assert_eq!(table.tai_minus_utc(250), Some(2));
+}
@@ -0,0 +1,243 @@
+//! The `tui` subcommand, an interactive calendar browser in the terminal.
+
+use std::io;
+
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+use strum::VariantArray as _;
+
+use omnical::*;
+
+use super::parse_any_date;
+
+const HELP: &str = "←→ day  ↑↓ week  PgUp/PgDn month  t today  g go to  q quit";
+
+struct App {
+    tz: f64,
+    today: Date,
+    selected: Date,
+    /// The date being typed after pressing `g`, if any.
+    input: Option<String>,
+    message: String,
+}
+
+/// Runs the browser until the user quits.
+pub fn run(tz: f64) -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    let result = App::new(tz).run(&mut terminal);
+    ratatui::restore();
+    result
+}
+
+impl App {
+    fn new(tz: f64) -> Self {
+        let today = super::today(tz);
+        Self {
+            tz,
+            today,
+            selected: today,
+            input: None,
+            message: String::new(),
+        }
+    }
+
+    fn run(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        loop {
+            terminal.draw(|frame| self.draw(frame))?;
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press && !self.handle_key(key.code) {
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    /// Handles a key press, returning whether to continue.
+    fn handle_key(&mut self, code: KeyCode) -> bool {
+        if let Some(input) = &mut self.input {
+            match code {
+                KeyCode::Enter => {
+                    let input = self.input.take().unwrap();
+                    match parse_any_date(&input) {
+                        Ok(date) => self.selected = date,
+                        Err(e) => self.message = e,
+                    }
+                }
+                KeyCode::Esc => self.input = None,
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Char(c) => input.push(c),
+                _ => {}
+            }
+            return true;
+        }
+        self.message.clear();
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Left | KeyCode::Char('h') => self.selected = self.selected.pred(),
+            KeyCode::Right | KeyCode::Char('l') => self.selected = self.selected.succ(),
+            KeyCode::Up | KeyCode::Char('k') => self.selected += -7,
+            KeyCode::Down | KeyCode::Char('j') => self.selected += 7,
+            KeyCode::PageUp | KeyCode::Char('[') => self.add_months(-1),
+            KeyCode::PageDown | KeyCode::Char(']') => self.add_months(1),
+            KeyCode::Char('t') => self.selected = self.today,
+            KeyCode::Char('g') => self.input = Some(String::new()),
+            _ => {}
+        }
+        true
+    }
+
+    /// Moves to the same day of another month, or to the last day of the
+    /// month if it is shorter.
+    fn add_months(&mut self, n: i32) {
+        if let Some(day) = GregorianDay::from(self.selected).add_months(n) {
+            self.selected = day.into();
+        }
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let [main, status] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+        let [grid, details] =
+            Layout::horizontal([Constraint::Length(30), Constraint::Min(0)]).areas(main);
+        frame.render_widget(self.month_grid(), grid);
+        frame.render_widget(self.details(), details);
+        let status_line = match &self.input {
+            Some(input) => format!("Go to: {}_", input),
+            None if !self.message.is_empty() => self.message.clone(),
+            None => HELP.to_string(),
+        };
+        frame.render_widget(Paragraph::new(status_line), status);
+    }
+
+    fn month_grid(&self) -> Paragraph<'_> {
+        let month = GregorianDay::from(self.selected).the_month();
+        let (first, last): (Date, Date) = (month.first_day().into(), month.last_day().into());
+        let begin = first + -(first.weekday() as i32);
+        let solar_terms = astronomy::find_solar_terms(begin, begin + 42, self.tz);
+
+        let header: String = Weekday::VARIANTS
+            .iter()
+            .map(|weekday| format!(" {:3}", weekday))
+            .collect();
+        let mut lines = vec![Line::from(header)];
+        let mut spans = Vec::new();
+        let mut date = begin;
+        while date <= last || date.weekday() != Weekday::first() {
+            let mut style = Style::default();
+            if date < first || date > last {
+                style = style.add_modifier(Modifier::DIM);
+            } else if ChineseDay::try_from(date).is_ok_and(|day| day.festival().is_some()) {
+                style = style.fg(Color::Magenta);
+            } else if solar_terms.iter().any(|(d, _)| *d == date) {
+                style = style.fg(Color::Green);
+            } else if matches!(date.weekday(), Saturday | Sunday) {
+                style = style.fg(Color::Red);
+            }
+            if date == self.today {
+                style = style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+            }
+            if date == self.selected {
+                style = style.add_modifier(Modifier::REVERSED);
+            }
+            let label = format!("{:>2}", GregorianDay::from(date).ord());
+            spans.push(Span::raw(" "));
+            spans.push(Span::styled(label, style));
+            spans.push(Span::raw(" "));
+            if date.weekday() == Weekday::last() {
+                lines.push(Line::from(std::mem::take(&mut spans)));
+            }
+            date = date.succ();
+        }
+        Paragraph::new(lines).block(Block::bordered().title(format!(" {:-} ", month)))
+    }
+
+    fn details(&self) -> Paragraph<'_> {
+        let date = self.selected;
+        let day = GregorianDay::from(date);
+        // A date outside the Chinese calendar has no Chinese details.
+        let chinese_day = ChineseDay::try_from(date).ok();
+        let lunar_phase = date.lunar_phase(self.tz);
+        let mut lines = vec![format!(
+            "Date:         {} {}",
+            day.to_iso8601(),
+            date.weekday()
+        )];
+        if let Some(chinese_day) = chinese_day {
+            lines.push(format!("Chinese date: {}", chinese_day));
+            lines.push(format!(
+                "Stem-branch:  {}年 {}月 {}日",
+                chinese_day.the_year().stem_branch(),
+                chinese_day.the_month().stem_branch(),
+                chinese_day.stem_branch()
+            ));
+        }
+        lines.push(format!(
+            "Lunar phase:  {} {}",
+            lunar_phase.emoji(),
+            lunar_phase.chinese()
+        ));
+        if let Some(solar_term) = date.solar_term(self.tz) {
+            lines.push(format!(
+                "Solar term:   {} ({})",
+                solar_term.chinese(),
+                solar_term.english()
+            ));
+        }
+        if let Some(festival) = chinese_day.and_then(|day| day.festival()) {
+            lines.push(format!("Festival:     {}", festival.chinese()));
+        }
+        let lines: Vec<_> = lines.into_iter().map(Line::from).collect();
+        Paragraph::new(lines).block(Block::bordered().title(" Details "))
+    }
+}
+
+#[test]
+fn test_handle_key() {
+    let date = |y, m, d| Date::from(GregorianCalendar::from_ymd(y, m, d).unwrap());
+    let mut app = App::new(8.0);
+    app.selected = date(2024, 1, 31);
+    app.handle_key(KeyCode::PageDown);
+    assert_eq!(app.selected, date(2024, 2, 29));
+    app.handle_key(KeyCode::Up);
+    assert_eq!(app.selected, date(2024, 2, 22));
+    app.handle_key(KeyCode::PageUp);
+    app.handle_key(KeyCode::PageUp);
+    assert_eq!(app.selected, date(2023, 12, 22));
+
+    app.handle_key(KeyCode::Char('g'));
+    for c in "2024-02-10".chars() {
+        app.handle_key(KeyCode::Char(c));
+    }
+    app.handle_key(KeyCode::Enter);
+    assert_eq!(app.selected, date(2024, 2, 10));
+    app.handle_key(KeyCode::Char('g'));
+    for c in "甲辰年正月十五".chars() {
+        app.handle_key(KeyCode::Char(c));
+    }
+    app.handle_key(KeyCode::Enter);
+    assert_eq!(app.selected, date(2024, 2, 24));
+    app.handle_key(KeyCode::Char('g'));
+    app.handle_key(KeyCode::Char('x'));
+    app.handle_key(KeyCode::Enter);
+    assert_eq!(app.message, "invalid date: x");
+    assert!(!app.handle_key(KeyCode::Char('q')));
+}
+
+#[test]
+fn test_draw() {
+    let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(80, 10)).unwrap();
+    let mut app = App::new(8.0);
+    app.selected = GregorianCalendar::from_ymd(2024, 2, 10).unwrap().into();
+    terminal.draw(|frame| app.draw(frame)).unwrap();
+    let buffer = terminal.backend().buffer();
+    let text: String = buffer.content().iter().map(|cell| cell.symbol()).collect();
+    assert!(text.contains("February 2024"));
+    assert!(text.contains("2024-02-10 Saturday"));
+    assert!(text.contains("Festival:"));
+}
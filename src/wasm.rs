@@ -0,0 +1,151 @@
+//! JavaScript bindings, built for wasm32-unknown-unknown with the `wasm`
+//! feature as a cdylib, which the crate is not by default, and bound by
+//! `wasm-bindgen`, e.g.:
+//!
+//! ```sh
+//! cargo rustc --lib --release --target wasm32-unknown-unknown --features wasm --crate-type cdylib
+//! wasm-bindgen --target web --out-dir pkg target/wasm32-unknown-unknown/release/omnical.wasm
+//! ```
+//!
+//! Dates are passed as ISO 8601 strings, e.g. "2024-02-10", and timezones as
+//! offsets in hours.
+
+use wasm_bindgen::prelude::*;
+
+use crate::calendar::{Calendar as _, Day as _, Month as _, Year as _};
+use crate::*;
+
+fn parse_iso8601(date: &str) -> Result<Date, JsError> {
+    GregorianDay::from_iso8601(date)
+        .map(Date::from)
+        .map_err(|_| JsError::new(&format!("invalid date: {}", date)))
+}
+
+fn chinese_day(date: Date) -> Result<ChineseDay, JsError> {
+    ChineseDay::try_from(date).map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// A day of the Chinese calendar.
+#[wasm_bindgen(getter_with_clone)]
+pub struct ChineseDate {
+    pub year: i32,
+    /// The number of the month, the same for a leap month and the month
+    /// before it.
+    pub month: u8,
+    pub leap: bool,
+    pub day: u8,
+    /// The date in Chinese, e.g. "甲辰年正月初一".
+    pub text: String,
+}
+
+/// Converts a Gregorian date to the Chinese calendar.
+#[wasm_bindgen(js_name = toChinese)]
+pub fn to_chinese(date: &str) -> Result<ChineseDate, JsError> {
+    let date = parse_iso8601(date)?;
+    let day = chinese_day(date)?;
+    Ok(ChineseDate {
+        year: day.the_year().ord(),
+        month: day.the_month().ord_no_leap(),
+        leap: day.the_month().is_leap(),
+        day: day.ord(),
+        text: day.to_string(),
+    })
+}
+
+/// Converts a Chinese date to the Gregorian calendar, as an ISO 8601 string.
+#[wasm_bindgen(js_name = fromChinese)]
+pub fn from_chinese(year: i32, month: u8, leap: bool, day: u8) -> Result<String, JsError> {
+    ChineseCalendar::try_from_y(year).map_err(|e| JsError::new(&e.to_string()))?;
+    let day = ChineseCalendar::from_ylmd(year, leap, month, day)
+        .ok_or_else(|| JsError::new("invalid Chinese date"))?;
+    Ok(GregorianDay::from(Date::from(day)).to_iso8601())
+}
+
+/// Converts a date between calendars of the [`registry`], with months
+/// numbered by their order in the year, to the text of the date.
+#[wasm_bindgen]
+pub fn convert(from: &str, to: &str, year: i32, month: u32, day: u32) -> Result<String, JsError> {
+    let calendar = |name| {
+        let error = || JsError::new(&format!("unknown calendar: {}", name));
+        registry::calendar(name).ok_or_else(error)
+    };
+    let (from, to) = (calendar(from)?, calendar(to)?);
+    let date = from
+        .day(year, month, day)
+        .ok_or_else(|| JsError::new("invalid date"))?
+        .date();
+    let error = || JsError::new(&format!("date outside the {} calendar", to.display_name()));
+    Ok(to.day_of_date(date).ok_or_else(error)?.to_string())
+}
+
+/// The lunar phase of a day, e.g. "FullMoon".
+#[wasm_bindgen(js_name = lunarPhase)]
+pub fn lunar_phase(date: &str, tz: f64) -> Result<String, JsError> {
+    Ok(parse_iso8601(date)?.lunar_phase(tz).to_string())
+}
+
+/// The illuminated fraction of the Moon at noon of a day.
+#[wasm_bindgen(js_name = lunarIllumination)]
+pub fn lunar_illumination(date: &str, tz: f64) -> Result<f64, JsError> {
+    Ok(astronomy::get_moon_illumination(
+        parse_iso8601(date)?.noon_jd(tz),
+    ))
+}
+
+/// The moment of a solar term.
+#[wasm_bindgen(getter_with_clone)]
+pub struct SolarTermInstant {
+    /// The Chinese name, e.g. "立春".
+    pub name: String,
+    /// The English name, e.g. "Beginning of Spring".
+    pub english: String,
+    /// The local date and time, e.g. "2024-02-04 16:26".
+    pub time: String,
+}
+
+/// The solar terms of a Gregorian year.
+#[wasm_bindgen(js_name = solarTerms)]
+pub fn solar_terms(year: i32, tz: f64) -> Result<Vec<SolarTermInstant>, JsError> {
+    let year = GregorianCalendar::from_y(year).ok_or_else(|| JsError::new("invalid year"))?;
+    let (begin, end) = (year.first_day().into(), Date::from(year.last_day()).succ());
+    let leap_seconds = timescale::LeapSecondTable::builtin();
+    Ok(astronomy::find_solar_term_instants(begin, end, tz)
+        .into_iter()
+        .map(|(jd, term)| {
+            let time = chinese::DateTime::from_tt_jd(jd, tz, Some(&leap_seconds));
+            SolarTermInstant {
+                name: term.chinese().to_string(),
+                english: term.english().to_string(),
+                time: format!(
+                    "{} {:02}:{:02}",
+                    GregorianDay::from(Date::from(time.day())).to_iso8601(),
+                    time.hour(),
+                    time.minute()
+                ),
+            }
+        })
+        .collect())
+}
+
+#[test]
+fn test_wasm() {
+    let day = to_chinese("2024-02-10").ok().unwrap();
+    assert_eq!(
+        (day.year, day.month, day.leap, day.day),
+        (2024, 1, false, 1)
+    );
+    assert_eq!(day.text, "甲辰年正月初一");
+    let day = to_chinese("2023-03-22").ok().unwrap();
+    assert_eq!((day.month, day.leap, day.day), (2, true, 1));
+    assert_eq!(from_chinese(2023, 2, true, 1).ok().unwrap(), "2023-03-22");
+    assert_eq!(
+        convert("chinese", "gregorian", 2024, 1, 1).ok().unwrap(),
+        "2024-02-10"
+    );
+    assert_eq!(lunar_phase("2024-02-10", 8.0).ok().unwrap(), "NewMoon");
+    assert!(lunar_illumination("2024-02-10", 8.0).ok().unwrap() < 0.01);
+    let terms = solar_terms(2024, 8.0).ok().unwrap();
+    assert_eq!(terms.len(), 24);
+    assert_eq!(terms[2].name, "立春");
+    assert_eq!(terms[2].time, "2024-02-04 16:26");
+}
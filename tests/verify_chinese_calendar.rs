@@ -1,3 +1,5 @@
+#![cfg(feature = "astronomy")]
+
 use chinese_lunisolar_calendar::{LunisolarDate, SolarDate};
 use chrono::NaiveDate;
 use nongli::ChineseDate;
@@ -8,7 +10,7 @@ fn verify_chinese_calendar() {
     let mut v = vec![];
     for year in 1928..2057 {
         let year = GregorianCalendar::from_y(year).unwrap();
-        let mut chinese_day = ChineseDay::from(Date::from(year.first_day()));
+        let mut chinese_day = ChineseDay::try_from(Date::from(year.first_day())).unwrap();
         for day in year.days() {
             let y = day.the_year().ord() as u16;
             let m = day.the_month().ord();